@@ -1,14 +1,23 @@
 use serde::{Deserialize, Serialize};
 
-use crate::the_world::index_specifier::IndexSpecifier;
+use crate::{
+    parser::save_format::SaveFormat,
+    the_world::{
+        index_specifier::IndexSpecifier,
+        inventory_sort::{InventorySortKey, SortOrder},
+        item_query::ItemQuery,
+        item_reference::ItemReference,
+        state_query::{ItemFilter, PlaceFilter, StateQuery},
+    },
+};
 
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Eq, Hash)]
 pub enum Command {
     State,
     Move(usize),
-    Equip(usize, usize),
-    SwapEquipment(usize, usize),
-    RerollModifier(usize, usize, Vec<IndexSpecifier>),
+    Equip(ItemReference, ItemReference),
+    SwapEquipment(ItemReference, ItemReference),
+    RerollModifier(ItemReference, usize, Vec<IndexSpecifier>),
     ExpandPlaces,
     ExpandElements,
     ExpandMaxElement,
@@ -17,9 +26,34 @@ pub enum Command {
     ExpandMinSimultaneousElement,
     ExpandEquipmentSlots,
     ReduceDifficulty,
-    AddModifier(usize, Vec<IndexSpecifier>),
+    AddModifier(ItemReference, Vec<IndexSpecifier>),
     Help,
     ReorderInventory,
-    SaveTheWorld(Box<str>, Option<Box<str>>),
+    SaveTheWorld(Box<str>, Option<Box<str>>, #[serde(default)] SaveFormat),
     LoadTheWorld(Box<str>, Option<Box<str>>),
+    NewGame(Box<str>, Option<[u8; 16]>),
+    SelectGame(Box<str>),
+    ListGames,
+    Undo,
+    Redo,
+    Preview(Box<Command>),
+    Simulate(Option<usize>),
+    OptimizeLoadout(Option<usize>, bool),
+    Places(StateQuery<PlaceFilter>),
+    Equipment(StateQuery<ItemFilter>),
+    Inventory(StateQuery<ItemFilter>),
+    Salvage(Vec<ItemReference>, Option<ItemQuery>),
+    Lock(ItemReference),
+    Unlock(ItemReference),
+    SortInventory(InventorySortKey, SortOrder, bool),
+    StashItem(ItemReference, Box<str>),
+    UnstashItem(Box<str>, ItemReference),
+    ExpandStash,
+    NameItem(ItemReference, Option<Box<str>>),
+    NoteItem(ItemReference, Option<Box<str>>),
+    UpgradeRarity(ItemReference, Vec<IndexSpecifier>),
+    ListSaves(Option<Box<str>>),
+    DeleteSave(Box<str>, Option<Box<str>>),
+    RenameSave(Box<str>, Box<str>, Option<Box<str>>),
+    SaveInfo(Box<str>, Option<Box<str>>),
 }
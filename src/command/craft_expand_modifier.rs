@@ -4,10 +4,12 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
 use crate::{
-    command::roll_modifier::execute_craft,
-    my_little_rpg_errors::MyError,
+    command::roll_modifier::{execute_craft, ModifierRolls},
+    my_little_rpg_errors::{ErrorCode, MyError},
     the_world::{
-        index_specifier::{calculate_absolute_item_indexes, ErrorConditions, IndexSpecifier},
+        index_specifier::{
+            calculate_absolute_item_indexes, expand_item_queries, ErrorConditions, IndexSpecifier,
+        },
         item::Item,
         treasure_types::TreasureType,
     },
@@ -19,6 +21,7 @@ mod tests;
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct ExecuteExpandModifiersReport {
     new_item: Item,
+    rolls: ModifierRolls,
     paid_cost: usize,
     new_cost: usize,
     leftover_spending_treasure: HashMap<TreasureType, u64>,
@@ -28,62 +31,70 @@ pub fn execute_craft_expand_modifiers_json(
     game: &mut Game,
     inventory_index: usize,
     sacrifice_item_indexes: Vec<IndexSpecifier>,
-) -> Value {
-    match execute_craft_expand_modifiers(game, inventory_index, sacrifice_item_indexes) {
-        Ok(result) => json!(result),
-        Err(result) => json!(result),
-    }
+) -> Result<Value, MyError> {
+    execute_craft_expand_modifiers(game, inventory_index, sacrifice_item_indexes)
+        .map(|result| json!(result))
 }
 
 pub fn execute_craft_expand_modifiers(
     game: &mut Game,
     inventory_index: usize,
-    mut sacrifice_item_indexes: Vec<IndexSpecifier>,
+    sacrifice_item_indexes: Vec<IndexSpecifier>,
 ) -> Result<ExecuteExpandModifiersReport, MyError> {
     //validation
     if game.inventory.len() <= inventory_index {
-        return Err(MyError::create_execute_command_error(format!(
-            "inventory_index {} is not within the range of the inventory {}",
-            inventory_index,
-            game.inventory.len()
-        )));
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::InventoryIndexOutOfRange,
+            format!(
+                "inventory_index {} is not within the range of the inventory {}",
+                inventory_index,
+                game.inventory.len()
+            ),
+        ));
     }
     let inventory_item = game.inventory[inventory_index].as_ref().ok_or_else(|| {
-        MyError::create_execute_command_error(format!(
-            "inventory_index {inventory_index} is empty."
-        ))
+        MyError::create_execute_command_error(
+            ErrorCode::InventorySlotEmpty,
+            format!("inventory_index {inventory_index} is empty."),
+        )
     })?;
-    if usize::from(
-        inventory_item
-            .crafting_info
-            .possible_rolls
-            .min_simultaneous_resistances,
-    ) <= inventory_item.modifiers.len()
-    {
-        return Err(MyError::create_execute_command_error(format!(
-            "inventory_index.possible_rolls.min_simultaneous_resistances {} need to be bigger \
-             than inventory_index current number of modifiers {} for it to be expanded.",
-            inventory_item
-                .crafting_info
-                .possible_rolls
-                .min_simultaneous_resistances,
-            inventory_item.modifiers.len()
-        )));
+    if inventory_item.modifier_slots() <= inventory_item.modifiers.len() {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::ModifierLimitReached,
+            format!(
+                "inventory_index.possible_rolls.min_simultaneous_resistances {} plus {} for being \
+                 {:?} need to be bigger than inventory_index current number of modifiers {} for it \
+                 to be expanded.",
+                inventory_item
+                    .crafting_info
+                    .possible_rolls
+                    .min_simultaneous_resistances,
+                inventory_item.rarity.extra_modifier_slots(),
+                inventory_item.rarity,
+                inventory_item.modifiers.len()
+            ),
+        ));
     }
 
+    let error_conditions = get_index_specifier_error_conditions(inventory_item);
+    let mut sacrifice_item_indexes =
+        expand_item_queries(game, inventory_index, sacrifice_item_indexes, &error_conditions);
+
     let cost = execute_craft_expand_modifiers_calculate_cost(game, inventory_index);
     if sacrifice_item_indexes.len() < cost {
-        return Err(MyError::create_execute_command_error(format!(
-            "craft_reroll_modifier needs {} items to be sacrificed but you only provided {}",
-            cost,
-            sacrifice_item_indexes.len()
-        )));
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::NotEnoughSacrifices,
+            format!(
+                "craft_reroll_modifier needs {} items to be sacrificed but you only provided {}",
+                cost,
+                sacrifice_item_indexes.len()
+            ),
+        ));
     }
 
     //Only need to cost amount of items
     sacrifice_item_indexes.truncate(cost);
 
-    let error_conditions = get_index_specifier_error_conditions(inventory_item);
     let calculated_sacrifice_item_indexes = calculate_absolute_item_indexes(
         game,
         inventory_index,
@@ -92,9 +103,11 @@ pub fn execute_craft_expand_modifiers(
     )?;
 
     //Create item
-    let new_item_modifier = execute_craft(
-        &mut game.random_generator_state,
+    let (new_item_modifier, rolls) = execute_craft(
+        &mut game.random_generators.crafting,
         &inventory_item.crafting_info,
+        inventory_item.rarity,
+        &game.rules,
     )?;
 
     //Crafting cost
@@ -109,6 +122,7 @@ pub fn execute_craft_expand_modifiers(
     Ok(ExecuteExpandModifiersReport {
         //TODO replace all unwrap and expect with better error handling
         new_item: inventory_item.clone(),
+        rolls,
         paid_cost: cost,
         new_cost: execute_craft_expand_modifiers_calculate_cost(game, inventory_index),
         leftover_spending_treasure: game.treasure.clone(),
@@ -121,9 +135,14 @@ fn get_index_specifier_error_conditions(inventory_item: &Item) -> ErrorCondition
         let crafting_item_modifiers_count = inventory_item_cloned.modifiers.len();
         let sacrificed_item_modifiers_count = sacrificed_item.modifiers.len();
         if sacrificed_item_modifiers_count < crafting_item_modifiers_count {
-            Some(MyError::create_execute_command_error(format!(
-                "sacrifice_item_index {sacrifice_item_index} need to have at least {crafting_item_modifiers_count} modifiers but it only had {sacrificed_item_modifiers_count}"
-            )))
+            Some(MyError::create_execute_command_error(
+                ErrorCode::SacrificeNotEnoughModifiers,
+                format!(
+                    "sacrifice_item_index {sacrifice_item_index} need to have at least \
+                     {crafting_item_modifiers_count} modifiers but it only had \
+                     {sacrificed_item_modifiers_count}"
+                ),
+            ))
         } else {
             None
         }
@@ -136,7 +155,14 @@ pub fn execute_craft_expand_modifiers_calculate_cost(
     inventory_index: usize,
 ) -> usize {
     match &game.inventory[inventory_index] {
-        Some(item) => item.modifiers.len() * 2,
+        Some(item) => {
+            let cost = game
+                .rules
+                .costs
+                .add_modifier
+                .cost(item.modifiers.len() as u64);
+            usize::try_from(cost).unwrap_or(usize::MAX)
+        }
         None => 0,
     }
 }
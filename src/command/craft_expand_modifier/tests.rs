@@ -3,8 +3,13 @@ mod tests_int {
     use crate::{
         command::craft_expand_modifier::execute_craft_expand_modifiers,
         generator::game::new_testing,
-        my_little_rpg_errors::MyError,
-        the_world::{index_specifier::IndexSpecifier, treasure_types::TreasureType::Gold},
+        my_little_rpg_errors::{ErrorCode, MyError},
+        the_world::{
+            index_specifier::IndexSpecifier,
+            item_query::{Comparison, ItemQuery},
+            rarity::Rarity,
+            treasure_types::TreasureType::Gold,
+        },
     };
 
     #[test]
@@ -14,6 +19,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::NotEnoughSacrifices,
                 "craft_reroll_modifier needs 2 items to be sacrificed but you only provided 1"
                     .to_string()
             )),
@@ -23,6 +29,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::SacrificeIsCraftedItem,
                 "inventory_index 0 and index_specifier Absolute(0) cannot be the same".to_string()
             )),
             execute_craft_expand_modifiers(
@@ -37,6 +44,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::SacrificeAlreadySelected,
                 "index_specifier Absolute(2) is already present in calculated sacrifice indexes \
                  {2}"
                 .to_string()
@@ -68,6 +76,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::InventoryIndexOutOfRange,
                 "inventory_index 99 is not within the range of the inventory 9".to_string()
             )),
             execute_craft_expand_modifiers(
@@ -78,6 +87,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::InventoryIndexOutOfRange,
                 "index_specifier Absolute(99) is not within the range of the inventory 9"
                     .to_string()
             )),
@@ -94,6 +104,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::InventorySlotEmpty,
                 "inventory_index 1 is empty.".to_string()
             )),
             execute_craft_expand_modifiers(
@@ -109,6 +120,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::InventorySlotEmpty,
                 "index_specifier Absolute(1) is pointing at empty inventory slot.".to_string()
             )),
             execute_craft_expand_modifiers(
@@ -124,6 +136,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::SacrificeNotEnoughModifiers,
                 "sacrifice_item_index 3 need to have at least 2 modifiers but it only had 1"
                     .to_string()
             )),
@@ -150,8 +163,10 @@ mod tests_int {
             .min_simultaneous_resistances = 0;
         assert_eq!(
             Err(MyError::create_execute_command_error(
-                "inventory_index.possible_rolls.min_simultaneous_resistances 0 need to be bigger \
-                 than inventory_index current number of modifiers 2 for it to be expanded."
+                ErrorCode::ModifierLimitReached,
+                "inventory_index.possible_rolls.min_simultaneous_resistances 0 plus 0 for being \
+                 Common need to be bigger than inventory_index current number of modifiers 2 for \
+                 it to be expanded."
                     .to_string()
             )),
             execute_craft_expand_modifiers(
@@ -160,6 +175,25 @@ mod tests_int {
                 vec![IndexSpecifier::Absolute(1), IndexSpecifier::Absolute(2)]
             )
         );
+
+        game.inventory[0].as_mut().unwrap().rarity = Rarity::Rare;
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::ModifierLimitReached,
+                "inventory_index.possible_rolls.min_simultaneous_resistances 0 plus 2 for being \
+                 Rare need to be bigger than inventory_index current number of modifiers 2 for it \
+                 to be expanded."
+                    .to_string()
+            )),
+            execute_craft_expand_modifiers(
+                &mut game,
+                0,
+                vec![IndexSpecifier::Absolute(1), IndexSpecifier::Absolute(2)]
+            )
+        );
+
+        game.inventory[0].as_mut().unwrap().rarity = Rarity::Legendary;
+        assert_eq!(3, game.inventory[0].as_ref().unwrap().modifier_slots());
     }
 
     #[test]
@@ -198,6 +232,7 @@ mod tests_int {
         game.inventory[8] = None;
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::NoSacrificeFound,
                 "index_specifier: RelativePositive(1) did not find any items in inventory from \
                  relative point 7 until end of inventory."
                     .to_string()
@@ -250,6 +285,7 @@ mod tests_int {
         game.inventory[0] = None;
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::NoSacrificeFound,
                 "index_specifier: RelativeNegative(1) did not find any items in inventory from \
                  relative point 1 until start of inventory."
                     .to_string()
@@ -302,6 +338,7 @@ mod tests_int {
         game.inventory[0] = None;
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::NoSacrificeFound,
                 "index_specifier: RelativeNegative(1) did not find any items in inventory from \
                  relative point 1 until start of inventory."
                     .to_string()
@@ -317,6 +354,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::NoSacrificeFound,
                 "index_specifier: RelativeNegative(1) did not find any items in inventory from \
                  relative point 1 until start of inventory."
                     .to_string()
@@ -334,6 +372,7 @@ mod tests_int {
         game.inventory[8] = None;
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::NoSacrificeFound,
                 "index_specifier: RelativePositive(1) did not find any items in inventory from \
                  relative point 3 until end of inventory."
                     .to_string()
@@ -349,6 +388,30 @@ mod tests_int {
         );
     }
 
+    // The query skips the item the relative index selects, so both are sacrificed.
+    #[test]
+    fn test_execute_expand_modifiers_relative_and_query() {
+        let mut game = new_testing(Some([1; 16]));
+        game.inventory[0] = None;
+        game.inventory[1] = None;
+        game.inventory[2] = None;
+
+        let result = execute_craft_expand_modifiers(
+            &mut game,
+            3,
+            vec![
+                IndexSpecifier::RelativePositive(1),
+                IndexSpecifier::Query(ItemQuery::Rarity(Comparison::Equal, Rarity::Common)),
+            ],
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(2, game.inventory[3].as_ref().unwrap().modifiers.len());
+        assert!(game.inventory[4].is_none());
+        assert!(game.inventory[5].is_none());
+        assert!(game.inventory[6].is_some());
+    }
+
     #[test]
     fn seeding_test() {
         let mut game = new_testing(Some([1; 16]));
@@ -2,10 +2,12 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
 use crate::{
-    command::roll_modifier::execute_craft,
-    my_little_rpg_errors::MyError,
+    command::roll_modifier::{execute_craft, ModifierRolls},
+    my_little_rpg_errors::{ErrorCode, MyError},
     the_world::{
-        index_specifier::{calculate_absolute_item_indexes, ErrorConditions, IndexSpecifier},
+        index_specifier::{
+            calculate_absolute_item_indexes, expand_item_queries, ErrorConditions, IndexSpecifier,
+        },
         item::Item,
     },
     Game,
@@ -16,6 +18,7 @@ mod tests;
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct ExecuteCraftRerollModifierReport {
     new_item: Item,
+    rolls: ModifierRolls,
     paid_cost: u16,
     new_cost: u16,
 }
@@ -25,64 +28,76 @@ pub fn execute_craft_reroll_modifier_json(
     inventory_index: usize,
     modifier_index: usize,
     sacrifice_item_indexes: Vec<IndexSpecifier>,
-) -> Value {
-    match execute(
+) -> Result<Value, MyError> {
+    execute(
         game,
         inventory_index,
         modifier_index,
         sacrifice_item_indexes,
-    ) {
-        Ok(result) => json!(result),
-        Err(result) => json!(result),
-    }
+    )
+    .map(|result| json!(result))
 }
 
 pub fn execute(
     game: &mut Game,
     inventory_index: usize,
     modifier_index: usize,
-    mut sacrifice_item_indexes: Vec<IndexSpecifier>,
+    sacrifice_item_indexes: Vec<IndexSpecifier>,
 ) -> Result<ExecuteCraftRerollModifierReport, MyError> {
     //validation
     if game.inventory.len() <= inventory_index {
-        return Err(MyError::create_execute_command_error(format!(
-            "inventory_index {} is not within the range of the inventory {}",
-            inventory_index,
-            game.inventory.len()
-        )));
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::InventoryIndexOutOfRange,
+            format!(
+                "inventory_index {} is not within the range of the inventory {}",
+                inventory_index,
+                game.inventory.len()
+            ),
+        ));
     }
     if game.inventory[inventory_index].is_none() {
-        return Err(MyError::create_execute_command_error(format!(
-            "inventory_index {inventory_index} is empty."
-        )));
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::InventorySlotEmpty,
+            format!("inventory_index {inventory_index} is empty."),
+        ));
     }
     let inventory_item = game.inventory[inventory_index].as_ref().ok_or_else(|| {
-        MyError::create_execute_command_error(format!(
-            "inventory_index {inventory_index} is empty."
-        ))
+        MyError::create_execute_command_error(
+            ErrorCode::InventorySlotEmpty,
+            format!("inventory_index {inventory_index} is empty."),
+        )
     })?;
     if inventory_item.modifiers.len() <= modifier_index {
-        return Err(MyError::create_execute_command_error(format!(
-            "modifier_index {} is not within the range of the item modifiers {}",
-            modifier_index.clone(),
-            inventory_item.modifiers.len()
-        )));
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::ModifierIndexOutOfRange,
+            format!(
+                "modifier_index {} is not within the range of the item modifiers {}",
+                modifier_index.clone(),
+                inventory_item.modifiers.len()
+            ),
+        ));
     }
 
+    let error_conditions = get_index_specifier_error_conditions(modifier_index);
+    let mut sacrifice_item_indexes =
+        expand_item_queries(game, inventory_index, sacrifice_item_indexes, &error_conditions);
+
     //Crafting cost
     let cost = execute_craft_reroll_modifier_calculate_cost(game, inventory_index);
     if sacrifice_item_indexes.len() < cost.into() {
-        return Err(MyError::create_execute_command_error(format!(
-            "craft_reroll_modifier needs {} items to be sacrificed but you only provided {}",
-            cost,
-            sacrifice_item_indexes.len()
-        )));
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::NotEnoughSacrifices,
+            format!(
+                "craft_reroll_modifier needs {} items to be sacrificed but you only provided {}",
+                cost,
+                sacrifice_item_indexes.len()
+            ),
+        ));
     }
 
     //Only need to sacrifice cost amount of items
     sacrifice_item_indexes.truncate(usize::from(cost));
 
-    let error_conditions = get_index_specifier_error_conditions(modifier_index);
     let calculated_sacrifice_item_indexes = calculate_absolute_item_indexes(
         game,
         inventory_index,
@@ -91,9 +106,11 @@ pub fn execute(
     )?;
 
     //Create item
-    let new_item_modifier = execute_craft(
-        &mut game.random_generator_state,
+    let (new_item_modifier, rolls) = execute_craft(
+        &mut game.random_generators.crafting,
         &inventory_item.crafting_info,
+        inventory_item.rarity,
+        &game.rules,
     )?;
 
     //Crafting cost
@@ -106,6 +123,7 @@ pub fn execute(
 
     Ok(ExecuteCraftRerollModifierReport {
         new_item: inventory_item.clone(),
+        rolls,
         new_cost: execute_craft_reroll_modifier_calculate_cost(game, inventory_index),
         paid_cost: cost,
     })
@@ -114,12 +132,15 @@ pub fn execute(
 fn get_index_specifier_error_conditions(modifier_index: usize) -> ErrorConditions {
     let enough_modifiers_condition = move |sacrifice_item_index: usize, sacrificed_item: &Item| {
         if sacrificed_item.modifiers.len() <= modifier_index {
-            Some(MyError::create_execute_command_error(format!(
-                "sacrifice_item_index {} need to have at least {} modifiers but it only had {}",
-                sacrifice_item_index,
-                modifier_index + 1,
-                sacrificed_item.modifiers.len()
-            )))
+            Some(MyError::create_execute_command_error(
+                ErrorCode::SacrificeNotEnoughModifiers,
+                format!(
+                    "sacrifice_item_index {} need to have at least {} modifiers but it only had {}",
+                    sacrifice_item_index,
+                    modifier_index + 1,
+                    sacrificed_item.modifiers.len()
+                ),
+            ))
         } else {
             None
         }
@@ -132,7 +153,14 @@ pub fn execute_craft_reroll_modifier_calculate_cost(
     inventory_index: usize,
 ) -> u16 {
     match &game.inventory[inventory_index] {
-        Some(item) => u16::try_from(item.modifiers.len()).unwrap_or(u16::MAX),
+        Some(item) => {
+            let cost = game
+                .rules
+                .costs
+                .reroll_modifier
+                .cost(item.modifiers.len() as u64);
+            u16::try_from(cost).unwrap_or(u16::MAX)
+        }
         None => 0,
     }
 }
@@ -3,8 +3,12 @@ mod tests_int {
     use crate::{
         command::craft_reroll_modifier::{execute, execute_craft_reroll_modifier_calculate_cost},
         generator::game::new_testing,
-        my_little_rpg_errors::MyError,
-        the_world::{index_specifier, item::test_util::create_item},
+        my_little_rpg_errors::{ErrorCode, MyError},
+        the_world::{
+            index_specifier,
+            item::test_util::create_item,
+            item_query::{ItemQuery, ModifierPartQuery},
+        },
         Game,
     };
 
@@ -19,6 +23,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::SacrificeIsCraftedItem,
                 "inventory_index 0 and index_specifier Absolute(0) cannot be the same".to_string()
             )),
             execute(
@@ -36,6 +41,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::SacrificeNotEnoughModifiers,
                 "sacrifice_item_index 1 need to have at least 2 modifiers but it only had 1"
                     .to_string()
             )),
@@ -55,6 +61,7 @@ mod tests_int {
         assert_eq!(2, execute_craft_reroll_modifier_calculate_cost(&game, 0));
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::NotEnoughSacrifices,
                 "craft_reroll_modifier needs 2 items to be sacrificed but you only provided 1"
                     .to_string()
             )),
@@ -84,6 +91,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::InventoryIndexOutOfRange,
                 "inventory_index 99 is not within the range of the inventory 10".to_string()
             )),
             execute(
@@ -95,6 +103,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::ModifierIndexOutOfRange,
                 "modifier_index 99 is not within the range of the item modifiers 2".to_string()
             )),
             execute(
@@ -106,6 +115,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::InventoryIndexOutOfRange,
                 "index_specifier Absolute(99) is not within the range of the inventory 10"
                     .to_string()
             )),
@@ -121,6 +131,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::InventorySlotEmpty,
                 "inventory_index 1 is empty.".to_string()
             )),
             execute(
@@ -135,6 +146,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::InventorySlotEmpty,
                 "index_specifier Absolute(1) is pointing at empty inventory slot.".to_string()
             )),
             execute(
@@ -149,6 +161,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::SacrificeAlreadySelected,
                 "index_specifier Absolute(8) is already present in calculated sacrifice indexes \
                  {8}"
                 .to_string()
@@ -223,6 +236,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::NoSacrificeFound,
                 "index_specifier: RelativePositive(1) did not find any items in inventory from \
                  relative point 1 until end of inventory."
                     .to_string()
@@ -300,6 +314,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::NoSacrificeFound,
                 "index_specifier: RelativeNegative(1) did not find any items in inventory from \
                  relative point 8 until start of inventory."
                     .to_string()
@@ -373,6 +388,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::NoSacrificeFound,
                 "index_specifier: RelativePositive(1) did not find any items in inventory from \
                  relative point 6 until end of inventory."
                     .to_string()
@@ -389,6 +405,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::NoSacrificeFound,
                 "index_specifier: RelativePositive(1) did not find any items in inventory from \
                  relative point 6 until end of inventory."
                     .to_string()
@@ -408,6 +425,7 @@ mod tests_int {
         game.inventory[2] = None;
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::NoSacrificeFound,
                 "index_specifier: RelativeNegative(1) did not find any items in inventory from \
                  relative point 4 until start of inventory."
                     .to_string()
@@ -461,4 +479,57 @@ mod tests_int {
             .is_ok());
         }
     }
+
+    fn flat_damage_query(damage_type: Option<&str>) -> index_specifier::IndexSpecifier {
+        index_specifier::IndexSpecifier::Query(ItemQuery::Gain(ModifierPartQuery {
+            name: "FlatDamage".into(),
+            argument: damage_type.map(Box::from),
+            comparison: None,
+        }))
+    }
+
+    #[test]
+    fn test_execute_craft_item_query() {
+        let mut game = new_testing(Some([1; 16]));
+        insert_game_in_inventory(&mut game);
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::NotEnoughSacrifices,
+                "craft_reroll_modifier needs 2 items to be sacrificed but you only provided 1"
+                    .to_string()
+            )),
+            execute(&mut game, 0, 0, vec![flat_damage_query(Some("Fire"))])
+        );
+        assert_eq!(10, game.inventory.iter().filter(|i| i.is_some()).count());
+
+        let result = execute(
+            &mut game,
+            0,
+            0,
+            vec![
+                flat_damage_query(Some("Fire")),
+                flat_damage_query(Some("Frost")),
+            ],
+        );
+        assert!(result.is_ok());
+        assert_eq!(None, game.inventory[2]);
+        assert_eq!(None, game.inventory[3]);
+        assert_eq!(8, game.inventory.iter().filter(|i| i.is_some()).count());
+
+        let result = execute(
+            &mut game,
+            0,
+            0,
+            vec![
+                index_specifier::IndexSpecifier::Absolute(5),
+                flat_damage_query(None),
+            ],
+        );
+        assert!(result.is_ok());
+        assert_eq!(None, game.inventory[1]);
+        assert_eq!(None, game.inventory[5]);
+        assert!(game.inventory[0].is_some());
+        assert_eq!(6, game.inventory.iter().filter(|i| i.is_some()).count());
+    }
 }
@@ -3,7 +3,7 @@ mod tests_int {
     use crate::{
         command::equip_swap::{execute_equip_item, execute_swap_equipped_item},
         generator::game::new_testing,
-        my_little_rpg_errors::MyError,
+        my_little_rpg_errors::{ErrorCode, MyError},
     };
 
     #[test]
@@ -28,6 +28,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::InventoryIndexOutOfRange,
                 "inventory_position 999 is not within the range of the inventory 9".to_string()
             )),
             execute_equip_item(&mut game, 999, 0)
@@ -46,6 +47,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::EquipmentIndexOutOfRange,
                 "equipped_item_position 999 is not within the range of the equipment slots 2"
                     .to_string()
             )),
@@ -78,6 +80,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::EquipmentIndexOutOfRange,
                 "equipped_item_position_1 999 is not within the range of the equipment slots 2"
                     .to_string()
             )),
@@ -97,6 +100,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::EquipmentIndexOutOfRange,
                 "equipped_item_position_2 999 is not within the range of the equipment slots 2"
                     .to_string()
             )),
@@ -116,6 +120,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::SameEquipmentIndex,
                 "equipped_item_position_1 0 cannot be the same as equipped_item_position_2 0"
                     .to_string()
             )),
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
 use crate::{
-    my_little_rpg_errors::MyError,
+    my_little_rpg_errors::{ErrorCode, MyError},
     the_world::{
         damage_types::DamageType,
         treasure_types::{pay_crafting_cost, TreasureType, TreasureType::Gold},
@@ -22,17 +22,15 @@ pub struct ExecuteExpandElementsReport {
     leftover_spending_treasure: HashMap<TreasureType, u64>,
 }
 
-pub fn execute_expand_elements_json(game: &mut Game) -> Value {
-    match execute(game) {
-        Ok(result) => json!(result),
-        Err(result) => json!(result),
-    }
+pub fn execute_expand_elements_json(game: &mut Game) -> Result<Value, MyError> {
+    execute(game).map(|result| json!(result))
 }
 
 pub fn execute(game: &mut Game) -> Result<ExecuteExpandElementsReport, MyError> {
     let difficulty_max_resistance_number = game.difficulty.max_resistance.len();
     if difficulty_max_resistance_number >= DamageType::get_all().len() {
         return Err(MyError::create_execute_command_error(
+            ErrorCode::MaximumElementsReached,
             "Already at maximum elements.".to_string(),
         ));
     }
@@ -58,6 +56,12 @@ pub fn execute(game: &mut Game) -> Result<ExecuteExpandElementsReport, MyError>
     })
 }
 
-pub fn execute_expand_elements_calculate_cost(game: &mut Game) -> HashMap<TreasureType, u64> {
-    HashMap::from([(Gold, (game.difficulty.max_resistance.len() * 10) as u64)])
+pub fn execute_expand_elements_calculate_cost(game: &Game) -> HashMap<TreasureType, u64> {
+    HashMap::from([(
+        Gold,
+        game.rules
+            .costs
+            .expand_elements
+            .cost(game.difficulty.max_resistance.len() as u64),
+    )])
 }
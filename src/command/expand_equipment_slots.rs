@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
 use crate::{
-    my_little_rpg_errors::MyError,
+    my_little_rpg_errors::{ErrorCode, MyError},
     the_world::{
         item::Item,
         treasure_types::{pay_crafting_cost, TreasureType, TreasureType::Gold},
@@ -22,19 +22,16 @@ pub struct ExecuteExpandEquipmentSlotsReport {
     leftover_spending_treasure: HashMap<TreasureType, u64>,
 }
 
-pub fn execute_expand_equipment_slots_json(game: &mut Game) -> Value {
-    match execute(game) {
-        Ok(result) => json!(result),
-        Err(result) => json!(result),
-    }
+pub fn execute_expand_equipment_slots_json(game: &mut Game) -> Result<Value, MyError> {
+    execute(game).map(|result| json!(result))
 }
 
 pub fn execute(game: &mut Game) -> Result<ExecuteExpandEquipmentSlotsReport, MyError> {
     let Some(first_item_index) = game.inventory.iter().position(Option::is_some) else {
-            return Err(MyError::create_execute_command_error(
-                "No item in inventory to equip in new item slot. Whole inventory is empty."
-                    .to_string(),
-            ))
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::InventoryEmpty,
+            "No item in inventory to equip in new item slot. Whole inventory is empty.".to_string(),
+        ));
     };
 
     //Crafting cost
@@ -53,5 +50,11 @@ pub fn execute(game: &mut Game) -> Result<ExecuteExpandEquipmentSlotsReport, MyE
 }
 
 pub fn execute_expand_equipment_slots_calculate_cost(game: &Game) -> HashMap<TreasureType, u64> {
-    HashMap::from([(Gold, (game.equipped_items.len() + 1).pow(5) as u64)])
+    HashMap::from([(
+        Gold,
+        game.rules
+            .costs
+            .expand_equipment_slots
+            .cost(game.equipped_items.len() as u64),
+    )])
 }
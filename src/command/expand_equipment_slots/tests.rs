@@ -3,7 +3,7 @@ mod tests_int {
     use crate::{
         command::expand_equipment_slots::execute,
         generator::game::new,
-        my_little_rpg_errors::MyError,
+        my_little_rpg_errors::{ErrorCode, MyError},
         the_world::{item::test_util::create_item, treasure_types::TreasureType::Gold},
     };
 
@@ -14,7 +14,9 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
-                "No item in inventory to equip in new item slot. Whole inventory is empty.".to_string()
+                ErrorCode::InventoryEmpty,
+                "No item in inventory to equip in new item slot. Whole inventory is empty."
+                    .to_string()
             )),
             execute(&mut game)
         );
@@ -27,6 +29,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::CantPayCraftingCost,
                 "Cant pay the crafting cost, the cost is {Gold: 32} and you only have {}"
                     .to_string()
             )),
@@ -48,6 +51,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::InventoryEmpty,
                 "No item in inventory to equip in new item slot. Whole inventory is empty."
                     .to_string()
             )),
@@ -56,6 +60,7 @@ mod tests_int {
         game.inventory.push(Some(item));
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::CantPayCraftingCost,
                 "Cant pay the crafting cost, the cost is {Gold: 3125} and you only have {Gold: 1}"
                     .to_string()
             )),
@@ -23,11 +23,8 @@ pub struct ExecuteExpandMaxElementReport {
     leftover_spending_treasure: HashMap<TreasureType, u64>,
 }
 
-pub fn execute_expand_max_element_json(game: &mut Game) -> Value {
-    match execute(game) {
-        Ok(result) => json!(result),
-        Err(result) => json!(result),
-    }
+pub fn execute_expand_max_element_json(game: &mut Game) -> Result<Value, MyError> {
+    execute(game).map(|result| json!(result))
 }
 
 pub fn execute(game: &mut Game) -> Result<ExecuteExpandMaxElementReport, MyError> {
@@ -37,11 +34,11 @@ pub fn execute(game: &mut Game) -> Result<ExecuteExpandMaxElementReport, MyError
 
     let max_resistance_diff: u64 = crafting_cost
         .values()
-        .fold(0u64, |r, s| r.checked_add(*s).unwrap_or(u64::MAX));
+        .fold(0u64, |r, s| r.saturating_add(*s));
 
     //Increase max of existing element
     *get_mut_random_attack_type(
-        &mut game.random_generator_state,
+        &mut game.random_generators.places,
         &mut game.difficulty.max_resistance,
         &|_,_| true
     )?
@@ -55,10 +52,14 @@ pub fn execute(game: &mut Game) -> Result<ExecuteExpandMaxElementReport, MyError
     })
 }
 
-pub fn execute_expand_max_element_calculate_cost(game: &mut Game) -> HashMap<TreasureType, u64> {
+pub fn execute_expand_max_element_calculate_cost(game: &Game) -> HashMap<TreasureType, u64> {
+    let average_max_resistance = game.difficulty.max_resistance.values().sum::<u64>()
+        / game.difficulty.max_resistance.len() as u64;
     HashMap::from([(
         Gold,
-        game.difficulty.max_resistance.values().sum::<u64>()
-            / game.difficulty.max_resistance.len() as u64,
+        game.rules
+            .costs
+            .expand_max_element
+            .cost(average_max_resistance),
     )])
 }
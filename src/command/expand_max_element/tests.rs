@@ -3,7 +3,7 @@ mod tests_int {
     use crate::{
         command::{expand_max_element::execute, r#move::execute as execute_move_command},
         generator::game::{new, new_testing},
-        my_little_rpg_errors::MyError,
+        my_little_rpg_errors::{ErrorCode, MyError},
         the_world::treasure_types::TreasureType::Gold,
     };
 
@@ -15,6 +15,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::CantPayCraftingCost,
                 "Cant pay the crafting cost, the cost is {Gold: 2} and you only have {}"
                     .to_string()
             )),
@@ -38,6 +39,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::CantPayCraftingCost,
                 "Cant pay the crafting cost, the cost is {Gold: 256} and you only have {Gold: 46}"
                     .to_string()
             )),
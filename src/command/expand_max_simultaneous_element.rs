@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
 use crate::{
-    my_little_rpg_errors::MyError,
+    my_little_rpg_errors::{ErrorCode, MyError},
     the_world::treasure_types::{pay_crafting_cost, TreasureType, TreasureType::Gold},
     Game,
 };
@@ -19,23 +19,23 @@ pub struct ExecuteExpandMaxSimultaneousElementReport {
     leftover_spending_treasure: HashMap<TreasureType, u64>,
 }
 
-pub fn execute_expand_max_simultaneous_element_json(game: &mut Game) -> Value {
-    match execute(game) {
-        Ok(result) => json!(result),
-        Err(result) => json!(result),
-    }
+pub fn execute_expand_max_simultaneous_element_json(game: &mut Game) -> Result<Value, MyError> {
+    execute(game).map(|result| json!(result))
 }
 
 pub fn execute(game: &mut Game) -> Result<ExecuteExpandMaxSimultaneousElementReport, MyError> {
     if (game.difficulty.max_simultaneous_resistances as usize)
         >= game.difficulty.max_resistance.len()
     {
-        return Err(MyError::create_execute_command_error(format!(
-            "max_simultaneous_resistances {} is already equal to number of active resistances {}. \
-             Consider calling ExpandElements.",
-            game.difficulty.max_simultaneous_resistances,
-            game.difficulty.max_resistance.len()
-        )));
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::MaximumSimultaneousElementsReached,
+            format!(
+                "max_simultaneous_resistances {} is already equal to number of active resistances \
+                 {}. Consider calling ExpandElements.",
+                game.difficulty.max_simultaneous_resistances,
+                game.difficulty.max_resistance.len()
+            ),
+        ));
     }
 
     //Crafting cost
@@ -54,10 +54,13 @@ pub fn execute(game: &mut Game) -> Result<ExecuteExpandMaxSimultaneousElementRep
 }
 
 pub fn execute_expand_max_simultaneous_element_calculate_cost(
-    game: &mut Game
+    game: &Game
 ) -> HashMap<TreasureType, u64> {
     HashMap::from([(
         Gold,
-        u64::from(game.difficulty.max_simultaneous_resistances) * 10,
+        game.rules
+            .costs
+            .expand_max_simultaneous_element
+            .cost(u64::from(game.difficulty.max_simultaneous_resistances)),
     )])
 }
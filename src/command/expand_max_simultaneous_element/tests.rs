@@ -6,7 +6,7 @@ mod tests_int {
             expand_max_simultaneous_element::execute as execute_expand_max_simultaneous_element,
         },
         generator::game::new,
-        my_little_rpg_errors::MyError,
+        my_little_rpg_errors::{ErrorCode, MyError},
         the_world::treasure_types::TreasureType::Gold,
     };
 
@@ -18,6 +18,7 @@ mod tests_int {
         assert_eq!(1, game.difficulty.min_resistance.len());
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::MaximumSimultaneousElementsReached,
                 "max_simultaneous_resistances 1 is already equal to number of active resistances \
                  1. Consider calling ExpandElements."
                     .to_string()
@@ -29,6 +30,7 @@ mod tests_int {
         assert!(execute_expand_elements(&mut game).is_ok());
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::CantPayCraftingCost,
                 "Cant pay the crafting cost, the cost is {Gold: 10} and you only have {Gold: 0}"
                     .to_string()
             )),
@@ -51,6 +53,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::MaximumSimultaneousElementsReached,
                 "max_simultaneous_resistances 2 is already equal to number of active resistances \
                  2. Consider calling ExpandElements."
                     .to_string()
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
 use crate::{
-    my_little_rpg_errors::MyError,
+    my_little_rpg_errors::{ErrorCode, MyError},
     the_world::{
         damage_types::get_mut_random_attack_type,
         difficulty::Difficulty,
@@ -23,11 +23,8 @@ pub struct ExecuteExpandMinElementReport {
     leftover_spending_treasure: HashMap<TreasureType, u64>,
 }
 
-pub fn execute_expand_min_element_json(game: &mut Game) -> Value {
-    match execute(game) {
-        Ok(result) => json!(result),
-        Err(result) => json!(result),
-    }
+pub fn execute_expand_min_element_json(game: &mut Game) -> Result<Value, MyError> {
+    execute(game).map(|result| json!(result))
 }
 
 pub fn execute(game: &mut Game) -> Result<ExecuteExpandMinElementReport, MyError> {
@@ -37,11 +34,11 @@ pub fn execute(game: &mut Game) -> Result<ExecuteExpandMinElementReport, MyError
 
     let min_resistance_diff: u64 = crafting_cost
         .values()
-        .fold(0u64, |r, s| r.checked_add(*s).unwrap_or(u64::MAX));
+        .fold(0u64, |r, s| r.saturating_add(*s));
 
     //Increase max of existing element
     *get_mut_random_attack_type(
-        &mut game.random_generator_state,
+        &mut game.random_generators.places,
         &mut game.difficulty.min_resistance,
         &|attack_type, amount| {
             let max_resistance_amount =
@@ -54,6 +51,7 @@ pub fn execute(game: &mut Game) -> Result<ExecuteExpandMinElementReport, MyError
     )
     .map_err(|_e| {
         MyError::create_execute_command_error(
+            ErrorCode::NoElementAvailable,
             "There are no element minimum values that can be upgraded, consider expanding a max \
              element value."
                 .to_string(),
@@ -69,10 +67,14 @@ pub fn execute(game: &mut Game) -> Result<ExecuteExpandMinElementReport, MyError
     })
 }
 
-pub fn execute_expand_min_element_calculate_cost(game: &mut Game) -> HashMap<TreasureType, u64> {
+pub fn execute_expand_min_element_calculate_cost(game: &Game) -> HashMap<TreasureType, u64> {
+    let average_min_resistance = game.difficulty.min_resistance.values().sum::<u64>()
+        / game.difficulty.min_resistance.len() as u64;
     HashMap::from([(
         Gold,
-        game.difficulty.min_resistance.values().sum::<u64>()
-            / game.difficulty.min_resistance.len() as u64,
+        game.rules
+            .costs
+            .expand_min_element
+            .cost(average_min_resistance),
     )])
 }
@@ -6,7 +6,7 @@ mod tests_int {
             expand_min_element::execute as execute_expand_min_element, r#move::execute,
         },
         generator::game::{new, new_testing},
-        my_little_rpg_errors::MyError,
+        my_little_rpg_errors::{ErrorCode, MyError},
         the_world::treasure_types::TreasureType::Gold,
     };
 
@@ -18,6 +18,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::CantPayCraftingCost,
                 "Cant pay the crafting cost, the cost is {Gold: 1} and you only have {}"
                     .to_string()
             )),
@@ -30,6 +31,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::NoElementAvailable,
                 "There are no element minimum values that can be upgraded, consider expanding a \
                  max element value."
                     .to_string()
@@ -45,6 +47,7 @@ mod tests_int {
         assert!(execute_expand_min_element(&mut game).is_ok());
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::NoElementAvailable,
                 "There are no element minimum values that can be upgraded, consider expanding a \
                  max element value."
                     .to_string()
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
 use crate::{
-    my_little_rpg_errors::MyError,
+    my_little_rpg_errors::{ErrorCode, MyError},
     the_world::treasure_types::{pay_crafting_cost, TreasureType, TreasureType::Gold},
     Game,
 };
@@ -19,11 +19,8 @@ pub struct ExecuteExpandMaxSimultaneousElementReport {
     leftover_spending_treasure: HashMap<TreasureType, u64>,
 }
 
-pub fn execute_expand_min_simultaneous_element_json(game: &mut Game) -> Value {
-    match execute_expand_min_simultaneous_element(game) {
-        Ok(result) => json!(result),
-        Err(result) => json!(result),
-    }
+pub fn execute_expand_min_simultaneous_element_json(game: &mut Game) -> Result<Value, MyError> {
+    execute_expand_min_simultaneous_element(game).map(|result| json!(result))
 }
 
 pub fn execute_expand_min_simultaneous_element(
@@ -31,12 +28,15 @@ pub fn execute_expand_min_simultaneous_element(
 ) -> Result<ExecuteExpandMaxSimultaneousElementReport, MyError> {
     if game.difficulty.min_simultaneous_resistances >= game.difficulty.max_simultaneous_resistances
     {
-        return Err(MyError::create_execute_command_error(format!(
-            "execute_expand_min_simultaneous_element {} is already equal to \
-             max_simultaneous_resistances {}. Consider calling ExpandMaxSimultaneousElement.",
-            game.difficulty.max_simultaneous_resistances,
-            game.difficulty.max_resistance.len()
-        )));
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::MinimumSimultaneousElementsReached,
+            format!(
+                "execute_expand_min_simultaneous_element {} is already equal to \
+                 max_simultaneous_resistances {}. Consider calling ExpandMaxSimultaneousElement.",
+                game.difficulty.max_simultaneous_resistances,
+                game.difficulty.max_resistance.len()
+            ),
+        ));
     }
 
     //Crafting cost
@@ -55,10 +55,13 @@ pub fn execute_expand_min_simultaneous_element(
 }
 
 pub fn execute_expand_min_simultaneous_element_calculate_cost(
-    game: &mut Game
+    game: &Game
 ) -> HashMap<TreasureType, u64> {
     HashMap::from([(
         Gold,
-        u64::from(game.difficulty.min_simultaneous_resistances) * 10,
+        game.rules
+            .costs
+            .expand_min_simultaneous_element
+            .cost(u64::from(game.difficulty.min_simultaneous_resistances)),
     )])
 }
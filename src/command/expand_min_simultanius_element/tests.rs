@@ -7,7 +7,7 @@ mod tests_int {
             expand_min_simultanius_element::execute_expand_min_simultaneous_element,
         },
         generator::game::new,
-        my_little_rpg_errors::MyError,
+        my_little_rpg_errors::{ErrorCode, MyError},
         the_world::treasure_types::TreasureType::Gold,
     };
 
@@ -20,6 +20,7 @@ mod tests_int {
         assert_eq!(1, game.difficulty.min_resistance.len());
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::MinimumSimultaneousElementsReached,
                 "execute_expand_min_simultaneous_element 1 is already equal to \
                  max_simultaneous_resistances 1. Consider calling ExpandMaxSimultaneousElement."
                     .to_string()
@@ -33,6 +34,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::CantPayCraftingCost,
                 "Cant pay the crafting cost, the cost is {Gold: 10} and you only have {Gold: 0}"
                     .to_string()
             )),
@@ -57,6 +59,7 @@ mod tests_int {
 
         assert_eq!(
             Err(MyError::create_execute_command_error(
+                ErrorCode::MinimumSimultaneousElementsReached,
                 "execute_expand_min_simultaneous_element 2 is already equal to \
                  max_simultaneous_resistances 2. Consider calling ExpandMaxSimultaneousElement."
                     .to_string()
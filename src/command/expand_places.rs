@@ -23,11 +23,8 @@ pub struct ExecuteExpandPlacesReport {
     leftover_spending_treasure: HashMap<TreasureType, u64>,
 }
 
-pub fn execute_expand_places_json(game: &mut Game) -> Value {
-    match execute(game) {
-        Ok(result) => json!(result),
-        Err(result) => json!(result),
-    }
+pub fn execute_expand_places_json(game: &mut Game) -> Result<Value, MyError> {
+    execute(game).map(|result| json!(result))
 }
 
 pub fn execute(game: &mut Game) -> Result<ExecuteExpandPlacesReport, MyError> {
@@ -47,6 +44,12 @@ pub fn execute(game: &mut Game) -> Result<ExecuteExpandPlacesReport, MyError> {
     })
 }
 
-pub fn execute_expand_places_calculate_cost(game: &mut Game) -> HashMap<TreasureType, u64> {
-    HashMap::from([(Gold, (game.places.len() * 10) as u64)])
+pub fn execute_expand_places_calculate_cost(game: &Game) -> HashMap<TreasureType, u64> {
+    HashMap::from([(
+        Gold,
+        game.rules
+            .costs
+            .expand_places
+            .cost(game.places.len() as u64),
+    )])
 }
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    my_little_rpg_errors::MyError,
+    the_world::{
+        stash::{STARTING_STASH_CAPACITY, STASH_CAPACITY_EXPANSION},
+        treasure_types::{pay_crafting_cost, TreasureType, TreasureType::Gold},
+    },
+    Game,
+};
+
+mod tests;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteExpandStashReport {
+    new_capacity: usize,
+    paid_cost: HashMap<TreasureType, u64>,
+    new_cost: HashMap<TreasureType, u64>,
+    leftover_spending_treasure: HashMap<TreasureType, u64>,
+}
+
+pub fn execute_expand_stash_json(game: &mut Game) -> Result<Value, MyError> {
+    execute(game).map(|result| json!(result))
+}
+
+pub fn execute(game: &mut Game) -> Result<ExecuteExpandStashReport, MyError> {
+    //Crafting cost
+    let crafting_cost = execute_expand_stash_calculate_cost(game);
+    pay_crafting_cost(game, &crafting_cost)?;
+
+    game.stash.capacity += STASH_CAPACITY_EXPANSION;
+
+    Ok(ExecuteExpandStashReport {
+        new_capacity: game.stash.capacity,
+        paid_cost: crafting_cost.clone(),
+        new_cost: execute_expand_stash_calculate_cost(game),
+        leftover_spending_treasure: game.treasure.clone(),
+    })
+}
+
+pub fn execute_expand_stash_calculate_cost(game: &Game) -> HashMap<TreasureType, u64> {
+    let expansions =
+        game.stash.capacity.saturating_sub(STARTING_STASH_CAPACITY) / STASH_CAPACITY_EXPANSION;
+    HashMap::from([(Gold, game.rules.costs.expand_stash.cost(expansions as u64))])
+}
@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod tests_int {
+    use std::collections::HashMap;
+
+    use crate::{
+        command::expand_stash::{execute, execute_expand_stash_calculate_cost},
+        generator::game::new,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        the_world::treasure_types::TreasureType::Gold,
+    };
+
+    #[test]
+    fn test_execute_expand_stash() {
+        let mut game = new(Some([1; 16]));
+        assert_eq!(10, game.stash.capacity);
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::CantPayCraftingCost,
+                "Cant pay the crafting cost, the cost is {Gold: 16} and you only have {}"
+                    .to_string()
+            )),
+            execute(&mut game)
+        );
+        assert_eq!(10, game.stash.capacity);
+
+        game.treasure.insert(Gold, 100);
+        assert!(execute(&mut game).is_ok());
+        assert_eq!(20, game.stash.capacity);
+        assert_eq!(Some(&84), game.treasure.get(&Gold));
+        assert_eq!(
+            HashMap::from([(Gold, 81)]),
+            execute_expand_stash_calculate_cost(&game)
+        );
+    }
+}
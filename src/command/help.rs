@@ -16,7 +16,9 @@ pub fn execute_help_json() -> Value {
 pub fn execute() -> ExecuteHelpReport {
     let tutorial = "Use state, see what you like. Then get Moving, try Move 0. Do some more \
                     movement, earn some treasure. Use the treasure to craft items and expand \
-                    difficulty. Have fun."
+                    difficulty. Items can be given by their index, by their id like #12 or by the \
+                    name given with NameItem, ids and names stay the same when items move. Have \
+                    fun."
         .into();
 
     let mut commands = Vec::new();
@@ -47,7 +49,9 @@ fn execute_help_for_command(command: &Command) -> &'static str {
              Z is a comma seperated list of indexes for items in the inventory to sacrifice to pay \
              the cost of the command, each item need to have at least the same amount of modifiers \
              as the index of the modifier being rerolled. Z can contain relative indexes prefixed \
-             with + or -, they are relative to X."
+             with + or -, they are relative to X. Z can also be, or be followed by, where and an \
+             item query like: where gain:FlatDamage(Fire)>5 and cost:none and modifiers>=3, which \
+             offers every matching item as a sacrifice."
         }
         Command::ExpandPlaces => "ExpandPlaces: Expand the number of places you can move too.",
         Command::ExpandElements => {
@@ -87,20 +91,129 @@ fn execute_help_for_command(command: &Command) -> &'static str {
              comma seperated list of indexes for items in the inventory to sacrifice to pay the \
              cost of the command, they each need to have at least the same amount of modifiers as \
              the item being upgraded. Z can contain relative indexes prefixed with + or -, they \
-             are relative to X."
+             are relative to X. Y can also be, or be followed by, where and an item query, like \
+             for RerollModifier."
         }
         Command::Help => "Help: Get the help text that you are reading right now.",
         Command::ReorderInventory => {
             "ReorderInventory: Arranges all items in the inventory so there are no more gaps in \
              indexes between items."
         }
-        Command::SaveTheWorld(_, _) => {
+        Command::SortInventory(_, _, _) => {
+            "SortInventory X: Sort the items in the inventory by X, which is modifiers, \
+             flat_damage, flat_damage:Y for damage type Y, cost_burden or places_count. Add \
+             descending to sort from high to low, and empty_last to keep the empty slots at the \
+             end instead of removing them. Items with the same value keep their order."
+        }
+        Command::StashItem(_, _) => {
+            "StashItem X Y: Move the item in the inventory at index X to the end of the stash tab \
+             named Y. Stashed items can not be sacrificed or salvaged, crafting only uses the \
+             inventory."
+        }
+        Command::UnstashItem(_, _) => {
+            "UnstashItem X Y: Move the item at index Y of the stash tab named X back to the first \
+             empty slot of the inventory. A stash tab without items is removed."
+        }
+        Command::ExpandStash => {
+            "ExpandStash: Pay gold to make room for 10 more items in the stash. The stash starts \
+             with room for 10 items."
+        }
+        Command::NameItem(_, _) => {
+            "NameItem X Y: Name the item in the inventory at index X, so it can be given as Y \
+             instead of its index. Y is one word starting with a letter. Without Y the name is \
+             removed."
+        }
+        Command::NoteItem(_, _) => {
+            "NoteItem X Y: Write the notes Y on the item in the inventory at index X. Without Y \
+             the notes are removed."
+        }
+        Command::UpgradeRarity(_, _) => {
+            "UpgradeRarity X Y: Raise the rarity of the item in the inventory at index X by one \
+             tier, from Common to Magic, Rare and Legendary. Rarer items have more modifier slots \
+             and roll bigger gains. Y is a comma seperated list of indexes for items in the \
+             inventory to sacrifice to pay the cost of the command, they each need to be at least \
+             as rare as the item being upgraded. Y can use relative indexes and where like for \
+             AddModifier."
+        }
+        Command::SaveTheWorld(_, _, _) => {
             "SaveTheWorld X Optional(Y): Save the world! So you can load it later; Stay saved. X \
-             is save game name, Y is optional save game path."
+             is save game name, Y is optional save game path. Save game paths are folders inside \
+             the save games folder of the server. Add binary at the end to save it smaller and not \
+             readable by hand."
         }
         Command::LoadTheWorld(_, _) => {
             "LoadTheWorld X Optional(Y): Load a saved game. X is save game name, Y is optional \
              save game path."
         }
+        Command::ListSaves(_) => {
+            "ListSaves Optional(Y): List the save games and when they were saved. Y is optional \
+             save game path."
+        }
+        Command::DeleteSave(_, _) => {
+            "DeleteSave X Optional(Y): Delete the save game named X. Y is optional save game path."
+        }
+        Command::RenameSave(_, _, _) => {
+            "RenameSave X Z Optional(Y): Rename the save game named X to Z, without overwriting \
+             another save game. Y is optional save game path."
+        }
+        Command::SaveInfo(_, _) => {
+            "SaveInfo X Optional(Y): Show the seed, statistics and difficulty of the save game \
+             named X and when it was saved. Y is optional save game path."
+        }
+        Command::NewGame(_, _) => {
+            "NewGame X Optional(Y): Create a new game named X and play it on this connection. Y is \
+             an optional seed of 32 hexidecimals."
+        }
+        Command::SelectGame(_) => {
+            "SelectGame X: Play the game named X on this connection. Every connection starts \
+             playing the game named default."
+        }
+        Command::ListGames => "ListGames: List all games on the server.",
+        Command::Undo => {
+            "Undo: Undo the last command that changed the game. How many commands can be undone is \
+             set when starting the server."
+        }
+        Command::Redo => "Redo: Redo the last command that was undone.",
+        Command::Simulate(_) => {
+            "Simulate X: Show if your equipped items would beat the place with index X, how much \
+             damage you have to spare or are missing against each resistance and which item costs \
+             could not be paid. Without X every place is simulated. Nothing in the game changes."
+        }
+        Command::OptimizeLoadout(_, _) => {
+            "OptimizeLoadout X: Search the equipped and inventory items for the loadout, in the \
+             best order, that beats place X, or the most places when X is all. Ties are broken by \
+             the gold won. Add apply, like OptimizeLoadout all apply, to also equip the loadout."
+        }
+        Command::Places(_) => {
+            "Places: List the places, or a page of them with offset X and limit Y, like Places \
+             offset 20 limit 10. Filter with resistance X or no_resistance X, where X is a damage \
+             type like Fire."
+        }
+        Command::Equipment(_) => {
+            "Equipment: List the equipped items, or a page of them with offset X and limit Y. \
+             Filter with min_modifiers X, max_modifiers X or where followed by an item query, like \
+             for RerollModifier."
+        }
+        Command::Inventory(_) => {
+            "Inventory: List the inventory items, or a page of them with offset X and limit Y, \
+             like Inventory min_modifiers 3 limit 10. Filter with min_modifiers X, max_modifiers X \
+             or where followed by an item query, like Inventory limit 10 where cost:none."
+        }
+        Command::Salvage(_, _) => {
+            "Salvage X: Destroy the items in the inventory at the comma seperated indexes X for \
+             gold. Every modifier of an item is worth more gold the harder the places were when it \
+             was found. X can also be, or be followed by, where and an item query, like Salvage \
+             where modifiers<2, to salvage every matching item."
+        }
+        Command::Lock(_) => {
+            "Lock X: Lock the item in the inventory at index X, so it can not be sacrificed or \
+             salvaged. Relative indexes and item queries skip locked items."
+        }
+        Command::Unlock(_) => "Unlock X: Unlock the item in the inventory at index X.",
+        Command::Preview(_) => {
+            "Preview X: Show what command X would do, without changing the game. Example: Preview \
+             Move 0. Commands that do more than change the game, like SaveTheWorld, DeleteSave or \
+             NewGame, can not be previewed."
+        }
     }
 }
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    my_little_rpg_errors::{ErrorCode, MyError},
+    Game,
+};
+
+mod tests;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteLockItemReport {
+    inventory_index: usize,
+    locked: bool,
+}
+
+pub fn execute_lock_item_json(
+    game: &mut Game,
+    inventory_index: usize,
+    locked: bool,
+) -> Result<Value, MyError> {
+    execute(game, inventory_index, locked).map(|result| json!(result))
+}
+
+// A locked item can not be sacrificed or salvaged, relative indexes and item queries skip it.
+pub fn execute(
+    game: &mut Game,
+    inventory_index: usize,
+    locked: bool,
+) -> Result<ExecuteLockItemReport, MyError> {
+    if game.inventory.len() <= inventory_index {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::InventoryIndexOutOfRange,
+            format!(
+                "inventory_index {} is not within the range of the inventory {}",
+                inventory_index,
+                game.inventory.len()
+            ),
+        ));
+    }
+    let item = game.inventory[inventory_index].as_mut().ok_or_else(|| {
+        MyError::create_execute_command_error(
+            ErrorCode::InventorySlotEmpty,
+            format!("inventory_index {inventory_index} is empty."),
+        )
+    })?;
+    item.locked = locked;
+
+    Ok(ExecuteLockItemReport {
+        inventory_index,
+        locked,
+    })
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests_int {
+    use crate::{
+        command::{craft_reroll_modifier, lock_item::execute, salvage},
+        generator::game::new_testing,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        the_world::{
+            index_specifier::IndexSpecifier,
+            item::{test_util::create_item, Item},
+            item_query::ItemQuery,
+        },
+    };
+
+    #[test]
+    fn test_execute_lock_item() {
+        let mut game = new_testing(Some([1; 16]));
+        game.inventory[1] = None;
+
+        assert!(execute(&mut game, 0, true).is_ok());
+        assert!(game.inventory[0].as_ref().unwrap().locked);
+        assert!(execute(&mut game, 0, false).is_ok());
+        assert!(!game.inventory[0].as_ref().unwrap().locked);
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::InventorySlotEmpty,
+                "inventory_index 1 is empty.".to_string()
+            )),
+            execute(&mut game, 1, true)
+        );
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::InventoryIndexOutOfRange,
+                "inventory_index 9 is not within the range of the inventory 9".to_string()
+            )),
+            execute(&mut game, 9, true)
+        );
+    }
+
+    #[test]
+    fn test_items_saved_before_locking_are_unlocked() {
+        let game = new_testing(Some([1; 16]));
+        let mut saved_item = serde_json::to_value(&game.equipped_items[0]).unwrap();
+        saved_item.as_object_mut().unwrap().remove("locked");
+
+        let item: Item = serde_json::from_value(saved_item).unwrap();
+        assert_eq!(game.equipped_items[0], item);
+        assert!(!item.locked);
+    }
+
+    #[test]
+    fn test_locked_items_are_not_sacrificed() {
+        let mut game = new_testing(Some([1; 16]));
+        game.inventory.insert(0, Some(create_item(&game)));
+        execute(&mut game, 1, true).unwrap();
+        execute(&mut game, 3, true).unwrap();
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::ItemLocked,
+                "index_specifier Absolute(1) is pointing at a locked item, unlock it to sacrifice \
+                 it."
+                .to_string()
+            )),
+            craft_reroll_modifier::execute(
+                &mut game,
+                0,
+                0,
+                vec![IndexSpecifier::Absolute(1), IndexSpecifier::Absolute(2)]
+            )
+        );
+
+        let result = craft_reroll_modifier::execute(
+            &mut game,
+            0,
+            0,
+            vec![
+                IndexSpecifier::RelativePositive(1),
+                IndexSpecifier::RelativePositive(1),
+            ],
+        );
+        assert!(result.is_ok());
+        assert!(game.inventory[1].is_some());
+        assert_eq!(None, game.inventory[2]);
+        assert!(game.inventory[3].is_some());
+        assert_eq!(None, game.inventory[4]);
+
+        let result = craft_reroll_modifier::execute(
+            &mut game,
+            0,
+            0,
+            vec![IndexSpecifier::Query(ItemQuery::Not(Box::new(
+                ItemQuery::NoGains,
+            )))],
+        );
+        assert!(result.is_ok());
+        assert!(game.inventory[1].is_some());
+        assert!(game.inventory[3].is_some());
+        assert_eq!(None, game.inventory[5]);
+        assert_eq!(None, game.inventory[6]);
+    }
+
+    #[test]
+    fn test_locked_items_are_not_salvaged() {
+        let mut game = new_testing(Some([1; 16]));
+        execute(&mut game, 0, true).unwrap();
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::ItemLocked,
+                "inventory_index 0 is locked, unlock it to salvage it.".to_string()
+            )),
+            salvage::execute(&mut game, vec![0], None)
+        );
+
+        let result = salvage::execute(&mut game, Vec::new(), Some(ItemQuery::NoCosts));
+        assert!(result.is_ok());
+        assert!(game.inventory[0].is_some());
+        assert_eq!(
+            1,
+            game.inventory.iter().filter(|item| item.is_some()).count()
+        );
+    }
+}
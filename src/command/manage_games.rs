@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    generator::game::new_with_rules,
+    my_little_rpg_errors::{ErrorCode, MyError},
+    parser::hex_encoder::encode_hex,
+    the_world::game_registry::{
+        lock_game, GameRegistry, SelectedGame, SharedGame, SESSION_GAME_PREFIX,
+    },
+};
+
+mod tests;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct GameSummary {
+    name: Box<str>,
+    seed: Box<str>,
+    moves_count: u64,
+    selected: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteSelectGameReport {
+    selected_game: GameSummary,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteListGamesReport {
+    games: Vec<GameSummary>,
+}
+
+pub fn execute_new_game_json(
+    game_registry: &GameRegistry,
+    selected_game: &mut SelectedGame,
+    session: u64,
+    name: &str,
+    seed: Option<[u8; 16]>,
+) -> Result<Value, MyError> {
+    execute_new_game(game_registry, selected_game, session, name, seed).map(|result| json!(result))
+}
+
+pub fn execute_new_game(
+    game_registry: &GameRegistry,
+    selected_game: &mut SelectedGame,
+    session: u64,
+    name: &str,
+    seed: Option<[u8; 16]>,
+) -> Result<ExecuteSelectGameReport, MyError> {
+    if name.starts_with(SESSION_GAME_PREFIX) {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::InvalidGameName,
+            format!(
+                "The game name {name:?} can not start with {SESSION_GAME_PREFIX}, those names are \
+                 kept for the games sessions start out playing."
+            ),
+        ));
+    }
+    let mut game = new_with_rules(seed, game_registry.rules());
+    game.save_root = game_registry.save_root();
+    let game = game_registry.insert(name, game, Some(session))?;
+    *selected_game = SelectedGame {
+        name: name.into(),
+        game,
+    };
+
+    Ok(ExecuteSelectGameReport {
+        selected_game: summarize(selected_game, &selected_game.name, &selected_game.game),
+    })
+}
+
+pub fn execute_select_game_json(
+    game_registry: &GameRegistry,
+    selected_game: &mut SelectedGame,
+    session: u64,
+    name: &str,
+) -> Result<Value, MyError> {
+    execute_select_game(game_registry, selected_game, session, name).map(|result| json!(result))
+}
+
+pub fn execute_select_game(
+    game_registry: &GameRegistry,
+    selected_game: &mut SelectedGame,
+    session: u64,
+    name: &str,
+) -> Result<ExecuteSelectGameReport, MyError> {
+    let game = game_registry.select(name, session)?;
+    *selected_game = SelectedGame {
+        name: name.into(),
+        game,
+    };
+
+    Ok(ExecuteSelectGameReport {
+        selected_game: summarize(selected_game, &selected_game.name, &selected_game.game),
+    })
+}
+
+pub fn execute_list_games_json(
+    game_registry: &GameRegistry,
+    selected_game: &SelectedGame,
+) -> Value {
+    json!(execute_list_games(game_registry, selected_game))
+}
+
+pub fn execute_list_games(
+    game_registry: &GameRegistry,
+    selected_game: &SelectedGame,
+) -> ExecuteListGamesReport {
+    ExecuteListGamesReport {
+        games: game_registry
+            .list()
+            .iter()
+            .map(|(name, game)| summarize(selected_game, name, game))
+            .collect(),
+    }
+}
+
+fn summarize(
+    selected_game: &SelectedGame,
+    name: &str,
+    game: &SharedGame,
+) -> GameSummary {
+    let game = lock_game(game);
+    GameSummary {
+        name: name.into(),
+        seed: encode_hex(&game.seed),
+        moves_count: game.statistics.moves_count,
+        selected: *selected_game.name == *name,
+    }
+}
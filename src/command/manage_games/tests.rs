@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests_int {
+    use crate::{
+        command::{
+            manage_games::{execute_list_games, execute_new_game, execute_select_game},
+            r#move::execute as execute_move_command,
+        },
+        generator::game::new_testing,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        the_world::game_registry::{lock_game, GameRegistry, DEFAULT_GAME_NAME},
+    };
+
+    #[test]
+    fn test_execute_new_game() {
+        let game_registry = GameRegistry::new(new_testing(Some([1; 16])));
+        let mut selected_game = game_registry.select_default();
+
+        assert!(execute_new_game(
+            &game_registry,
+            &mut selected_game,
+            0,
+            "other",
+            Some([2; 16])
+        )
+        .is_ok());
+        assert_eq!(Box::from("other"), selected_game.name);
+        assert_eq!([2; 16], lock_game(&selected_game.game).seed);
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::GameAlreadyExists,
+                "A game with the name other already exists.".to_string()
+            )),
+            execute_new_game(&game_registry, &mut selected_game, 0, "other", None)
+        );
+        for name in ["", "../other", "not/saveable", ".hidden", "session-7"] {
+            assert!(matches!(
+                execute_new_game(&game_registry, &mut selected_game, 0, name, None),
+                Err(MyError::ExecuteCommand {
+                    code: ErrorCode::InvalidGameName,
+                    ..
+                })
+            ));
+        }
+        assert_eq!(Box::from("other"), selected_game.name);
+    }
+
+    #[test]
+    fn test_execute_select_game() {
+        let game_registry = GameRegistry::new(new_testing(Some([1; 16])));
+        let mut selected_game = game_registry.select_default();
+        execute_new_game(
+            &game_registry,
+            &mut selected_game,
+            0,
+            "other",
+            Some([1; 16]),
+        )
+        .unwrap();
+
+        let _move_result = execute_move_command(&mut lock_game(&selected_game.game), 0);
+        assert_eq!(1, lock_game(&selected_game.game).statistics.moves_count);
+
+        assert!(
+            execute_select_game(&game_registry, &mut selected_game, 0, DEFAULT_GAME_NAME).is_ok()
+        );
+        assert_eq!(0, lock_game(&selected_game.game).statistics.moves_count);
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::GameNotFound,
+                "There is no game with the name missing.".to_string()
+            )),
+            execute_select_game(&game_registry, &mut selected_game, 0, "missing")
+        );
+        assert_eq!(Box::from(DEFAULT_GAME_NAME), selected_game.name);
+    }
+
+    #[test]
+    fn test_execute_select_game_of_another_session() {
+        let game_registry = GameRegistry::new(new_testing(Some([1; 16])));
+        let mut selected_game = game_registry.select_default();
+        let mut other_selected_game = game_registry.select_default();
+        execute_new_game(&game_registry, &mut selected_game, 0, "mine", Some([1; 16])).unwrap();
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::GameOwnedByOtherSession,
+                "The game mine is played by another session.".to_string()
+            )),
+            execute_select_game(&game_registry, &mut other_selected_game, 1, "mine")
+        );
+        assert!(execute_select_game(
+            &game_registry,
+            &mut other_selected_game,
+            1,
+            DEFAULT_GAME_NAME
+        )
+        .is_ok());
+
+        game_registry.release(0);
+        assert!(execute_select_game(&game_registry, &mut other_selected_game, 1, "mine").is_ok());
+        assert!(matches!(
+            execute_select_game(&game_registry, &mut selected_game, 0, "mine"),
+            Err(MyError::ExecuteCommand {
+                code: ErrorCode::GameOwnedByOtherSession,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_execute_list_games() {
+        let game_registry = GameRegistry::new(new_testing(Some([1; 16])));
+        let mut selected_game = game_registry.select_default();
+        execute_new_game(
+            &game_registry,
+            &mut selected_game,
+            0,
+            "a_game",
+            Some([1; 16]),
+        )
+        .unwrap();
+
+        let report = execute_list_games(&game_registry, &selected_game);
+
+        assert_eq!(2, report.games.len());
+        assert_eq!(Box::from("a_game"), report.games[0].name);
+        assert!(report.games[0].selected);
+        assert_eq!(Box::from(DEFAULT_GAME_NAME), report.games[1].name);
+        assert!(!report.games[1].selected);
+    }
+}
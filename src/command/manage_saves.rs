@@ -0,0 +1,287 @@
+use std::{fs, io::ErrorKind, path::Path, time::UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    command::save_load::read_save_file,
+    my_little_rpg_errors::{ErrorCode, MyError},
+    parser::{hex_encoder::encode_hex, save_format::SaveFormat},
+    the_world::{
+        game_statistics::GameStatistics,
+        save_root::{is_save_name, SaveRoot},
+    },
+    Game,
+};
+
+mod tests;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct SaveSummary {
+    name: Box<str>,
+    format: SaveFormat,
+    saved_at: u64,
+}
+
+impl ExecuteListSavesReport {
+    pub fn saves(&self) -> &[SaveSummary] {
+        &self.saves
+    }
+}
+
+impl SaveSummary {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn format(&self) -> SaveFormat {
+        self.format
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteListSavesReport {
+    saves: Vec<SaveSummary>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteDeleteSaveReport {
+    deleted_save: Box<str>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteRenameSaveReport {
+    old_name: Box<str>,
+    new_name: Box<str>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteSaveInfoReport {
+    name: Box<str>,
+    format: SaveFormat,
+    // Whether the checksum proves the save game was not changed since it was saved. Save games from
+    // before checksums can not be verified.
+    checksum_verified: bool,
+    saved_at: u64,
+    seed: Box<str>,
+    statistics: GameStatistics,
+    difficulty: DifficultySummary,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct DifficultySummary {
+    places_count: usize,
+    damage_types: usize,
+    highest_max_resistance: u64,
+    highest_min_resistance: u64,
+    max_simultaneous_resistances: u8,
+    min_simultaneous_resistances: u8,
+}
+
+pub fn execute_list_saves_json(
+    game: &Game,
+    save_path: Option<Box<str>>,
+) -> Result<Value, MyError> {
+    execute_list_saves(&game.save_root, save_path).map(|result| json!(result))
+}
+
+// A folder without any save games yet is not an error, it just has no saves.
+pub fn execute_list_saves(
+    save_root: &SaveRoot,
+    save_path: Option<Box<str>>,
+) -> Result<ExecuteListSavesReport, MyError> {
+    list_saves_in(&save_root.folder(save_path.as_deref())?)
+}
+
+pub(crate) fn list_saves_in(folder: &Path) -> Result<ExecuteListSavesReport, MyError> {
+    let entries = match fs::read_dir(folder) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == ErrorKind::NotFound => {
+            return Ok(ExecuteListSavesReport { saves: Vec::new() });
+        }
+        Err(error) => {
+            return Err(MyError::create_save_load_error(
+                ErrorCode::LoadFailed,
+                format!("Failed listing the save games! Reason: {error}"),
+            ));
+        }
+    };
+
+    let mut saves: Vec<SaveSummary> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter_map(|path| {
+            let format = SaveFormat::from_extension(path.extension()?.to_str()?)?;
+            let name = path
+                .file_stem()?
+                .to_str()
+                .filter(|name| is_save_name(name))?;
+            Some(SaveSummary {
+                name: name.into(),
+                format,
+                saved_at: saved_at(&path),
+            })
+        })
+        .collect();
+    saves.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(ExecuteListSavesReport { saves })
+}
+
+pub fn execute_delete_save_json(
+    game: &Game,
+    save_name: &str,
+    save_path: Option<Box<str>>,
+) -> Result<Value, MyError> {
+    execute_delete_save(&game.save_root, save_name, save_path).map(|result| json!(result))
+}
+
+pub fn execute_delete_save(
+    save_root: &SaveRoot,
+    save_name: &str,
+    save_path: Option<Box<str>>,
+) -> Result<ExecuteDeleteSaveReport, MyError> {
+    let (file_path, _) = save_root.find_file(save_name, save_path.as_deref())?;
+    match fs::remove_file(file_path) {
+        Ok(()) => {
+            Ok(ExecuteDeleteSaveReport {
+                deleted_save: save_name.into(),
+            })
+        }
+        Err(error) if error.kind() == ErrorKind::NotFound => {
+            Err(create_save_not_found_error(save_name))
+        }
+        Err(error) => {
+            Err(MyError::create_save_load_error(
+                ErrorCode::SaveFailed,
+                format!("Failed deleting the save game {save_name}! Reason: {error}"),
+            ))
+        }
+    }
+}
+
+pub fn execute_rename_save_json(
+    game: &Game,
+    old_name: &str,
+    new_name: &str,
+    save_path: Option<Box<str>>,
+) -> Result<Value, MyError> {
+    execute_rename_save(&game.save_root, old_name, new_name, save_path).map(|result| json!(result))
+}
+
+// Renames the save game within its folder, without overwriting another save game. The save game is
+// linked under the new name before the old name is removed, and linking never replaces a file, so
+// a save game written under the new name in the meantime is not overwritten either.
+pub fn execute_rename_save(
+    save_root: &SaveRoot,
+    old_name: &str,
+    new_name: &str,
+    save_path: Option<Box<str>>,
+) -> Result<ExecuteRenameSaveReport, MyError> {
+    let (old_file_path, format) = save_root.find_file(old_name, save_path.as_deref())?;
+    if !old_file_path.is_file() {
+        return Err(create_save_not_found_error(old_name));
+    }
+    // Linking only notices a save game with the new name in the same format.
+    let (taken_file_path, taken_format) = save_root.find_file(new_name, save_path.as_deref())?;
+    if taken_format != format && taken_file_path.exists() {
+        return Err(create_save_already_exists_error(new_name));
+    }
+
+    let new_file_path = save_root.file(new_name, save_path.as_deref(), format)?;
+    fs::hard_link(&old_file_path, new_file_path).map_err(|error| {
+        if error.kind() == ErrorKind::AlreadyExists {
+            create_save_already_exists_error(new_name)
+        } else {
+            create_rename_failed_error(old_name, &error)
+        }
+    })?;
+    fs::remove_file(old_file_path).map_err(|error| create_rename_failed_error(old_name, &error))?;
+    Ok(ExecuteRenameSaveReport {
+        old_name: old_name.into(),
+        new_name: new_name.into(),
+    })
+}
+
+pub fn execute_save_info_json(
+    game: &Game,
+    save_name: &str,
+    save_path: Option<Box<str>>,
+) -> Result<Value, MyError> {
+    execute_save_info(&game.save_root, save_name, save_path).map(|result| json!(result))
+}
+
+pub fn execute_save_info(
+    save_root: &SaveRoot,
+    save_name: &str,
+    save_path: Option<Box<str>>,
+) -> Result<ExecuteSaveInfoReport, MyError> {
+    let (file_path, _) = save_root.find_file(save_name, save_path.as_deref())?;
+    if !file_path.is_file() {
+        return Err(create_save_not_found_error(save_name));
+    }
+    let loaded_save = read_save_file(save_root, &file_path)?;
+
+    let game = &loaded_save.game;
+    let difficulty = &game.difficulty;
+    Ok(ExecuteSaveInfoReport {
+        name: save_name.into(),
+        format: loaded_save.format,
+        checksum_verified: loaded_save.checksum_verified,
+        saved_at: saved_at(&file_path),
+        seed: encode_hex(&game.seed),
+        statistics: game.statistics.clone(),
+        difficulty: DifficultySummary {
+            places_count: game.places.len(),
+            damage_types: difficulty.max_resistance.len(),
+            highest_max_resistance: difficulty
+                .max_resistance
+                .values()
+                .copied()
+                .max()
+                .unwrap_or(0),
+            highest_min_resistance: difficulty
+                .min_resistance
+                .values()
+                .copied()
+                .max()
+                .unwrap_or(0),
+            max_simultaneous_resistances: difficulty.max_simultaneous_resistances,
+            min_simultaneous_resistances: difficulty.min_simultaneous_resistances,
+        },
+    })
+}
+
+// Seconds since the unix epoch the save game was last written, 0 when the file system does not
+// know.
+fn saved_at(file_path: &Path) -> u64 {
+    fs::metadata(file_path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |duration| duration.as_secs())
+}
+
+fn create_save_already_exists_error(save_name: &str) -> MyError {
+    MyError::create_save_load_error(
+        ErrorCode::SaveAlreadyExists,
+        format!("A save game with the name {save_name} already exists."),
+    )
+}
+
+fn create_rename_failed_error(
+    save_name: &str,
+    error: &std::io::Error,
+) -> MyError {
+    MyError::create_save_load_error(
+        ErrorCode::SaveFailed,
+        format!("Failed renaming the save game {save_name}! Reason: {error}"),
+    )
+}
+
+fn create_save_not_found_error(save_name: &str) -> MyError {
+    MyError::create_save_load_error(
+        ErrorCode::SaveNotFound,
+        format!("There is no save game with the name {save_name}."),
+    )
+}
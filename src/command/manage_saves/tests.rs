@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests_int {
+    use std::fs;
+
+    use crate::{
+        command::{
+            manage_saves::{
+                execute_delete_save, execute_list_saves, execute_rename_save, execute_save_info,
+            },
+            save_load::execute_save_command,
+        },
+        generator::game::new_testing,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        parser::save_format::SaveFormat,
+        the_world::save_root::SaveRoot,
+    };
+
+    #[test]
+    fn test_execute_list_saves() {
+        let mut game = new_testing(Some([1; 16]));
+        game.set_save_root(SaveRoot::new("./testing_list_saves/"));
+        let save_root = game.save_root.clone();
+
+        assert!(execute_list_saves(&save_root, None)
+            .unwrap()
+            .saves
+            .is_empty());
+
+        execute_save_command(&game, "b", None, SaveFormat::Json).unwrap();
+        execute_save_command(&game, "a", None, SaveFormat::Binary).unwrap();
+        execute_save_command(&game, "c", Some("folder".into()), SaveFormat::Json).unwrap();
+        fs::write("./testing_list_saves/notes.txt", "not a save game").unwrap();
+
+        let saves = execute_list_saves(&save_root, None).unwrap().saves;
+        assert_eq!(
+            vec![("a", SaveFormat::Binary), ("b", SaveFormat::Json)],
+            saves
+                .iter()
+                .map(|save| (&*save.name, save.format))
+                .collect::<Vec<_>>()
+        );
+        assert!(saves.iter().all(|save| save.saved_at > 0));
+        assert_eq!(
+            1,
+            execute_list_saves(&save_root, Some("folder".into()))
+                .unwrap()
+                .saves
+                .len()
+        );
+        assert!(matches!(
+            execute_list_saves(&save_root, Some("..".into())),
+            Err(MyError::SaveLoad {
+                code: ErrorCode::InvalidSavePath,
+                ..
+            })
+        ));
+
+        fs::remove_dir_all("./testing_list_saves/").expect("Had trouble cleanup after list saves");
+    }
+
+    #[test]
+    fn test_execute_delete_and_rename_save() {
+        let mut game = new_testing(Some([1; 16]));
+        game.set_save_root(SaveRoot::new("./testing_delete_rename_save/"));
+        let save_root = game.save_root.clone();
+        execute_save_command(&game, "a", None, SaveFormat::Json).unwrap();
+        execute_save_command(&game, "b", None, SaveFormat::Json).unwrap();
+
+        assert_eq!(
+            Err(MyError::create_save_load_error(
+                ErrorCode::SaveAlreadyExists,
+                "A save game with the name b already exists.".to_string()
+            )),
+            execute_rename_save(&save_root, "a", "b", None)
+        );
+        assert_eq!(
+            Err(MyError::create_save_load_error(
+                ErrorCode::SaveAlreadyExists,
+                "A save game with the name a already exists.".to_string()
+            )),
+            execute_rename_save(&save_root, "a", "a", None)
+        );
+        execute_save_command(&game, "d", None, SaveFormat::Binary).unwrap();
+        assert_eq!(
+            Err(MyError::create_save_load_error(
+                ErrorCode::SaveAlreadyExists,
+                "A save game with the name d already exists.".to_string()
+            )),
+            execute_rename_save(&save_root, "a", "d", None)
+        );
+        assert!(execute_delete_save(&save_root, "d", None).is_ok());
+        assert!(execute_rename_save(&save_root, "a", "c", None).is_ok());
+        assert!(!fs::exists("./testing_delete_rename_save/a.json").unwrap());
+        assert!(execute_delete_save(&save_root, "b", None).is_ok());
+        assert_eq!(
+            Err(MyError::create_save_load_error(
+                ErrorCode::SaveNotFound,
+                "There is no save game with the name b.".to_string()
+            )),
+            execute_delete_save(&save_root, "b", None)
+        );
+        assert!(matches!(
+            execute_rename_save(&save_root, "c", "../c", None),
+            Err(MyError::SaveLoad {
+                code: ErrorCode::InvalidSaveName,
+                ..
+            })
+        ));
+
+        let saves = execute_list_saves(&save_root, None).unwrap().saves;
+        assert_eq!(
+            vec!["c"],
+            saves.iter().map(|save| &*save.name).collect::<Vec<_>>()
+        );
+
+        fs::remove_dir_all("./testing_delete_rename_save/")
+            .expect("Had trouble cleanup after delete and rename save");
+    }
+
+    #[test]
+    fn test_execute_save_info() {
+        let mut game = new_testing(Some([1; 16]));
+        game.set_save_root(SaveRoot::new("./testing_save_info/"));
+        game.statistics.wins = 3;
+        execute_save_command(&game, "a", None, SaveFormat::Json).unwrap();
+
+        let save_info = execute_save_info(&game.save_root, "a", None).unwrap();
+        assert!(save_info.checksum_verified);
+        assert_eq!(
+            Box::from("01010101010101010101010101010101"),
+            save_info.seed
+        );
+        assert_eq!(game.statistics, save_info.statistics);
+        assert_eq!(10, save_info.difficulty.places_count);
+        assert!(save_info.saved_at > 0);
+        assert!(matches!(
+            execute_save_info(&game.save_root, "b", None),
+            Err(MyError::SaveLoad {
+                code: ErrorCode::SaveNotFound,
+                ..
+            })
+        ));
+
+        fs::remove_dir_all("./testing_save_info/").expect("Had trouble cleanup after save info");
+    }
+}
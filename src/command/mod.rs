@@ -1,5 +1,5 @@
 pub mod commands;
-pub(crate) mod craft_expand_modifier;
+pub mod craft_expand_modifier;
 pub mod craft_reroll_modifier;
 pub mod equip_swap;
 pub mod expand_elements;
@@ -9,10 +9,23 @@ pub mod expand_max_simultaneous_element;
 pub mod expand_min_element;
 pub mod expand_min_simultanius_element;
 pub mod expand_places;
+pub mod expand_stash;
 pub mod help;
+pub mod lock_item;
+pub mod manage_games;
+pub mod manage_saves;
+pub mod name_item;
+pub mod optimize_loadout;
 pub mod r#move;
 pub mod presentation_game_state;
+pub mod query_state;
 pub mod reduce_difficulty;
 pub mod reorder_inventory;
 mod roll_modifier;
+pub mod salvage;
 pub mod save_load;
+pub mod simulate;
+pub mod sort_inventory;
+pub mod stash;
+pub mod undo_redo;
+pub mod upgrade_rarity;
@@ -5,7 +5,7 @@ use serde_json::{json, Value};
 
 use crate::{
     generator::place::new,
-    my_little_rpg_errors::MyError,
+    my_little_rpg_errors::{ErrorCode, MyError},
     the_world::{
         damage_types::DamageType,
         item::{CraftingInfo, Item},
@@ -14,6 +14,7 @@ use crate::{
         modifier_cost::Cost,
         modifier_gain::Gain,
         place::Place,
+        rarity::Rarity,
         treasure_types::TreasureType,
     },
     Game,
@@ -35,6 +36,22 @@ pub struct ItemReport {
     current_item_resources: HashMap<Type, u64>,
 }
 
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct FailedItemCost {
+    pub(crate) equipped_item_index: usize,
+    pub(crate) error: MyError,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct Fight {
+    pub(crate) item_report: Vec<ItemReport>,
+    pub(crate) failed_costs: Vec<FailedItemCost>,
+    pub(crate) item_resources: HashMap<Type, u64>,
+    pub(crate) damage: HashMap<DamageType, u64>,
+    pub(crate) item_gain: u16,
+    pub(crate) rewards: Option<HashMap<TreasureType, u64>>,
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct ExecuteMoveCommandReport {
     item_report: Vec<ItemReport>,
@@ -45,11 +62,8 @@ pub struct ExecuteMoveCommandReport {
 pub fn execute_move_command_json(
     game: &mut Game,
     index: usize,
-) -> Value {
-    match execute(game, index) {
-        Ok(result) => json!(result),
-        Err(result) => json!(result),
-    }
+) -> Result<Value, MyError> {
+    execute(game, index).map(|result| json!(result))
 }
 
 pub fn execute(
@@ -62,39 +76,109 @@ pub fn execute(
 
     game.statistics.moves_count += 1;
 
+    let fight = fight(game, index);
+    game.item_resources = fight.item_resources;
+
+    //If we can claim the reward.
+    if let Some(rewards) = fight.rewards {
+        game.statistics.wins += 1;
+        game.statistics.wins_in_a_row += 1;
+        game.statistics.loses_in_a_row = 0;
+
+        let possible_rolls = &game.places[index].item_reward_possible_rolls;
+        let rarity_table = game.rules.item_rarity_table(possible_rolls);
+        for _i in 0..fight.item_gain {
+            let id = game.create_item_id();
+            let rarity = rarity_table
+                .roll(&mut game.random_generators.loot)
+                .map_or(Rarity::Common, |(rarity, _)| *rarity);
+            game.inventory.push(Some(Item {
+                crafting_info: CraftingInfo {
+                    possible_rolls: game.places[index].item_reward_possible_rolls.clone(),
+                    places_count: game.places.len(),
+                },
+                modifiers: vec![Modifier {
+                    costs: Vec::new(),
+                    gains: Vec::new(),
+                }],
+                locked: false,
+                id,
+                name: None,
+                notes: None,
+                rarity,
+            }));
+        }
+
+        return Ok(update_claim_place_effect(
+            game,
+            index,
+            fight.item_report,
+            rewards,
+        ));
+    }
+
+    game.statistics.loses += 1;
+    game.statistics.loses_in_a_row += 1;
+    game.statistics.wins_in_a_row = 0;
+
+    Err(MyError::create_move_command_error(
+        ErrorCode::NotEnoughDamage,
+        "You did not deal enough damage to overcome the challenges in this place.".to_string(),
+        json!(fight.item_report).to_string(),
+    ))
+}
+
+// Fights the place with the equipped items without changing the game. The items are used in
+// order until the place is beaten, so the items after the winning one are never used.
+pub(crate) fn fight(
+    game: &Game,
+    index: usize,
+) -> Fight {
+    let place = game
+        .places
+        .get(index)
+        .expect("Error: fight: Could not find place even though it were within the index.");
+    let mut item_resources = game.item_resources.clone();
     let mut current_damage = HashMap::new();
     let mut current_resistance_reduction = HashMap::new();
     let mut treasure_bonus = HashMap::new();
     let mut item_gain = 1;
     let mut item_report = Vec::new();
+    let mut failed_costs = Vec::new();
+    let mut damage = HashMap::new();
+
+    for (equipped_item_index, item) in game.equipped_items.iter().enumerate() {
+        let item_resource_cost =
+            match evaluate_item_costs(item, &current_damage, &item_resources, game, index) {
+                Ok(costs) => costs,
+                Err(error) => {
+                    item_report.push(ItemReport {
+                        item: item.clone(),
+                        current_damage: current_damage.clone(),
+                        current_resistance_reduction: current_resistance_reduction.clone(),
+                        treasure_bonus: treasure_bonus.clone(),
+                        item_gain,
+                        effect_description: error.clone().into(),
+                        item_resource_costs: None,
+                        current_item_resources: item_resources.clone(),
+                    });
+                    failed_costs.push(FailedItemCost {
+                        equipped_item_index,
+                        error,
+                    });
+                    continue;
+                }
+            };
 
-    for item in &game.equipped_items {
-        let item_resource_cost = match evaluate_item_costs(item, &current_damage, game, index) {
-            Ok(costs) => costs,
-            Err(message) => {
-                item_report.push(ItemReport {
-                    item: item.clone(),
-                    current_damage: current_damage.clone(),
-                    current_resistance_reduction: current_resistance_reduction.clone(),
-                    treasure_bonus: treasure_bonus.clone(),
-                    item_gain,
-                    effect_description: message.into(),
-                    item_resource_costs: None,
-                    current_item_resources: game.item_resources.clone(),
-                });
-                continue;
-            }
-        };
-
-        update_cost_effect(&mut game.item_resources, &item_resource_cost);
+        update_cost_effect(&mut item_resources, &item_resource_cost);
         update_gain_effect(
             &mut current_damage,
             &mut current_resistance_reduction,
             &mut treasure_bonus,
             &mut item_gain,
-            &mut game.item_resources,
+            &mut item_resources,
             item,
-            game.places.get(index).unwrap(),
+            place,
         );
         item_report.push(ItemReport {
             item: item.clone(),
@@ -104,11 +188,11 @@ pub fn execute(
             item_gain,
             effect_description: "Costs paid and all gains executed.".into(),
             item_resource_costs: Some(item_resource_cost),
-            current_item_resources: game.item_resources.clone(),
+            current_item_resources: item_resources.clone(),
         });
 
         //For the calculation of claiming the rewards we can merge the attack damage and flat resistance reduction into damage;
-        let merged_damage_and_reduced_resistance = current_damage
+        damage = current_damage
             .keys()
             .chain(current_resistance_reduction.keys())
             .map(|attack_type| {
@@ -118,24 +202,15 @@ pub fn execute(
                 let merged_damage = current_damage_amount
                     .checked_add(*current_resistance_reduction_amount)
                     .unwrap_or(u64::MAX);
-                (attack_type, merged_damage)
+                (attack_type.clone(), merged_damage)
             })
             .collect();
 
-        //If we can claim the reward.
-        if let Some(rewards) = game
-            .places
-            .get(index)
-            .expect(
-                "Error: execute_move_command: Could not find place even though it were within the \
-                 index.",
-            )
-            .claim_rewards(&merged_damage_and_reduced_resistance)
-        {
-            game.statistics.wins += 1;
-            game.statistics.wins_in_a_row += 1;
-            game.statistics.loses_in_a_row = 0;
-
+        let merged_damage_and_reduced_resistance = damage
+            .iter()
+            .map(|(attack_type, amount)| (attack_type, *amount))
+            .collect();
+        if let Some(rewards) = place.claim_rewards(&merged_damage_and_reduced_resistance) {
             let modified_rewards = rewards
                 .into_iter()
                 .map(|(treasure_type, treasure_amount)| {
@@ -150,36 +225,25 @@ pub fn execute(
                 })
                 .collect();
 
-            for _i in 0..item_gain {
-                game.inventory.push(Some(Item {
-                    crafting_info: CraftingInfo {
-                        possible_rolls: game.places[index].item_reward_possible_rolls.clone(),
-                        places_count: game.places.len(),
-                    },
-                    modifiers: vec![Modifier {
-                        costs: Vec::new(),
-                        gains: Vec::new(),
-                    }],
-                }));
-            }
-
-            return Ok(update_claim_place_effect(
-                game,
-                index,
+            return Fight {
                 item_report,
-                modified_rewards,
-            ));
+                failed_costs,
+                item_resources,
+                damage,
+                item_gain,
+                rewards: Some(modified_rewards),
+            };
         }
     }
 
-    game.statistics.loses += 1;
-    game.statistics.loses_in_a_row += 1;
-    game.statistics.wins_in_a_row = 0;
-
-    Err(MyError::create_move_command_error(
-        "You did not deal enough damage to overcome the challenges in this place.".to_string(),
-        json!(item_report).to_string(),
-    ))
+    Fight {
+        item_report,
+        failed_costs,
+        item_resources,
+        damage,
+        item_gain,
+        rewards: None,
+    }
 }
 
 //TODO Save stack of events, expose events and load events (Last part likely requires to be able to load files)
@@ -189,6 +253,7 @@ fn report_place_does_not_exist(
     index: usize,
 ) -> Result<ExecuteMoveCommandReport, MyError> {
     Err(MyError::create_move_command_error(
+        ErrorCode::PlaceNotFound,
         format!(
             "Error: execute_move_command: Index {} is out of range of places, places is {} long.",
             index,
@@ -335,13 +400,11 @@ fn add_multiplier_to_base(
     base_value: u64,
 ) -> u64 {
     base_value
-        .checked_mul(u64::from(multiplier_as_percentage))
-        .unwrap_or(u64::MAX)
+        .saturating_mul(u64::from(multiplier_as_percentage))
         .checked_div(100)
         .unwrap_or(1)
         .max(1)
-        .checked_add(base_value)
-        .unwrap_or(u64::MAX)
+        .saturating_add(base_value)
 }
 
 fn update_cost_effect(
@@ -373,6 +436,7 @@ fn calculate_are_all_costs_payable(
 fn evaluate_item_costs(
     item: &Item,
     current_damage: &HashMap<DamageType, u64>,
+    item_resources: &HashMap<Type, u64>,
     game: &Game,
     index: usize,
 ) -> Result<HashMap<Type, u64>, MyError> {
@@ -386,132 +450,177 @@ fn evaluate_item_costs(
                         .or_insert(0) += amount;
                 }
                 Cost::FlatMinItemResourceRequirement(item_resource_type, amount) => {
-                    let resource_amount = game.item_resources.get(item_resource_type).unwrap_or(&0);
+                    let resource_amount = item_resources.get(item_resource_type).unwrap_or(&0);
                     if resource_amount < amount {
-                        return Err(MyError::create_execute_command_error(format!(
-                            "Did not fulfill the FlatMinItemResourceRequirement of {amount} \
-                             {item_resource_type:?}, only had {resource_amount}."
-                        )));
+                        return Err(MyError::create_execute_command_error(
+                            ErrorCode::ItemCostNotFulfilled,
+                            format!(
+                                "Did not fulfill the FlatMinItemResourceRequirement of {amount} \
+                                 {item_resource_type:?}, only had {resource_amount}."
+                            ),
+                        ));
                     }
                 }
                 Cost::FlatMaxItemResourceRequirement(item_resource_type, amount) => {
-                    let resource_amount = game.item_resources.get(item_resource_type).unwrap_or(&0);
+                    let resource_amount = item_resources.get(item_resource_type).unwrap_or(&0);
                     if resource_amount > amount {
-                        return Err(MyError::create_execute_command_error(format!(
-                            "Did not fulfill the FlatMaxItemResourceRequirement of {amount} \
-                             {item_resource_type:?}, had {resource_amount:?} and that is too much."
-                        )));
+                        return Err(MyError::create_execute_command_error(
+                            ErrorCode::ItemCostNotFulfilled,
+                            format!(
+                                "Did not fulfill the FlatMaxItemResourceRequirement of {amount} \
+                                 {item_resource_type:?}, had {resource_amount:?} and that is too \
+                                 much."
+                            ),
+                        ));
                     }
                 }
                 Cost::FlatMinAttackRequirement(attack_type, amount) => {
                     if current_damage.get(attack_type).unwrap_or(&0) < amount {
-                        return Err(MyError::create_execute_command_error(format!(
-                            "Did not fulfill the FlatMinAttackRequirement of {amount} \
-                             {attack_type:?} damage, only did {current_damage:?} damage."
-                        )));
+                        return Err(MyError::create_execute_command_error(
+                            ErrorCode::ItemCostNotFulfilled,
+                            format!(
+                                "Did not fulfill the FlatMinAttackRequirement of {amount} \
+                                 {attack_type:?} damage, only did {current_damage:?} damage."
+                            ),
+                        ));
                     }
                 }
                 Cost::FlatMaxAttackRequirement(attack_type, amount) => {
                     if current_damage.get(attack_type).unwrap_or(&0) > amount {
-                        return Err(MyError::create_execute_command_error(format!(
-                            "Did not fulfill the FlatMaxAttackRequirement of {amount} \
-                             {attack_type:?} damage, did {current_damage:?} damage and that is \
-                             too much."
-                        )));
+                        return Err(MyError::create_execute_command_error(
+                            ErrorCode::ItemCostNotFulfilled,
+                            format!(
+                                "Did not fulfill the FlatMaxAttackRequirement of {amount} \
+                                 {attack_type:?} damage, did {current_damage:?} damage and that \
+                                 is too much."
+                            ),
+                        ));
                     }
                 }
                 Cost::FlatSumMinAttackRequirement(amount) => {
                     if current_damage.values().sum::<u64>() < *amount {
-                        return Err(MyError::create_execute_command_error(format!(
-                            "Did not fulfill the FlatSumMinAttackRequirement of {amount} damage, \
-                             only did {current_damage:?} damage."
-                        )));
+                        return Err(MyError::create_execute_command_error(
+                            ErrorCode::ItemCostNotFulfilled,
+                            format!(
+                                "Did not fulfill the FlatSumMinAttackRequirement of {amount} \
+                                 damage, only did {current_damage:?} damage."
+                            ),
+                        ));
                     }
                 }
                 Cost::FlatSumMaxAttackRequirement(amount) => {
                     if current_damage.values().sum::<u64>() > *amount {
-                        return Err(MyError::create_execute_command_error(format!(
-                            "Did not fulfill the FlatSumMaxAttackRequirement of {amount} damage, \
-                             did {current_damage:?} damage damage and that is too much."
-                        )));
+                        return Err(MyError::create_execute_command_error(
+                            ErrorCode::ItemCostNotFulfilled,
+                            format!(
+                                "Did not fulfill the FlatSumMaxAttackRequirement of {amount} \
+                                 damage, did {current_damage:?} damage damage and that is too \
+                                 much."
+                            ),
+                        ));
                     }
                 }
                 Cost::PlaceLimitedByIndexModulus(modulus, valid_values) => {
                     let modulus_value = index.rem_euclid(usize::from(*modulus));
                     if !valid_values.contains(&u8::try_from(modulus_value).unwrap()) {
-                        return Err(MyError::create_execute_command_error(format!(
-                            "Did not fulfill the PlaceLimitedByIndexModulus: {index} % {modulus} \
-                             = {modulus_value} and that is not contained in {valid_values:?}."
-                        )));
+                        return Err(MyError::create_execute_command_error(
+                            ErrorCode::ItemCostNotFulfilled,
+                            format!(
+                                "Did not fulfill the PlaceLimitedByIndexModulus: {index} % \
+                                 {modulus} = {modulus_value} and that is not contained in \
+                                 {valid_values:?}."
+                            ),
+                        ));
                     }
                 }
                 Cost::FlatMinResistanceRequirement(attack_type, amount) => {
                     let resistance_amount_place =
                         game.places[index].resistance.get(attack_type).unwrap_or(&0);
                     if resistance_amount_place < amount {
-                        return Err(MyError::create_execute_command_error(format!(
-                            "Did not fulfill the FlatMinResistanceRequirement of {amount} \
-                             {attack_type:?} damage, place only has {resistance_amount_place:?} \
-                             damage."
-                        )));
+                        return Err(MyError::create_execute_command_error(
+                            ErrorCode::ItemCostNotFulfilled,
+                            format!(
+                                "Did not fulfill the FlatMinResistanceRequirement of {amount} \
+                                 {attack_type:?} damage, place only has \
+                                 {resistance_amount_place:?} damage."
+                            ),
+                        ));
                     }
                 }
                 Cost::FlatMaxResistanceRequirement(attack_type, amount) => {
                     let resistance_amount_place =
                         game.places[index].resistance.get(attack_type).unwrap_or(&0);
                     if resistance_amount_place > amount {
-                        return Err(MyError::create_execute_command_error(format!(
-                            "Did not fulfill the FlatMaxResistanceRequirement of {amount} \
-                             {attack_type:?} damage, place has {resistance_amount_place:?} damage \
-                             and that is too much."
-                        )));
+                        return Err(MyError::create_execute_command_error(
+                            ErrorCode::ItemCostNotFulfilled,
+                            format!(
+                                "Did not fulfill the FlatMaxResistanceRequirement of {amount} \
+                                 {attack_type:?} damage, place has {resistance_amount_place:?} \
+                                 damage and that is too much."
+                            ),
+                        ));
                     }
                 }
                 Cost::FlatMinSumResistanceRequirement(amount) => {
                     let damage_sum = game.places[index].resistance.values().sum::<u64>();
                     if damage_sum < *amount {
-                        return Err(MyError::create_execute_command_error(format!(
-                            "Did not fulfill the FlatMinSumResistanceRequirement of {amount} \
-                             damage, place only has {damage_sum:?} damage."
-                        )));
+                        return Err(MyError::create_execute_command_error(
+                            ErrorCode::ItemCostNotFulfilled,
+                            format!(
+                                "Did not fulfill the FlatMinSumResistanceRequirement of {amount} \
+                                 damage, place only has {damage_sum:?} damage."
+                            ),
+                        ));
                     }
                 }
                 Cost::FlatMaxSumResistanceRequirement(amount) => {
                     let damage_sum = game.places[index].resistance.values().sum::<u64>();
                     if damage_sum > *amount {
-                        return Err(MyError::create_execute_command_error(format!(
-                            "Did not fulfill the FlatMaxSumResistanceRequirement of {amount} \
-                             damage, place has {damage_sum:?} damage and that is too much."
-                        )));
+                        return Err(MyError::create_execute_command_error(
+                            ErrorCode::ItemCostNotFulfilled,
+                            format!(
+                                "Did not fulfill the FlatMaxSumResistanceRequirement of {amount} \
+                                 damage, place has {damage_sum:?} damage and that is too much."
+                            ),
+                        ));
                     }
                 }
                 Cost::MinWinsInARow(amount) => {
                     if game.statistics.wins_in_a_row < u64::from(*amount) {
-                        return Err(MyError::create_execute_command_error(format!(
-                            "Did not fulfill the MinWinsInARow of {} win, only hase {:?} wins in \
-                             a row.",
-                            amount, game.statistics.wins_in_a_row
-                        )));
+                        return Err(MyError::create_execute_command_error(
+                            ErrorCode::ItemCostNotFulfilled,
+                            format!(
+                                "Did not fulfill the MinWinsInARow of {} win, only hase {:?} wins \
+                                 in a row.",
+                                amount, game.statistics.wins_in_a_row
+                            ),
+                        ));
                     }
                 }
                 Cost::MaxWinsInARow(amount) => {
                     if game.statistics.wins_in_a_row > u64::from(*amount) {
-                        return Err(MyError::create_execute_command_error(format!(
-                            "Did not fulfill the MaxWinsInARow of {} win, have {:?} wins in a row \
-                             and that is too much.",
-                            amount, game.statistics.wins_in_a_row
-                        )));
+                        return Err(MyError::create_execute_command_error(
+                            ErrorCode::ItemCostNotFulfilled,
+                            format!(
+                                "Did not fulfill the MaxWinsInARow of {} win, have {:?} wins in a \
+                                 row and that is too much.",
+                                amount, game.statistics.wins_in_a_row
+                            ),
+                        ));
                     }
                 }
             }
         }
     }
 
-    if !calculate_are_all_costs_payable(&game.item_resources, &item_resource_cost) {
-        return Err(MyError::create_execute_command_error(format!(
-            "Were not able to pay all the costs. Had to pay {:?}, but only had {:?} available.",
-            item_resource_cost, game.item_resources
-        )));
+    if !calculate_are_all_costs_payable(item_resources, &item_resource_cost) {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::CantPayItemResourceCost,
+            format!(
+                "Were not able to pay all the costs. Had to pay {:?}, but only had {:?} available.",
+                item_resource_cost, item_resources
+            ),
+        ));
     }
 
     Ok(item_resource_cost)
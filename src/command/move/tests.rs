@@ -406,13 +406,22 @@ mod tests_int {
 
     fn unwrap_move_error(result: Result<ExecuteMoveCommandReport, MyError>) -> MoveCommandErrorBody {
         match result.unwrap_err() {
-            MyError::MoveCommand { error_message, item_report } => MoveCommandErrorBody { error_message, item_report },
-            _ => panic!("Did not return the right type of error!")
+            MyError::MoveCommand {
+                error_message,
+                item_report,
+                ..
+            } => {
+                MoveCommandErrorBody {
+                    error_message,
+                    item_report,
+                }
+            }
+            _ => panic!("Did not return the right type of error!"),
         }
     }
 
-    fn move_and_verify_win(mut game: &mut Game, place: Place) {
-        let result = execute(&mut game, 0).expect("Test failed!");
+    fn move_and_verify_win(game: &mut Game, place: Place) {
+        let result = execute(game, 0).expect("Test failed!");
 
         assert_eq!(
             "You won and got a new item in the inventory.",
@@ -431,8 +440,8 @@ mod tests_int {
         assert_eq!(0, game.statistics.loses_in_a_row);
     }
 
-    fn move_and_verify_loss(mut game: &mut Game) {
-        let result = execute(&mut game, 0).expect_err("Test failed!");
+    fn move_and_verify_loss(game: &mut Game) {
+        let result = execute(game, 0).expect_err("Test failed!");
 
         let printed_result = format!("{result:?}");
         println!("{printed_result}");
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    my_little_rpg_errors::{ErrorCode, MyError},
+    parser::basetype_parser::is_item_name,
+    the_world::item::Item,
+    Game,
+};
+
+mod tests;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteNameItemReport {
+    inventory_index: usize,
+    item: Item,
+}
+
+pub fn execute_name_item_json(
+    game: &mut Game,
+    inventory_index: usize,
+    name: Option<Box<str>>,
+) -> Result<Value, MyError> {
+    execute_name_item(game, inventory_index, name).map(|result| json!(result))
+}
+
+// The name can be used instead of the index in commands, so it has to be unique among all items and
+// has to parse as a name. Without a name the name is removed.
+pub fn execute_name_item(
+    game: &mut Game,
+    inventory_index: usize,
+    name: Option<Box<str>>,
+) -> Result<ExecuteNameItemReport, MyError> {
+    get_inventory_item(game, inventory_index)?;
+    if let Some(name) = &name {
+        if !is_item_name(name) {
+            return Err(MyError::create_execute_command_error(
+                ErrorCode::InvalidItemName,
+                format!(
+                    "An item name is one word that starts with a letter, without , or #, and is \
+                     not where, and, or or not. Got {name:?}"
+                ),
+            ));
+        }
+        let is_taken = game.items().any(|item| item.name.as_ref() == Some(name));
+        let is_own_name = game.inventory[inventory_index]
+            .as_ref()
+            .and_then(|item| item.name.as_ref())
+            == Some(name);
+        if is_taken && !is_own_name {
+            return Err(MyError::create_execute_command_error(
+                ErrorCode::ItemNameTaken,
+                format!("There already is an item named {name}."),
+            ));
+        }
+    }
+
+    let item = get_inventory_item(game, inventory_index)?;
+    item.name = name;
+
+    Ok(ExecuteNameItemReport {
+        inventory_index,
+        item: item.clone(),
+    })
+}
+
+pub fn execute_note_item_json(
+    game: &mut Game,
+    inventory_index: usize,
+    notes: Option<Box<str>>,
+) -> Result<Value, MyError> {
+    execute_note_item(game, inventory_index, notes).map(|result| json!(result))
+}
+
+pub fn execute_note_item(
+    game: &mut Game,
+    inventory_index: usize,
+    notes: Option<Box<str>>,
+) -> Result<ExecuteNameItemReport, MyError> {
+    let item = get_inventory_item(game, inventory_index)?;
+    item.notes = notes;
+
+    Ok(ExecuteNameItemReport {
+        inventory_index,
+        item: item.clone(),
+    })
+}
+
+fn get_inventory_item(
+    game: &mut Game,
+    inventory_index: usize,
+) -> Result<&mut Item, MyError> {
+    if game.inventory.len() <= inventory_index {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::InventoryIndexOutOfRange,
+            format!(
+                "inventory_index {} is not within the range of the inventory {}",
+                inventory_index,
+                game.inventory.len()
+            ),
+        ));
+    }
+    game.inventory[inventory_index].as_mut().ok_or_else(|| {
+        MyError::create_execute_command_error(
+            ErrorCode::InventorySlotEmpty,
+            format!("inventory_index {inventory_index} is empty."),
+        )
+    })
+}
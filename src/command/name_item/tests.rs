@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests_int {
+    use crate::{
+        command::{
+            commands::Command,
+            name_item::{execute_name_item, execute_note_item},
+        },
+        generator::game::new_testing,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        the_world::item_reference::ItemReference,
+    };
+
+    #[test]
+    fn test_execute_name_item() {
+        let mut game = new_testing(Some([1; 16]));
+
+        assert!(execute_name_item(&mut game, 2, Some("keeper".into())).is_ok());
+        assert_eq!(Some("keeper"), game.inventory[2].as_ref().unwrap().name());
+        assert!(execute_name_item(&mut game, 2, Some("keeper".into())).is_ok());
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::ItemNameTaken,
+                "There already is an item named keeper.".to_string()
+            )),
+            execute_name_item(&mut game, 3, Some("keeper".into()))
+        );
+
+        assert!(execute_name_item(&mut game, 2, None).is_ok());
+        assert_eq!(None, game.inventory[2].as_ref().unwrap().name());
+        assert!(execute_name_item(&mut game, 3, Some("keeper".into())).is_ok());
+
+        for name in ["", "where", "#12", "12", "two words", "a,b"] {
+            assert_eq!(
+                Err(MyError::create_execute_command_error(
+                    ErrorCode::InvalidItemName,
+                    format!(
+                        "An item name is one word that starts with a letter, without , or #, and \
+                         is not where, and, or or not. Got {name:?}"
+                    )
+                )),
+                execute_name_item(&mut game, 2, Some(name.into()))
+            );
+        }
+        assert_eq!(None, game.inventory[2].as_ref().unwrap().name());
+
+        game.inventory[4] = None;
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::InventorySlotEmpty,
+                "inventory_index 4 is empty.".to_string()
+            )),
+            execute_name_item(&mut game, 4, Some("fodder".into()))
+        );
+    }
+
+    #[test]
+    fn test_execute_note_item() {
+        let mut game = new_testing(Some([1; 16]));
+
+        assert!(execute_note_item(&mut game, 0, Some("Keep for fire places".into())).is_ok());
+        assert_eq!(
+            Some("Keep for fire places"),
+            game.inventory[0].as_ref().unwrap().notes()
+        );
+        assert!(execute_note_item(&mut game, 0, None).is_ok());
+        assert_eq!(None, game.inventory[0].as_ref().unwrap().notes());
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::InventoryIndexOutOfRange,
+                "inventory_index 9 is not within the range of the inventory 9".to_string()
+            )),
+            execute_note_item(&mut game, 9, None)
+        );
+    }
+
+    #[test]
+    fn test_names_and_ids_survive_reordering() {
+        let mut game = new_testing(Some([1; 16]));
+        let id = game.inventory[5].as_ref().unwrap().id();
+
+        game.execute(Command::NameItem(
+            ItemReference::Index(4),
+            Some("keeper".into()),
+        ))
+        .unwrap();
+        game.inventory[1] = None;
+        game.execute(Command::ReorderInventory).unwrap();
+
+        game.execute(Command::Lock(ItemReference::Name("keeper".into())))
+            .unwrap();
+        assert!(game.inventory[3].as_ref().unwrap().is_locked());
+
+        game.execute(Command::StashItem(ItemReference::Id(id), "keepers".into()))
+            .unwrap();
+        assert!(game.inventory[4].is_none());
+        game.execute(Command::UnstashItem(
+            "keepers".into(),
+            ItemReference::Id(id),
+        ))
+        .unwrap();
+        assert_eq!(Some(id), game.inventory[4].as_ref().map(|item| item.id()));
+    }
+}
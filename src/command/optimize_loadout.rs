@@ -0,0 +1,238 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    command::r#move::fight,
+    my_little_rpg_errors::{ErrorCode, MyError},
+    the_world::{item::Item, treasure_types::TreasureType},
+    Game,
+};
+
+mod tests;
+
+// Every round tries all single changes to the loadout, so this only limits pathological cases.
+const MAX_SEARCH_ROUNDS: usize = 100;
+// Every loadout fights the places, so this keeps a big inventory from blocking the game for long.
+const MAX_EVALUATED_LOADOUTS: usize = 10_000;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum ItemSource {
+    Equipped(usize),
+    Inventory(usize),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+pub struct LoadoutScore {
+    places_won: usize,
+    gold: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteOptimizeLoadoutReport {
+    loadout: Vec<ItemSource>,
+    score_before: LoadoutScore,
+    score: LoadoutScore,
+    applied: bool,
+    new_equipped_items: Vec<Item>,
+    evaluated_loadouts: usize,
+    // The search stopped before no single change improved the loadout anymore, so a better
+    // loadout may exist.
+    search_cut_short: bool,
+}
+
+pub fn execute_optimize_loadout_json(
+    game: &mut Game,
+    place_index: Option<usize>,
+    apply: bool,
+) -> Result<Value, MyError> {
+    execute(game, place_index, apply).map(|result| json!(result))
+}
+
+// Searches the equipped and inventory items for the loadout that wins the most places, and the
+// most gold on a tie, by changing one slot at a time for as long as that improves the loadout.
+// The order of the items matters, since the costs and gains of an item depend on the items before
+// it. The proposed loadout lists for every equipment slot where the item comes from.
+pub fn execute(
+    game: &mut Game,
+    place_index: Option<usize>,
+    apply: bool,
+) -> Result<ExecuteOptimizeLoadoutReport, MyError> {
+    execute_with_limit(game, place_index, apply, MAX_EVALUATED_LOADOUTS)
+}
+
+// The best loadout found so far is proposed when the limit is reached.
+fn execute_with_limit(
+    game: &mut Game,
+    place_index: Option<usize>,
+    apply: bool,
+    max_evaluated_loadouts: usize,
+) -> Result<ExecuteOptimizeLoadoutReport, MyError> {
+    let place_indexes: Vec<usize> = match place_index {
+        Some(place_index) if game.places.len() <= place_index => {
+            return Err(MyError::create_execute_command_error(
+                ErrorCode::PlaceNotFound,
+                format!(
+                    "Index {} is out of range of places, places is {} long.",
+                    place_index,
+                    game.places.len()
+                ),
+            ));
+        }
+        Some(place_index) => vec![place_index],
+        None => (0..game.places.len()).collect(),
+    };
+
+    let candidates: Vec<ItemSource> = (0..game.equipped_items.len())
+        .map(ItemSource::Equipped)
+        .chain(
+            game.inventory
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| item.is_some())
+                .map(|(inventory_index, _)| ItemSource::Inventory(inventory_index)),
+        )
+        .collect();
+
+    let mut loadout: Vec<ItemSource> = (0..game.equipped_items.len())
+        .map(ItemSource::Equipped)
+        .collect();
+    let mut candidate_game = game.clone();
+    let score_before = score_loadout(game, &mut candidate_game, &loadout, &place_indexes);
+    let mut score = score_before;
+    let mut evaluated_loadouts = 1;
+    let mut converged = false;
+
+    for _round in 0..MAX_SEARCH_ROUNDS {
+        let neighbours = neighbour_loadouts(&loadout, &candidates);
+        let remaining_loadouts = max_evaluated_loadouts.saturating_sub(evaluated_loadouts);
+        let round_complete = neighbours.len() <= remaining_loadouts;
+        let mut best_neighbour = None;
+        for neighbour in neighbours.into_iter().take(remaining_loadouts) {
+            evaluated_loadouts += 1;
+            let neighbour_score =
+                score_loadout(game, &mut candidate_game, &neighbour, &place_indexes);
+            if neighbour_score > score {
+                score = neighbour_score;
+                best_neighbour = Some(neighbour);
+            }
+        }
+        match best_neighbour {
+            None => {
+                converged = round_complete;
+                break;
+            }
+            Some(neighbour) => loadout = neighbour,
+        }
+        if !round_complete {
+            break;
+        }
+    }
+
+    if apply {
+        apply_loadout(game, &loadout);
+    }
+
+    Ok(ExecuteOptimizeLoadoutReport {
+        new_equipped_items: if apply {
+            game.equipped_items.clone()
+        } else {
+            get_items(game, &loadout)
+        },
+        loadout,
+        score_before,
+        score,
+        applied: apply,
+        evaluated_loadouts,
+        search_cut_short: !converged,
+    })
+}
+
+fn neighbour_loadouts(
+    loadout: &[ItemSource],
+    candidates: &[ItemSource],
+) -> Vec<Vec<ItemSource>> {
+    let mut neighbours = Vec::new();
+    for slot in 0..loadout.len() {
+        for candidate in candidates.iter().filter(|source| !loadout.contains(source)) {
+            let mut neighbour = loadout.to_vec();
+            neighbour[slot] = *candidate;
+            neighbours.push(neighbour);
+        }
+        for other_slot in slot + 1..loadout.len() {
+            let mut neighbour = loadout.to_vec();
+            neighbour.swap(slot, other_slot);
+            neighbours.push(neighbour);
+        }
+    }
+    neighbours
+}
+
+// The candidate game is a copy of the game that only differs in the equipped items, so it does not
+// have to be cloned for every loadout.
+fn score_loadout(
+    game: &Game,
+    candidate_game: &mut Game,
+    loadout: &[ItemSource],
+    place_indexes: &[usize],
+) -> LoadoutScore {
+    candidate_game.equipped_items = get_items(game, loadout);
+    let mut score = LoadoutScore {
+        places_won: 0,
+        gold: 0,
+    };
+    for place_index in place_indexes {
+        if let Some(rewards) = fight(candidate_game, *place_index).rewards {
+            score.places_won += 1;
+            score.gold = score
+                .gold
+                .saturating_add(*rewards.get(&TreasureType::Gold).unwrap_or(&0));
+        }
+    }
+    score
+}
+
+fn get_items(
+    game: &Game,
+    loadout: &[ItemSource],
+) -> Vec<Item> {
+    loadout
+        .iter()
+        .map(|source| get_item(game, *source))
+        .collect()
+}
+
+fn get_item(
+    game: &Game,
+    source: ItemSource,
+) -> Item {
+    match source {
+        ItemSource::Equipped(equipped_item_index) => {
+            game.equipped_items[equipped_item_index].clone()
+        }
+        ItemSource::Inventory(inventory_index) => {
+            game.inventory[inventory_index]
+                .clone()
+                .expect("Only inventory slots with an item are candidates.")
+        }
+    }
+}
+
+// The equipped items that are not part of the loadout take the inventory slots of the items that
+// are, like when equipping the items one by one.
+fn apply_loadout(
+    game: &mut Game,
+    loadout: &[ItemSource],
+) {
+    let new_equipped_items = get_items(game, loadout);
+    let mut unequipped_items = (0..game.equipped_items.len())
+        .filter(|equipped_item_index| {
+            !loadout.contains(&ItemSource::Equipped(*equipped_item_index))
+        })
+        .map(|equipped_item_index| game.equipped_items[equipped_item_index].clone());
+    for source in loadout {
+        if let ItemSource::Inventory(inventory_index) = source {
+            game.inventory[*inventory_index] = unequipped_items.next();
+        }
+    }
+    game.equipped_items = new_equipped_items;
+}
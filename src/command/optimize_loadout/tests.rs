@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests_int {
+    use crate::{
+        command::optimize_loadout::{execute, execute_with_limit, ItemSource},
+        generator::game::new_testing,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        the_world::item_resource::Type,
+    };
+
+    #[test]
+    fn test_execute_optimize_loadout_reorders_equipped_items() {
+        let mut game = new_testing(Some([1; 16]));
+        game.item_resources.insert(Type::Mana, 4);
+        game.equipped_items.swap(0, 1);
+        let original_game = game.clone();
+
+        let result = execute(&mut game, None, false).unwrap();
+
+        assert_eq!(original_game, game);
+        assert_eq!(
+            vec![ItemSource::Equipped(1), ItemSource::Equipped(0)],
+            result.loadout
+        );
+        assert_eq!(0, result.score_before.places_won);
+        assert_eq!(10, result.score.places_won);
+        assert!(!result.applied);
+        assert!(!result.search_cut_short);
+
+        let result = execute(&mut game, None, true).unwrap();
+
+        assert!(result.applied);
+        assert_eq!(original_game.equipped_items[1], game.equipped_items[0]);
+        assert_eq!(original_game.equipped_items[0], game.equipped_items[1]);
+        assert_eq!(original_game.inventory, game.inventory);
+        assert_eq!(game.equipped_items, result.new_equipped_items);
+    }
+
+    #[test]
+    fn test_execute_optimize_loadout_equips_from_inventory() {
+        let mut game = new_testing(Some([1; 16]));
+        game.item_resources.insert(Type::Mana, 4);
+        let generator_item = game.equipped_items[0].clone();
+        let basic_item = game.inventory[0].clone().unwrap();
+        game.equipped_items[0] = basic_item.clone();
+        game.inventory[0] = Some(generator_item.clone());
+
+        let result = execute(&mut game, Some(3), true).unwrap();
+
+        assert_eq!(
+            vec![ItemSource::Inventory(0), ItemSource::Equipped(1)],
+            result.loadout
+        );
+        assert_eq!(0, result.score_before.places_won);
+        assert_eq!(1, result.score.places_won);
+        assert_eq!(generator_item, game.equipped_items[0]);
+        assert_eq!(Some(basic_item), game.inventory[0]);
+    }
+
+    #[test]
+    fn test_execute_optimize_loadout_never_gets_worse() {
+        let mut game = new_testing(Some([1; 16]));
+        let original_game = game.clone();
+
+        let result = execute(&mut game, None, true).unwrap();
+
+        assert!(result.score >= result.score_before);
+        assert_eq!(original_game.equipped_items, game.equipped_items);
+    }
+
+    #[test]
+    fn test_execute_optimize_loadout_cut_short() {
+        let mut game = new_testing(Some([1; 16]));
+        game.item_resources.insert(Type::Mana, 4);
+        game.equipped_items.swap(0, 1);
+        let full_result = execute(&mut game, None, false).unwrap();
+
+        let result = execute_with_limit(&mut game, None, false, 3).unwrap();
+
+        assert!(result.search_cut_short);
+        assert_eq!(3, result.evaluated_loadouts);
+        assert!(result.evaluated_loadouts < full_result.evaluated_loadouts);
+        assert!(result.score >= result.score_before);
+
+        let result =
+            execute_with_limit(&mut game, None, false, full_result.evaluated_loadouts).unwrap();
+
+        assert!(!result.search_cut_short);
+        assert_eq!(full_result, result);
+    }
+
+    #[test]
+    fn test_execute_optimize_loadout_place_not_found() {
+        let mut game = new_testing(Some([1; 16]));
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::PlaceNotFound,
+                "Index 10 is out of range of places, places is 10 long.".to_string()
+            )),
+            execute(&mut game, Some(10), false)
+        );
+    }
+}
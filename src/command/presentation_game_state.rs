@@ -14,12 +14,14 @@ use crate::{
         expand_min_element::execute_expand_min_element_calculate_cost,
         expand_min_simultanius_element::execute_expand_min_simultaneous_element_calculate_cost,
         expand_places::execute_expand_places_calculate_cost,
+        expand_stash::execute_expand_stash_calculate_cost,
         reduce_difficulty::execute_execute_reduce_difficulty_cost,
+        upgrade_rarity::execute_upgrade_rarity_calculate_cost,
     },
     parser::hex_encoder::encode_hex,
     the_world::{
         difficulty::Difficulty, game_statistics::GameStatistics, item::Item, item_resource::Type,
-        place::Place, treasure_types::TreasureType,
+        place::Place, rarity::Rarity, stash::Stash, treasure_types::TreasureType,
     },
     Game,
 };
@@ -29,6 +31,7 @@ pub struct PresentationGameState {
     pub(crate) places: Vec<PresentationPlace>,
     pub(crate) equipped_items: Vec<PresentationItem>,
     pub(crate) inventory: Vec<PresentationItem>,
+    pub(crate) stash: Stash,
     pub(crate) difficulty: Difficulty,
     pub(crate) treasure: HashMap<TreasureType, u64>,
     //TODO use type alias or new type; instead of u64 create a treasure(u64) type
@@ -47,19 +50,21 @@ pub struct PlaceCosts {
     expand_max_simultaneous_element: HashMap<TreasureType, u64>,
     expand_min_simultaneous_element: HashMap<TreasureType, u64>,
     expand_equipment_slots: HashMap<TreasureType, u64>,
+    expand_stash: HashMap<TreasureType, u64>,
     execute_reduce_difficulty: HashMap<TreasureType, u64>,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct PresentationPlace {
-    index: usize,
-    place: Place,
+    pub(crate) index: usize,
+    pub(crate) place: Place,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct PresentationItem {
-    index: usize,
-    item: Item,
+    pub(crate) index: usize,
+    pub(crate) rarity: Rarity,
+    pub(crate) item: Item,
     crafting_action_costs: Result<ItemCosts, Box<str>>, //TODO Insert this into items, in a way where we do not need to maintain a second item model
 }
 
@@ -67,44 +72,33 @@ pub struct PresentationItem {
 pub struct ItemCosts {
     reroll_modifier: u16,
     add_modifier: usize,
+    upgrade_rarity: Option<usize>,
 }
 
-pub fn execute_presentation_game_state_json(game: &mut Game) -> Value {
+pub fn execute_presentation_game_state_json(game: &Game) -> Value {
     json!(execute(game))
 }
 
-pub fn execute(game: &mut Game) -> PresentationGameState {
+pub fn execute(game: &Game) -> PresentationGameState {
     let places: Vec<PresentationPlace> = game
         .places
         .iter()
-        .cloned()
         .enumerate()
-        .map(|(index, place)| PresentationPlace { index, place })
+        .map(|(index, place)| present_place(index, place))
         .collect();
     let equipped_items: Vec<PresentationItem> = game
         .equipped_items
         .iter()
-        .cloned()
         .enumerate()
-        .map(|(index, item)| {
-            PresentationItem {
-                index,
-                item,
-                crafting_action_costs: Err("Equipped items cannot be crafted on.".into()), //TODD make this nicer; it is just an info and not an error.
-            }
-        })
+        .map(|(index, item)| present_equipped_item(index, item))
         .collect();
     let inventory: Vec<PresentationItem> = game
         .inventory
         .iter()
         .enumerate()
-        .filter(|item| item.1.is_some())
-        .map(|(index, item)| {
-            PresentationItem {
-                index,
-                item: item.as_ref().unwrap().clone(),
-                crafting_action_costs: Ok(calculate_item_cost(game, index)),
-            }
+        .filter_map(|(index, item)| {
+            item.as_ref()
+                .map(|item| present_inventory_item(game, index, item))
         })
         .collect();
 
@@ -120,7 +114,8 @@ pub fn execute(game: &mut Game) -> PresentationGameState {
             game,
         ),
         expand_equipment_slots: execute_expand_equipment_slots_calculate_cost(game),
-        execute_reduce_difficulty: execute_execute_reduce_difficulty_cost(),
+        expand_stash: execute_expand_stash_calculate_cost(game),
+        execute_reduce_difficulty: execute_execute_reduce_difficulty_cost(game),
     };
 
     //TODO can I do all these presentation models without cloning so much?
@@ -128,6 +123,7 @@ pub fn execute(game: &mut Game) -> PresentationGameState {
         places,
         equipped_items,
         inventory,
+        stash: game.stash.clone(),
         difficulty: game.difficulty.clone(),
         treasure: game.treasure.clone(),
         item_resources: game.item_resources.clone(),
@@ -137,14 +133,51 @@ pub fn execute(game: &mut Game) -> PresentationGameState {
     }
 }
 
+pub(crate) fn present_place(
+    index: usize,
+    place: &Place,
+) -> PresentationPlace {
+    PresentationPlace {
+        index,
+        place: place.clone(),
+    }
+}
+
+pub(crate) fn present_equipped_item(
+    index: usize,
+    item: &Item,
+) -> PresentationItem {
+    PresentationItem {
+        index,
+        rarity: item.rarity,
+        item: item.clone(),
+        crafting_action_costs: Err("Equipped items cannot be crafted on.".into()), //TODD make this nicer; it is just an info and not an error.
+    }
+}
+
+pub(crate) fn present_inventory_item(
+    game: &Game,
+    index: usize,
+    item: &Item,
+) -> PresentationItem {
+    PresentationItem {
+        index,
+        rarity: item.rarity,
+        item: item.clone(),
+        crafting_action_costs: Ok(calculate_item_cost(game, index)),
+    }
+}
+
 fn calculate_item_cost(
     game: &Game,
     item_index: usize,
 ) -> ItemCosts {
     let add_modifier = execute_craft_expand_modifiers_calculate_cost(game, item_index);
     let reroll_modifier = execute_craft_reroll_modifier_calculate_cost(game, item_index);
+    let upgrade_rarity = execute_upgrade_rarity_calculate_cost(game, item_index);
     ItemCosts {
         reroll_modifier,
         add_modifier,
+        upgrade_rarity,
     }
 }
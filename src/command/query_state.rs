@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    command::presentation_game_state::{
+        present_equipped_item, present_inventory_item, present_place, PresentationItem,
+        PresentationPlace,
+    },
+    the_world::state_query::{ItemFilter, PlaceFilter, StateQuery},
+    Game,
+};
+
+mod tests;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteQueryStateReport<T> {
+    total_matches: usize,
+    results: Vec<T>,
+}
+
+pub fn execute_places_json(
+    game: &Game,
+    query: &StateQuery<PlaceFilter>,
+) -> Value {
+    json!(execute_places(game, query))
+}
+
+pub fn execute_equipment_json(
+    game: &Game,
+    query: &StateQuery<ItemFilter>,
+) -> Value {
+    json!(execute_equipment(game, query))
+}
+
+pub fn execute_inventory_json(
+    game: &Game,
+    query: &StateQuery<ItemFilter>,
+) -> Value {
+    json!(execute_inventory(game, query))
+}
+
+pub fn execute_places(
+    game: &Game,
+    query: &StateQuery<PlaceFilter>,
+) -> ExecuteQueryStateReport<PresentationPlace> {
+    let matches = game
+        .places
+        .iter()
+        .enumerate()
+        .filter(|(_, place)| query.filters.iter().all(|filter| filter.matches(place)));
+    paginate(matches, query, |(index, place)| present_place(index, place))
+}
+
+pub fn execute_equipment(
+    game: &Game,
+    query: &StateQuery<ItemFilter>,
+) -> ExecuteQueryStateReport<PresentationItem> {
+    let matches = game
+        .equipped_items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| query.filters.iter().all(|filter| filter.matches(item)));
+    paginate(matches, query, |(index, item)| {
+        present_equipped_item(index, item)
+    })
+}
+
+// Empty inventory slots never match, like they are left out of the State command.
+pub fn execute_inventory(
+    game: &Game,
+    query: &StateQuery<ItemFilter>,
+) -> ExecuteQueryStateReport<PresentationItem> {
+    let matches = game
+        .inventory
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| item.as_ref().map(|item| (index, item)))
+        .filter(|(_, item)| query.filters.iter().all(|filter| filter.matches(item)));
+    paginate(matches, query, |(index, item)| {
+        present_inventory_item(game, index, item)
+    })
+}
+
+// Only the selected page is presented, since presenting an inventory item calculates its crafting
+// costs.
+fn paginate<M, F, T>(
+    matches: impl Iterator<Item = M>,
+    query: &StateQuery<F>,
+    present: impl Fn(M) -> T,
+) -> ExecuteQueryStateReport<T> {
+    let mut total_matches = 0;
+    let mut results = Vec::new();
+    for (match_index, matching) in matches.enumerate() {
+        total_matches += 1;
+        let is_selected = match_index >= query.offset
+            && query
+                .limit
+                .is_none_or(|limit| match_index - query.offset < limit);
+        if is_selected {
+            results.push(present(matching));
+        }
+    }
+    ExecuteQueryStateReport {
+        total_matches,
+        results,
+    }
+}
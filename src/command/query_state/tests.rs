@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests_int {
+    use std::collections::HashMap;
+
+    use crate::{
+        command::query_state::{execute_equipment, execute_inventory, execute_places},
+        generator::game::new_testing,
+        the_world::{
+            damage_types::DamageType,
+            item::test_util::create_item,
+            state_query::{ItemFilter, PlaceFilter, StateQuery},
+        },
+    };
+
+    #[test]
+    fn test_execute_inventory_filters_and_paginates() {
+        let mut game = new_testing(Some([1; 16]));
+        let item = create_item(&game);
+        let mut item_with_one_modifier = item.clone();
+        item_with_one_modifier.modifiers.truncate(1);
+        game.inventory = vec![
+            Some(item.clone()),
+            None,
+            Some(item_with_one_modifier),
+            Some(item.clone()),
+            Some(item),
+        ];
+
+        let result = execute_inventory(&game, &StateQuery::all());
+        assert_eq!(4, result.total_matches);
+        assert_eq!(
+            vec![0, 2, 3, 4],
+            result
+                .results
+                .iter()
+                .map(|item| item.index)
+                .collect::<Vec<_>>()
+        );
+
+        let query = StateQuery::new(1, Some(1), vec![ItemFilter::MinModifiers(2)]);
+        let result = execute_inventory(&game, &query);
+        assert_eq!(3, result.total_matches);
+        assert_eq!(1, result.results.len());
+        assert_eq!(3, result.results[0].index);
+
+        let result = execute_inventory(&game, &StateQuery::new(5, None, Vec::new()));
+        assert_eq!(4, result.total_matches);
+        assert!(result.results.is_empty());
+    }
+
+    #[test]
+    fn test_execute_equipment_filters() {
+        let mut game = new_testing(Some([1; 16]));
+        let equipped_items_count = game.equipped_items.len();
+
+        let result = execute_equipment(&game, &StateQuery::all());
+        assert_eq!(equipped_items_count, result.total_matches);
+
+        game.equipped_items[0].modifiers.clear();
+        let query = StateQuery::new(0, None, vec![ItemFilter::MaxModifiers(0)]);
+        let result = execute_equipment(&game, &query);
+        assert_eq!(1, result.total_matches);
+        assert_eq!(0, result.results[0].index);
+    }
+
+    #[test]
+    fn test_execute_places_filters_by_resistance() {
+        let mut game = new_testing(Some([1; 16]));
+        let place = game.places[0].clone();
+        game.places = vec![place.clone(), place.clone(), place];
+        game.places[0].resistance = HashMap::from([(DamageType::Fire, 10)]);
+        game.places[1].resistance = HashMap::from([(DamageType::Frost, 10)]);
+        game.places[2].resistance = HashMap::from([(DamageType::Fire, 5), (DamageType::Frost, 5)]);
+
+        let query = StateQuery::new(0, None, vec![PlaceFilter::Resistance(DamageType::Fire)]);
+        let result = execute_places(&game, &query);
+        assert_eq!(
+            vec![0, 2],
+            result
+                .results
+                .iter()
+                .map(|place| place.index)
+                .collect::<Vec<_>>()
+        );
+
+        let query = StateQuery::new(
+            0,
+            Some(10),
+            vec![
+                PlaceFilter::Resistance(DamageType::Fire),
+                PlaceFilter::NoResistance(DamageType::Frost),
+            ],
+        );
+        let result = execute_places(&game, &query);
+        assert_eq!(1, result.total_matches);
+        assert_eq!(0, result.results[0].index);
+    }
+}
@@ -14,7 +14,7 @@ use crate::{
     the_world::{
         damage_types::get_mut_random_attack_type,
         difficulty::Difficulty,
-        treasure_types::{TreasureType, TreasureType::Gold},
+        treasure_types::{pay_crafting_cost, TreasureType, TreasureType::Gold},
     },
     Game,
 };
@@ -29,17 +29,17 @@ pub struct Report {
     leftover_spending_treasure: HashMap<TreasureType, u64>,
 }
 
-pub fn execute_reduce_difficulty_json(game: &mut Game) -> Value {
-    match execute(game) {
-        Ok(result) => json!(result),
-        Err(result) => json!(result),
-    }
+pub fn execute_reduce_difficulty_json(game: &mut Game) -> Result<Value, MyError> {
+    execute(game).map(|result| json!(result))
 }
 
 pub fn execute(game: &mut Game) -> Result<Report, MyError> {
+    let crafting_cost = execute_execute_reduce_difficulty_cost(game);
+    pay_crafting_cost(game, &crafting_cost)?;
+
     //Add new element
     let mut random_min_entry = get_mut_random_attack_type(
-        &mut game.random_generator_state,
+        &mut game.random_generators.places,
         &mut game.difficulty.min_resistance,
         &|_, _| true,
     )?;
@@ -78,17 +78,23 @@ pub fn execute(game: &mut Game) -> Result<Report, MyError> {
     let new_place = new(game);
     *game
         .places
-        .choose_mut(&mut game.random_generator_state)
+        .choose_mut(&mut game.random_generators.places)
         .unwrap() = new_place;
 
     Ok(Report {
         new_difficulty: game.difficulty.clone(),
-        paid_cost: execute_execute_reduce_difficulty_cost(),
-        new_cost: execute_execute_reduce_difficulty_cost(),
+        paid_cost: crafting_cost,
+        new_cost: execute_execute_reduce_difficulty_cost(game),
         leftover_spending_treasure: game.treasure.clone(),
     })
 }
 
-pub fn execute_execute_reduce_difficulty_cost() -> HashMap<TreasureType, u64> {
-    HashMap::from([(Gold, 0)])
+pub fn execute_execute_reduce_difficulty_cost(game: &Game) -> HashMap<TreasureType, u64> {
+    HashMap::from([(
+        Gold,
+        game.rules
+            .costs
+            .reduce_difficulty
+            .cost(game.places.len() as u64),
+    )])
 }
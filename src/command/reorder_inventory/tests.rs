@@ -6,6 +6,7 @@ mod tests_int {
         the_world::{
             item::{CraftingInfo, Item},
             item_modifier::Modifier,
+            rarity::Rarity,
         },
     };
 
@@ -21,6 +22,11 @@ mod tests_int {
                 possible_rolls: game.difficulty.clone(),
                 places_count: game.places.len(),
             },
+            locked: false,
+            id: 0,
+            name: None,
+            notes: None,
+            rarity: Rarity::Common,
         });
 
         game.inventory = vec![item.clone(), None, item.clone(), None, None, item];
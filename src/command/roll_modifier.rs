@@ -6,6 +6,7 @@ use std::{
 
 use rand::Rng;
 use rand_pcg::Lcg64Xsh32;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     my_little_rpg_errors::MyError,
@@ -13,6 +14,7 @@ use crate::{
         damage_types::{get_random_attack_type_from_unlocked_new, DamageType},
         item::CraftingInfo,
         item_modifier::Modifier,
+        item_query::gain_part,
         item_resource::Type,
         modifier_cost::Cost,
         modifier_gain::{
@@ -25,15 +27,27 @@ use crate::{
                 PercentageIncreaseResistanceReduction, PercentageIncreaseTreasure,
             },
         },
+        rarity::Rarity,
+        roll_table::{RollTable, RolledEntry},
+        rules::Rules,
     },
 };
 
 mod tests;
 
+// The entries of the roll tables a modifier was rolled from, in the order of its costs and gains.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ModifierRolls {
+    costs: Vec<RolledEntry>,
+    gains: Vec<RolledEntry>,
+}
+
 pub fn execute_craft(
     random_generator_state: &mut Lcg64Xsh32,
     crafting_info: &CraftingInfo,
-) -> Result<Modifier, MyError> {
+    rarity: Rarity,
+    rules: &Rules,
+) -> Result<(Modifier, ModifierRolls), MyError> {
     let minimum_elements = min(
         crafting_info.possible_rolls.min_resistance.len(),
         crafting_info.possible_rolls.min_simultaneous_resistances as usize,
@@ -43,28 +57,39 @@ pub fn execute_craft(
         crafting_info.possible_rolls.max_simultaneous_resistances as usize,
     );
 
-    let (modifier_costs, cost) =
-        execute_craft_roll_modifier_costs(random_generator_state, crafting_info)?;
+    let (modifier_costs, cost, cost_rolls) =
+        execute_craft_roll_modifier_costs(random_generator_state, crafting_info, rules)?;
 
-    let modifier_gain = execute_craft_roll_modifier_benefits(
+    // Rarer items roll bigger gains for the same costs.
+    let gain_budget = cost.saturating_mul(rarity.gain_budget_percentage()) / 100;
+    let (modifier_gain, gain_rolls) = execute_craft_roll_modifier_benefits(
         random_generator_state,
         crafting_info,
-        cost,
+        rules,
+        gain_budget,
         minimum_elements,
         maximum_elements,
     );
 
-    Ok(Modifier {
-        costs: modifier_costs,
-        gains: modifier_gain,
-    })
+    Ok((
+        Modifier {
+            costs: modifier_costs,
+            gains: modifier_gain,
+        },
+        ModifierRolls {
+            costs: cost_rolls,
+            gains: gain_rolls,
+        },
+    ))
 }
 
 fn execute_craft_roll_modifier_costs(
     random_generator_state: &mut Lcg64Xsh32,
     crafting_info: &CraftingInfo,
-) -> Result<(Vec<Cost>, u64), MyError> {
+    rules: &Rules,
+) -> Result<(Vec<Cost>, u64, Vec<RolledEntry>), MyError> {
     let mut modifier_costs = Vec::new();
+    let mut cost_rolls = Vec::new();
     let mut accumulated_cost = 0;
     let max_cost = crafting_info
         .possible_rolls
@@ -82,10 +107,19 @@ fn execute_craft_roll_modifier_costs(
             .add(1),
     );
 
+    let cost_table = rules.modifier_cost_table(&crafting_info.possible_rolls);
     for _i in 0..number_of_costs {
         if accumulated_cost < max_cost {
-            accumulated_cost += match random_generator_state.gen_range(0..14) {
-                0 => {
+            let Some((rolled_cost, weight)) = cost_table.roll(random_generator_state) else {
+                break;
+            };
+            cost_rolls.push(RolledEntry::new(
+                rolled_cost,
+                weight,
+                cost_table.total_weight(),
+            ));
+            accumulated_cost += match *rolled_cost {
+                "FlatMinAttackRequirement" => {
                     add_flat_min_attack(
                         random_generator_state,
                         crafting_info,
@@ -94,7 +128,7 @@ fn execute_craft_roll_modifier_costs(
                         max_cost,
                     )?
                 }
-                1 => {
+                "FlatMaxAttackRequirement" => {
                     add_flat_max_attack(
                         random_generator_state,
                         crafting_info,
@@ -103,14 +137,14 @@ fn execute_craft_roll_modifier_costs(
                         max_cost,
                     )?
                 }
-                2 => {
+                "PlaceLimitedByIndexModulus" => {
                     add_place_limited_by_index_modulus(
                         random_generator_state,
                         crafting_info,
                         &mut modifier_costs,
                     )
                 }
-                3 => {
+                "FlatSumMinAttackRequirement" => {
                     add_flat_sum_min_attach(
                         random_generator_state,
                         crafting_info,
@@ -119,7 +153,7 @@ fn execute_craft_roll_modifier_costs(
                         max_cost,
                     )
                 }
-                4 => {
+                "FlatSumMaxAttackRequirement" => {
                     add_flat_sum_max_attack(
                         random_generator_state,
                         crafting_info,
@@ -128,7 +162,7 @@ fn execute_craft_roll_modifier_costs(
                         max_cost,
                     )
                 }
-                5 => {
+                "FlatMinItemResourceRequirement" => {
                     add_flat_min_item_resource(
                         random_generator_state,
                         &mut modifier_costs,
@@ -136,7 +170,7 @@ fn execute_craft_roll_modifier_costs(
                         max_cost,
                     )
                 }
-                6 => {
+                "FlatMaxItemResourceRequirement" => {
                     add_flat_max_item_resource(
                         random_generator_state,
                         &mut modifier_costs,
@@ -144,7 +178,7 @@ fn execute_craft_roll_modifier_costs(
                         max_cost,
                     )
                 }
-                7 => {
+                "FlatMinResistanceRequirement" => {
                     add_min_resistance(
                         random_generator_state,
                         crafting_info,
@@ -153,7 +187,7 @@ fn execute_craft_roll_modifier_costs(
                         max_cost,
                     )?
                 }
-                8 => {
+                "FlatMaxResistanceRequirement" => {
                     add_flat_max_resistance(
                         random_generator_state,
                         crafting_info,
@@ -162,7 +196,7 @@ fn execute_craft_roll_modifier_costs(
                         max_cost,
                     )?
                 }
-                9 => {
+                "FlatMinSumResistanceRequirement" => {
                     add_flat_min_sum_resistance(
                         random_generator_state,
                         crafting_info,
@@ -171,7 +205,7 @@ fn execute_craft_roll_modifier_costs(
                         max_cost,
                     )
                 }
-                10 => {
+                "FlatMaxSumResistanceRequirement" => {
                     add_flat_max_sum_resistance(
                         random_generator_state,
                         crafting_info,
@@ -180,10 +214,10 @@ fn execute_craft_roll_modifier_costs(
                         max_cost,
                     )
                 }
-                11 => {
+                "MinWinsInARow" => {
                     add_min_wins_in_row(random_generator_state, crafting_info, &mut modifier_costs)
                 }
-                12 => {
+                "MaxWinsInARow" => {
                     add_max_wins_in_row(random_generator_state, crafting_info, &mut modifier_costs)
                 }
                 _ => {
@@ -198,7 +232,7 @@ fn execute_craft_roll_modifier_costs(
         }
     }
 
-    Ok((modifier_costs, accumulated_cost))
+    Ok((modifier_costs, accumulated_cost, cost_rolls))
 }
 
 fn add_flat_min_attack(
@@ -501,10 +535,11 @@ fn add_flat_item_resource(
 fn execute_craft_roll_modifier_benefits(
     random_generator_state: &mut Lcg64Xsh32,
     crafting_info: &CraftingInfo,
+    rules: &Rules,
     cost: u64,
     minimum_elements: usize,
     maximum_elements: usize,
-) -> Vec<Gain> {
+) -> (Vec<Gain>, Vec<RolledEntry>) {
     let attack_types = DamageType::get_all()
         .into_iter()
         .filter(|attack_type| {
@@ -518,7 +553,13 @@ fn execute_craft_roll_modifier_benefits(
     let mut leftover_cost = cost;
 
     let all_modifier_gain_options = Gain::get_all_given_attack_types(attack_types);
+    let gain_table = RollTable::new(all_modifier_gain_options.iter().map(|gain_option| {
+        let weight =
+            rules.modifier_gain_weight(gain_part(gain_option).name, &crafting_info.possible_rolls);
+        (gain_option, weight)
+    }));
     let mut modifier_gain = Vec::new();
+    let mut gain_rolls = Vec::new();
     for i in minimum_elements..=maximum_elements {
         let cost_bonus = if i == maximum_elements {
             leftover_cost
@@ -527,9 +568,15 @@ fn execute_craft_roll_modifier_benefits(
         };
         leftover_cost -= cost_bonus;
 
-        let gain_seize = all_modifier_gain_options.len();
-        let modifier_index = random_generator_state.gen_range(0..gain_seize);
-        modifier_gain.push(match &all_modifier_gain_options[modifier_index] {
+        let Some((gain_option, weight)) = gain_table.roll(random_generator_state) else {
+            continue;
+        };
+        gain_rolls.push(RolledEntry::new(
+            &gain_entry_name(gain_option),
+            weight,
+            gain_table.total_weight(),
+        ));
+        modifier_gain.push(match gain_option {
             //TODO do the same with costs.
             FlatDamage(attack_type, _) => {
                 let damage = randomize_flat_damage(
@@ -596,7 +643,16 @@ fn execute_craft_roll_modifier_benefits(
             }
         });
     }
-    modifier_gain
+    (modifier_gain, gain_rolls)
+}
+
+// Gains are named like in item queries, with their argument, like FlatDamage(Fire).
+fn gain_entry_name(gain: &Gain) -> String {
+    let part = gain_part(gain);
+    match part.argument {
+        Some(argument) => format!("{}({argument})", part.name),
+        None => part.name.to_string(),
+    }
 }
 
 fn randomize_flat_damage(
@@ -619,6 +675,5 @@ fn randomize_flat_damage(
         .gen_range(min_damage..=max_damage)
         .div(2)
         .max(1)
-        .checked_mul(cost_bonus * 2)
-        .unwrap_or(u64::MAX)
+        .saturating_mul(cost_bonus * 2)
 }
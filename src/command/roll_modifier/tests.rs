@@ -6,7 +6,13 @@ mod tests_int {
         command::roll_modifier::execute_craft,
         generator::game::new_testing,
         the_world::{
-            item_modifier::Modifier, item_resource::Type, modifier_cost::Cost, modifier_gain::Gain,
+            item_modifier::Modifier,
+            item_resource::Type,
+            modifier_cost::Cost,
+            modifier_gain::Gain,
+            rarity::Rarity,
+            roll_table::{RollWeight, RolledEntry},
+            rules::Rules,
             treasure_types::TreasureType,
         },
     };
@@ -15,8 +21,10 @@ mod tests_int {
     fn basic_test() {
         let mut game = new_testing(Some([1; 16]));
         let _ = execute_craft(
-            &mut game.random_generator_state,
+            &mut game.random_generators.crafting,
             &game.inventory[0].as_ref().unwrap().crafting_info,
+            Rarity::Common,
+            &game.rules,
         );
     }
 
@@ -24,20 +32,60 @@ mod tests_int {
     fn seeding_test() {
         let mut game = new_testing(Some([1; 16]));
         let original_game = execute_craft(
-            &mut game.random_generator_state,
+            &mut game.random_generators.crafting,
             &game.inventory[0].as_ref().unwrap().crafting_info,
+            Rarity::Common,
+            &game.rules,
         );
 
         for _i in 1..1000 {
             let mut game = new_testing(Some([1; 16]));
             let result = execute_craft(
-                &mut game.random_generator_state,
+                &mut game.random_generators.crafting,
                 &game.inventory[0].as_ref().unwrap().crafting_info,
+                Rarity::Common,
+                &game.rules,
             );
             assert_eq!(original_game, result);
         }
     }
 
+    #[test]
+    fn test_weighted_rolls() {
+        let mut game = new_testing(Some([1; 16]));
+        let rules = Rules {
+            modifier_cost_weights: [("MinWinsInARow".into(), RollWeight::new(1))].into(),
+            modifier_gain_weights: [
+                ("FlatIncreaseRewardedItems".into(), RollWeight::new(1)),
+                ("FlatDamage".into(), RollWeight::new(0)),
+            ]
+            .into(),
+            ..Rules::default()
+        };
+
+        for _i in 1..100 {
+            let (result, rolls) = execute_craft(
+                &mut game.random_generators.crafting,
+                &game.inventory[0].as_ref().unwrap().crafting_info,
+                Rarity::Common,
+                &rules,
+            )
+            .unwrap();
+            assert!(result
+                .costs
+                .iter()
+                .all(|cost| matches!(cost, Cost::MinWinsInARow(_))));
+            assert!(result
+                .gains
+                .iter()
+                .all(|gain| matches!(gain, Gain::FlatIncreaseRewardedItems(_))));
+            assert!(rolls
+                .gains
+                .iter()
+                .all(|roll| *roll == RolledEntry::new("FlatIncreaseRewardedItems", 1, 1)));
+        }
+    }
+
     #[test]
     fn test_many_runs() {
         let mut game = new_testing(Some([1; 16]));
@@ -45,10 +93,14 @@ mod tests_int {
         let mut gain_modifiers: HashMap<Gain, u32> = HashMap::new();
 
         for _i in 1..1000 {
-            let result = execute_craft(
-                &mut game.random_generator_state,
+            let (result, rolls) = execute_craft(
+                &mut game.random_generators.crafting,
                 &game.inventory[0].as_ref().unwrap().crafting_info,
+                Rarity::Common,
+                &game.rules,
             ).expect("Should not Err! ");
+            assert_eq!(result.costs.len(), rolls.costs.len());
+            assert_eq!(result.gains.len(), rolls.gains.len());
 
             setup_costs(&mut cost_modifiers, &result);
 
@@ -64,7 +116,7 @@ mod tests_int {
                         .get(&Cost::FlatMinAttackRequirement(attack_type.clone(), 0))
                         .unwrap()
                         == &0
-                }).cloned()
+                })
                 .count()
         );
 
@@ -77,7 +129,7 @@ mod tests_int {
                         .get(&Cost::FlatMaxAttackRequirement(attack_type.clone(), 0))
                         .unwrap()
                         == &0
-                }).cloned()
+                })
                 .count()
         );
 
@@ -155,7 +207,7 @@ mod tests_int {
                         .get(&Cost::FlatMinResistanceRequirement(attack_type.clone(), 0))
                         .unwrap()
                         == &0
-                }).cloned()
+                })
                 .count()
         );
 
@@ -168,7 +220,7 @@ mod tests_int {
                         .get(&Cost::FlatMaxResistanceRequirement(attack_type.clone(), 0))
                         .unwrap()
                         == &0
-                }).cloned()
+                })
                 .count()
         );
 
@@ -196,7 +248,7 @@ mod tests_int {
                         .get(&Gain::FlatDamage(attack_type.clone(), 0))
                         .unwrap()
                         == &0
-                }).cloned()
+                })
                 .count()
         );
 
@@ -222,7 +274,7 @@ mod tests_int {
                         .get(&Gain::PercentageIncreaseDamage(attack_type.clone(), 0))
                         .unwrap()
                         == &0
-                }).cloned()
+                })
                 .count()
         );
 
@@ -235,7 +287,7 @@ mod tests_int {
                         .get(&Gain::FlatResistanceReduction(attack_type.clone(), 0))
                         .unwrap()
                         == &0
-                }).cloned()
+                })
                 .count()
         );
 
@@ -251,7 +303,7 @@ mod tests_int {
                         ))
                         .unwrap()
                         == &0
-                }).cloned()
+                })
                 .count()
         );
 
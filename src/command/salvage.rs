@@ -0,0 +1,126 @@
+use std::{
+    cmp::max,
+    collections::{BTreeSet, HashMap},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    my_little_rpg_errors::{ErrorCode, MyError},
+    the_world::{item::Item, item_query::ItemQuery, treasure_types::TreasureType},
+    Game,
+};
+
+mod tests;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteSalvageReport {
+    salvaged_item_indexes: Vec<usize>,
+    gained_treasure: HashMap<TreasureType, u64>,
+    treasure: HashMap<TreasureType, u64>,
+}
+
+pub fn execute_salvage_json(
+    game: &mut Game,
+    inventory_indexes: Vec<usize>,
+    query: Option<ItemQuery>,
+) -> Result<Value, MyError> {
+    execute(game, inventory_indexes, query).map(|result| json!(result))
+}
+
+// Destroys the items at the given inventory indexes and every unlocked item matching the query, and
+// pays out their salvage value. Nothing is salvaged when any of the indexes is invalid or locked.
+pub fn execute(
+    game: &mut Game,
+    inventory_indexes: Vec<usize>,
+    query: Option<ItemQuery>,
+) -> Result<ExecuteSalvageReport, MyError> {
+    let mut salvaged_item_indexes = BTreeSet::new();
+    for inventory_index in inventory_indexes {
+        if game.inventory.len() <= inventory_index {
+            return Err(MyError::create_execute_command_error(
+                ErrorCode::InventoryIndexOutOfRange,
+                format!(
+                    "inventory_index {} is not within the range of the inventory {}",
+                    inventory_index,
+                    game.inventory.len()
+                ),
+            ));
+        }
+        match &game.inventory[inventory_index] {
+            None => {
+                return Err(MyError::create_execute_command_error(
+                    ErrorCode::InventorySlotEmpty,
+                    format!("inventory_index {inventory_index} is empty."),
+                ));
+            }
+            Some(item) if item.locked => {
+                return Err(MyError::create_execute_command_error(
+                    ErrorCode::ItemLocked,
+                    format!(
+                        "inventory_index {inventory_index} is locked, unlock it to salvage it."
+                    ),
+                ));
+            }
+            Some(_) => {}
+        }
+        salvaged_item_indexes.insert(inventory_index);
+    }
+    if let Some(query) = &query {
+        salvaged_item_indexes.extend(game.inventory.iter().enumerate().filter_map(
+            |(inventory_index, item)| {
+                item.as_ref()
+                    .filter(|item| !item.locked && query.matches(item))
+                    .map(|_| inventory_index)
+            },
+        ));
+    }
+
+    if salvaged_item_indexes.is_empty() {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::NothingToSalvage,
+            format!("There are no items to salvage, the query {query:?} did not match any items."),
+        ));
+    }
+
+    let mut gained_treasure = HashMap::new();
+    for inventory_index in &salvaged_item_indexes {
+        let item = game.inventory[*inventory_index]
+            .take()
+            .expect("Only inventory slots with an item are salvaged.");
+        *gained_treasure.entry(TreasureType::Gold).or_insert(0) += calculate_salvage_value(&item);
+    }
+    for (treasure_type, amount) in &gained_treasure {
+        let treasure = game.treasure.entry(treasure_type.clone()).or_insert(0);
+        *treasure = treasure.saturating_add(*amount);
+    }
+
+    Ok(ExecuteSalvageReport {
+        salvaged_item_indexes: salvaged_item_indexes.into_iter().collect(),
+        gained_treasure,
+        treasure: game.treasure.clone(),
+    })
+}
+
+// Every modifier is worth a share of the average resistance the item could have been rolled
+// against, like the part of the place rewards that comes from the difficulty.
+pub fn calculate_salvage_value(item: &Item) -> u64 {
+    let possible_rolls = &item.crafting_info.possible_rolls;
+    let possible_resistance_values_sum = possible_rolls
+        .max_resistance
+        .values()
+        .chain(possible_rolls.min_resistance.values())
+        .sum::<u64>();
+    let possible_resistance_values_count = max(
+        1,
+        possible_rolls.max_resistance.len() + possible_rolls.min_resistance.len(),
+    );
+    let average_possible_resistance_values =
+        possible_resistance_values_sum / possible_resistance_values_count as u64;
+    let value_per_modifier = max(
+        1,
+        average_possible_resistance_values / max(1, item.crafting_info.places_count) as u64,
+    );
+    value_per_modifier.saturating_mul(item.modifiers.len() as u64)
+}
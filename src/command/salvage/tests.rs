@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests_int {
+    use std::collections::HashMap;
+
+    use crate::{
+        command::salvage::{calculate_salvage_value, execute},
+        generator::game::new_testing,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        the_world::{item_query::ItemQuery, treasure_types::TreasureType},
+    };
+
+    #[test]
+    fn test_calculate_salvage_value() {
+        let game = new_testing(Some([1; 16]));
+
+        assert_eq!(3, calculate_salvage_value(&game.equipped_items[0]));
+        assert_eq!(27, calculate_salvage_value(&game.equipped_items[1]));
+    }
+
+    #[test]
+    fn test_execute_salvage() {
+        let mut game = new_testing(Some([1; 16]));
+        game.treasure.insert(TreasureType::Gold, 10);
+        game.inventory[1] = Some(game.equipped_items[1].clone());
+
+        let result = execute(&mut game, vec![2, 0, 2], None).unwrap();
+
+        assert_eq!(vec![0, 2], result.salvaged_item_indexes);
+        assert_eq!(
+            HashMap::from([(TreasureType::Gold, 6)]),
+            result.gained_treasure
+        );
+        assert_eq!(HashMap::from([(TreasureType::Gold, 16)]), result.treasure);
+        assert_eq!(None, game.inventory[0]);
+        assert_eq!(None, game.inventory[2]);
+        assert_eq!(
+            7,
+            game.inventory.iter().filter(|item| item.is_some()).count()
+        );
+
+        let result = execute(&mut game, vec![3], Some(ItemQuery::NoCosts)).unwrap();
+
+        assert_eq!(vec![3, 4, 5, 6, 7, 8], result.salvaged_item_indexes);
+        assert_eq!(HashMap::from([(TreasureType::Gold, 34)]), result.treasure);
+        assert!(game.inventory[1].is_some());
+        assert_eq!(
+            1,
+            game.inventory.iter().filter(|item| item.is_some()).count()
+        );
+    }
+
+    #[test]
+    fn test_execute_salvage_errors() {
+        let mut game = new_testing(Some([1; 16]));
+        game.inventory[1] = None;
+        let original_game = game.clone();
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::InventorySlotEmpty,
+                "inventory_index 1 is empty.".to_string()
+            )),
+            execute(&mut game, vec![0, 1], None)
+        );
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::InventoryIndexOutOfRange,
+                "inventory_index 9 is not within the range of the inventory 9".to_string()
+            )),
+            execute(&mut game, vec![9], None)
+        );
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::NothingToSalvage,
+                "There are no items to salvage, the query Some(NoGains) did not match any items."
+                    .to_string()
+            )),
+            execute(&mut game, Vec::new(), Some(ItemQuery::NoGains))
+        );
+        assert_eq!(original_game, game);
+    }
+}
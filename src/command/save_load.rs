@@ -1,8 +1,25 @@
-use std::{fs, fs::create_dir_all};
+use std::{
+    ffi::OsString,
+    fs,
+    fs::{create_dir_all, File, OpenOptions},
+    io,
+    io::Write,
+    path::Path,
+    process,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
 
 use serde_json::{json, Value};
 
-use crate::{my_little_rpg_errors::MyError, Game};
+use crate::{
+    my_little_rpg_errors::{ErrorCode, MyError},
+    parser::save_format::{read_save_details, write_save, LoadedSave, SaveFormat},
+    the_world::save_root::SaveRoot,
+    Game,
+};
 
 mod tests;
 
@@ -10,75 +27,179 @@ pub fn execute_save_command_json(
     game: &Game,
     save_name: &str,
     save_path: Option<Box<str>>,
-) -> Value {
-    match execute_save_command(game, save_name, save_path) {
-        Ok(result) => json!(result),
-        Err(result) => json!(result),
-    }
+    format: SaveFormat,
+) -> Result<Value, MyError> {
+    execute_save_command(game, save_name, save_path, format).map(|result| json!(result))
 }
 
+// A save game only exists in one format, saving it in the other format replaces it.
 pub fn execute_save_command(
     game: &Game,
     save_name: &str,
     save_path: Option<Box<str>>,
+    format: SaveFormat,
 ) -> Result<Box<str>, MyError> {
-    let file_path = &*get_file_path(save_name, save_path)?;
-    match fs::write(file_path, format!("{}", json!(game)).as_bytes()) {
-        Err(error_message) => {
-            Err(MyError::create_save_load_error(format!(
-                "Failed saving the world! Reason: {error_message}"
-            )))
+    let file_path = game
+        .save_root
+        .file(save_name, save_path.as_deref(), format)?;
+    write_save_file(game, &file_path, format)?;
+
+    for other_format in SaveFormat::get_all()
+        .into_iter()
+        .filter(|other_format| *other_format != format)
+    {
+        let other_file_path = game
+            .save_root
+            .file(save_name, save_path.as_deref(), other_format)?;
+        if other_file_path.is_file() {
+            fs::remove_file(other_file_path).map_err(create_save_failed_error)?;
         }
-        Ok(()) => Ok("You saved the world!".into()),
     }
+    Ok("You saved the world!".into())
 }
 
 pub fn execute_load_command_json(
     game: &mut Game,
     save_name: &str,
     save_path: Option<Box<str>>,
+) -> Result<Value, MyError> {
+    let loaded_game = execute_load_command(&game.save_root, save_name, save_path)?;
+    Ok(replace_game_json(game, loaded_game))
+}
+
+// The loaded game keeps playing by the rules, and saving in the save root, of the game it replaces.
+pub(crate) fn replace_game_json(
+    game: &mut Game,
+    loaded_game: Game,
 ) -> Value {
-    match execute_load_command(save_name, save_path) {
-        Ok(new_game) => {
-            *game = new_game;
-            json!("Game is loaded!")
-        }
-        Err(result) => json!(result),
-    }
+    let rules = game.rules.clone();
+    let save_root = game.save_root.clone();
+    let generation = game.generation;
+    *game = loaded_game;
+    game.rules = rules;
+    game.save_root = save_root;
+    game.generation = generation;
+    json!("Game is loaded!")
 }
 
 pub fn execute_load_command(
+    save_root: &Arc<SaveRoot>,
     save_name: &str,
     save_path: Option<Box<str>>,
 ) -> Result<Game, MyError> {
-    let file_path = &*get_file_path(save_name, save_path)?;
-    //TODO better way of flattening this?!
+    let (file_path, _) = save_root.find_file(save_name, save_path.as_deref())?;
+    load_save_file(save_root, &file_path)
+}
+
+pub(crate) fn write_save_file(
+    game: &Game,
+    file_path: &Path,
+    format: SaveFormat,
+) -> Result<(), MyError> {
+    create_save_folder(file_path)?;
+    let data = write_save(game, format, game.save_root.signing_key());
+    write_file_atomically(file_path, &data).map_err(create_save_failed_error)
+}
+
+// The loaded game saves in the same save root it was loaded from.
+pub(crate) fn load_save_file(
+    save_root: &Arc<SaveRoot>,
+    file_path: &Path,
+) -> Result<Game, MyError> {
+    read_save_file(save_root, file_path).map(|loaded_save| {
+        let mut game = loaded_save.game;
+        game.save_root = save_root.clone();
+        game
+    })
+}
+
+// Save games of older versions are migrated to the current version while loading.
+pub(crate) fn read_save_file(
+    save_root: &SaveRoot,
+    file_path: &Path,
+) -> Result<LoadedSave, MyError> {
     fs::read(file_path)
         .map_err(|error| {
             let error_message = format!("Failed loading the world! Reason: {error}");
-            MyError::create_save_load_error(error_message)
-        })
-        .map(|data| serde_json::from_slice::<Game>(data.as_slice()))
-        .and_then(|result| {
-            result.map_err(|error| {
-                let error_message = format!("Failed loading the world! Reason: {error}");
-                MyError::create_save_load_error(error_message)
-            })
+            MyError::create_save_load_error(ErrorCode::LoadFailed, error_message)
         })
+        .and_then(|data| read_save_details(&data, save_root.signing_key()))
 }
 
-fn get_file_path(
-    save_name: &str,
-    save_path: Option<Box<str>>,
-) -> Result<Box<str>, MyError> {
-    let save_path: Box<str> = save_path.unwrap_or_else(|| "./save_games/".into());
+// Numbers the temporary files of this process, so sessions saving the same save game at the same
+// time never write into each others temporary file.
+static TEMPORARY_FILE_COUNT: AtomicU64 = AtomicU64::new(0);
 
-    match create_dir_all(save_path.as_ref()) {
-        Err(error_message) => {
-            Err(MyError::create_save_load_error(format!(
-                "Failed creating the folder for the save games, Reason: {error_message}"
-            )))
+// The save game is written to a new file next to it and renamed afterwards, so a crash while saving
+// leaves the old save game instead of a half written one. The folder is synced after the rename,
+// otherwise a crash can still lose the rename.
+fn write_file_atomically(
+    file_path: &Path,
+    data: &[u8],
+) -> io::Result<()> {
+    let (temporary_file_path, mut file) = create_temporary_file(file_path)?;
+    let write_result = file.write_all(data).and_then(|()| file.sync_all());
+    drop(file);
+    let result = write_result.and_then(|()| fs::rename(&temporary_file_path, file_path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temporary_file_path);
+    }
+    result?;
+    sync_folder(file_path)
+}
+
+// A temporary file left behind by a crashed server with the same process id is never written into,
+// the next number is tried instead.
+fn create_temporary_file(file_path: &Path) -> io::Result<(OsString, File)> {
+    loop {
+        let mut temporary_file_path = file_path.as_os_str().to_owned();
+        temporary_file_path.push(format!(
+            ".{}.{}.tmp",
+            process::id(),
+            TEMPORARY_FILE_COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&temporary_file_path)
+        {
+            Ok(file) => return Ok((temporary_file_path, file)),
+            Err(error) if error.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(error) => return Err(error),
         }
-        Ok(()) => Ok(format!("{save_path}{save_name}.json").into()),
     }
 }
+
+#[cfg(unix)]
+fn sync_folder(file_path: &Path) -> io::Result<()> {
+    let folder = match file_path.parent() {
+        Some(folder) if !folder.as_os_str().is_empty() => folder,
+        _ => Path::new("."),
+    };
+    File::open(folder)?.sync_all()
+}
+
+// Only unix can open a folder to sync it, elsewhere the rename is left to the file system.
+#[cfg(not(unix))]
+fn sync_folder(_file_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+fn create_save_failed_error(error_message: std::io::Error) -> MyError {
+    MyError::create_save_load_error(
+        ErrorCode::SaveFailed,
+        format!("Failed saving the world! Reason: {error_message}"),
+    )
+}
+
+fn create_save_folder(file_path: &Path) -> Result<(), MyError> {
+    let Some(save_folder) = file_path.parent() else {
+        return Ok(());
+    };
+    create_dir_all(save_folder).map_err(|error_message| {
+        MyError::create_save_load_error(
+            ErrorCode::SaveFolderNotCreated,
+            format!("Failed creating the folder for the save games, Reason: {error_message}"),
+        )
+    })
+}
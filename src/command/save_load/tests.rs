@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests_int {
-    use std::fs;
+    use std::{fs, sync::Arc, thread};
 
     use crate::{
         command::{
@@ -8,21 +8,48 @@ mod tests_int {
             save_load::{execute_load_command, execute_save_command},
         },
         generator::game::new_testing,
-        the_world::treasure_types::TreasureType::Gold,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        parser::save_format::SaveFormat,
+        the_world::{save_root::SaveRoot, treasure_types::TreasureType::Gold},
     };
 
     #[test]
     fn save_the_world_special_chars() {
         // I implemented this test in an effort to trigger the saving errors, but could not. So I just converted it to a ordianry test instead.
-        let game = new_testing(Some([1; 16]));
+        let mut game = new_testing(Some([1; 16]));
+        game.set_save_root(SaveRoot::new("./testing/"));
         assert_eq!(
             Box::from("You saved the world!"),
-            execute_save_command(&game, "save_load_seeding_test", Some("./testing/".into())).unwrap()
+            execute_save_command(&game, "save_load_seeding_test", Some("./folder/".into()), SaveFormat::Json).unwrap()
         );
+        assert!(fs::exists("./testing/folder/save_load_seeding_test.json").unwrap());
+        assert_eq!(1, fs::read_dir("./testing/folder/").unwrap().count());
 
         fs::remove_dir_all("./testing/").expect("Had trouble cleanup after save_load_time");
     }
 
+    // Every save writes its own temporary file, so saves of the same save game at the same time
+    // never mix their data.
+    #[test]
+    fn save_the_same_save_game_concurrently() {
+        let mut game = new_testing(Some([1; 16]));
+        game.set_save_root(SaveRoot::new("./testing_concurrent_saves/"));
+
+        thread::scope(|scope| {
+            for _i in 0..8 {
+                scope.spawn(|| {
+                    execute_save_command(&game, "concurrent", None, SaveFormat::Json).unwrap();
+                });
+            }
+        });
+        let file_count = fs::read_dir("./testing_concurrent_saves/").unwrap().count();
+        let loaded_game = execute_load_command(&game.save_root, "concurrent", None);
+        fs::remove_dir_all("./testing_concurrent_saves/").unwrap();
+
+        assert_eq!(1, file_count);
+        assert_eq!(Ok(game), loaded_game);
+    }
+
     #[test]
     fn seeding_test() {
         let mut game = new_testing(Some([1; 16]));
@@ -31,9 +58,10 @@ mod tests_int {
 
         for _i in 1..1000 {
             let mut game = new_testing(Some([1; 16]));
+            game.set_save_root(SaveRoot::new("./testing2/"));
             game.treasure.insert(Gold, 1000);
-            execute_save_command(&game, "save_load_seeding_test", Some("./testing2/".into())).unwrap();
-            let mut parsed_game = execute_load_command("save_load_seeding_test", Some("./testing2/".into())).unwrap();
+            execute_save_command(&game, "save_load_seeding_test", None, SaveFormat::Json).unwrap();
+            let mut parsed_game = execute_load_command(&game.save_root, "save_load_seeding_test", None).unwrap();
 
             assert_eq!(game, parsed_game);
 
@@ -44,4 +72,85 @@ mod tests_int {
         //Cleanup
         fs::remove_dir_all("./testing2/").expect("Had trouble cleanup after save_load_time");
     }
+
+    #[test]
+    fn save_outside_of_the_save_root() {
+        let mut game = new_testing(Some([1; 16]));
+        game.set_save_root(SaveRoot::new("./testing3/"));
+
+        assert!(matches!(
+            execute_save_command(&game, "escaped", Some("../".into()), SaveFormat::Json),
+            Err(MyError::SaveLoad {
+                code: ErrorCode::InvalidSavePath,
+                ..
+            })
+        ));
+        assert!(matches!(
+            execute_save_command(&game, "../escaped", None, SaveFormat::Json),
+            Err(MyError::SaveLoad {
+                code: ErrorCode::InvalidSaveName,
+                ..
+            })
+        ));
+        assert!(matches!(
+            execute_load_command(&game.save_root, "save", Some("/etc/".into())),
+            Err(MyError::SaveLoad {
+                code: ErrorCode::InvalidSavePath,
+                ..
+            })
+        ));
+        assert!(!fs::exists("./testing3/").unwrap());
+        assert!(!fs::exists("./escaped.json").unwrap());
+    }
+
+    #[test]
+    fn save_binary_replaces_json() {
+        let mut game = new_testing(Some([1; 16]));
+        game.set_save_root(SaveRoot::new("./testing4/"));
+
+        execute_save_command(&game, "save", None, SaveFormat::Json).unwrap();
+        execute_save_command(&game, "save", None, SaveFormat::Binary).unwrap();
+        assert!(!fs::exists("./testing4/save.json").unwrap());
+        assert!(
+            fs::metadata("./testing4/save.sav").unwrap().len()
+                < serde_json::to_string(&game).unwrap().len() as u64
+        );
+        assert_eq!(
+            game,
+            execute_load_command(&game.save_root, "save", None).unwrap()
+        );
+
+        fs::remove_dir_all("./testing4/").expect("Had trouble cleanup after save_binary");
+    }
+
+    #[test]
+    fn load_changed_save() {
+        let mut game = new_testing(Some([1; 16]));
+        game.set_save_root(SaveRoot::new("./testing5/").with_signing_key(*b"secret"));
+        execute_save_command(&game, "save", None, SaveFormat::Json).unwrap();
+
+        let save = fs::read_to_string("./testing5/save.json").unwrap();
+        let changed_save = save.replace("\"wins\":", "\"wins\":9");
+        assert_ne!(save, changed_save);
+        fs::write("./testing5/changed.json", changed_save).unwrap();
+        assert!(matches!(
+            execute_load_command(&game.save_root, "changed", None),
+            Err(MyError::SaveLoad {
+                code: ErrorCode::SaveChecksumMismatch,
+                ..
+            })
+        ));
+
+        let other_key_root = Arc::new(SaveRoot::new("./testing5/").with_signing_key(*b"other"));
+        assert!(matches!(
+            execute_load_command(&other_key_root, "save", None),
+            Err(MyError::SaveLoad {
+                code: ErrorCode::SaveChecksumMismatch,
+                ..
+            })
+        ));
+        assert!(execute_load_command(&game.save_root, "save", None).is_ok());
+
+        fs::remove_dir_all("./testing5/").expect("Had trouble cleanup after load_changed_save");
+    }
 }
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    command::r#move::{fight, FailedItemCost},
+    my_little_rpg_errors::{ErrorCode, MyError},
+    the_world::damage_types::DamageType,
+    Game,
+};
+
+mod tests;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct PlaceSimulation {
+    place_index: usize,
+    wins: bool,
+    damage_margin: HashMap<DamageType, i64>,
+    failed_costs: Vec<FailedItemCost>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteSimulateReport {
+    places: Vec<PlaceSimulation>,
+}
+
+pub fn execute_simulate_json(
+    game: &Game,
+    place_index: Option<usize>,
+) -> Result<Value, MyError> {
+    execute(game, place_index).map(|result| json!(result))
+}
+
+// Fights are not random, so the simulation tells exactly whether a move would win. Without a place
+// index every place is simulated.
+pub fn execute(
+    game: &Game,
+    place_index: Option<usize>,
+) -> Result<ExecuteSimulateReport, MyError> {
+    let place_indexes = match place_index {
+        Some(place_index) if game.places.len() <= place_index => {
+            return Err(MyError::create_execute_command_error(
+                ErrorCode::PlaceNotFound,
+                format!(
+                    "Index {} is out of range of places, places is {} long.",
+                    place_index,
+                    game.places.len()
+                ),
+            ));
+        }
+        Some(place_index) => place_index..place_index + 1,
+        None => 0..game.places.len(),
+    };
+
+    Ok(ExecuteSimulateReport {
+        places: place_indexes
+            .map(|place_index| simulate_place(game, place_index))
+            .collect(),
+    })
+}
+
+fn simulate_place(
+    game: &Game,
+    place_index: usize,
+) -> PlaceSimulation {
+    let fight = fight(game, place_index);
+
+    // Positive when there is damage to spare, negative when that much damage is missing.
+    let damage_margin = game.places[place_index]
+        .resistance
+        .iter()
+        .map(|(damage_type, resistance)| {
+            let damage = fight.damage.get(damage_type).copied().unwrap_or(0);
+            let margin = i128::from(damage) - i128::from(*resistance);
+            let margin =
+                i64::try_from(margin).unwrap_or(if margin < 0 { i64::MIN } else { i64::MAX });
+            (damage_type.clone(), margin)
+        })
+        .collect();
+
+    PlaceSimulation {
+        place_index,
+        wins: fight.rewards.is_some(),
+        damage_margin,
+        failed_costs: fight.failed_costs,
+    }
+}
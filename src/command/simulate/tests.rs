@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests_int {
+    use crate::{
+        command::{r#move::execute as execute_move_command, simulate::execute},
+        generator::game::new_testing,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        the_world::item_resource::Type,
+    };
+
+    #[test]
+    fn test_execute_simulate_does_not_change_the_game() {
+        let game = new_testing(Some([1; 16]));
+        let original_game = game.clone();
+
+        let result = execute(&game, Some(0)).unwrap();
+
+        assert_eq!(original_game, game);
+        assert_eq!(1, result.places.len());
+        assert_eq!(0, result.places[0].place_index);
+        assert!(!result.places[0].wins);
+        assert_eq!(1, result.places[0].failed_costs.len());
+        assert_eq!(1, result.places[0].failed_costs[0].equipped_item_index);
+        assert!(matches!(
+            result.places[0].failed_costs[0].error,
+            MyError::ExecuteCommand {
+                code: ErrorCode::CantPayItemResourceCost,
+                ..
+            }
+        ));
+        assert!(result.places[0]
+            .damage_margin
+            .values()
+            .any(|margin| *margin < 0));
+    }
+
+    #[test]
+    fn test_execute_simulate_wins() {
+        let mut game = new_testing(Some([1; 16]));
+        game.item_resources.insert(Type::Mana, 5);
+
+        let result = execute(&game, Some(0)).unwrap();
+
+        assert!(result.places[0].wins);
+        assert!(result.places[0].failed_costs.is_empty());
+        assert_eq!(
+            game.places[0].resistance.len(),
+            result.places[0].damage_margin.len()
+        );
+        assert!(result.places[0]
+            .damage_margin
+            .values()
+            .all(|margin| *margin >= 0));
+    }
+
+    #[test]
+    fn test_execute_simulate_matches_move() {
+        let mut game = new_testing(Some([1; 16]));
+        game.item_resources.insert(Type::Mana, 3);
+
+        let result = execute(&game, None).unwrap();
+
+        assert_eq!(game.places.len(), result.places.len());
+        for place_simulation in result.places {
+            let move_result = execute_move_command(&mut game.clone(), place_simulation.place_index);
+            assert_eq!(move_result.is_ok(), place_simulation.wins);
+        }
+    }
+
+    #[test]
+    fn test_execute_simulate_place_not_found() {
+        let game = new_testing(Some([1; 16]));
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::PlaceNotFound,
+                "Index 10 is out of range of places, places is 10 long.".to_string()
+            )),
+            execute(&game, Some(10))
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    the_world::inventory_sort::{InventorySortKey, SortOrder},
+    Game,
+};
+
+mod tests;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteSortInventoryReport {
+    // For every inventory slot after sorting, the index the item had before.
+    previous_indexes: Vec<Option<usize>>,
+}
+
+pub fn execute_sort_inventory_json(
+    game: &mut Game,
+    sort_key: &InventorySortKey,
+    sort_order: SortOrder,
+    keep_empty_slots: bool,
+) -> Value {
+    json!(execute(game, sort_key, sort_order, keep_empty_slots))
+}
+
+// The sort is stable, so items with the same value keep their order. Empty slots are removed like
+// ReorderInventory does, or moved to the end when they are kept.
+pub fn execute(
+    game: &mut Game,
+    sort_key: &InventorySortKey,
+    sort_order: SortOrder,
+    keep_empty_slots: bool,
+) -> ExecuteSortInventoryReport {
+    let inventory = std::mem::take(&mut game.inventory);
+    let (mut items, empty_slots): (Vec<_>, Vec<_>) = inventory
+        .into_iter()
+        .enumerate()
+        .partition(|(_, item)| item.is_some());
+
+    items.sort_by_cached_key(|(_, item)| {
+        let sort_value = item.as_ref().map_or(0, |item| sort_key.sort_value(item));
+        match sort_order {
+            SortOrder::Ascending => sort_value,
+            SortOrder::Descending => u64::MAX - sort_value,
+        }
+    });
+    if keep_empty_slots {
+        items.extend(empty_slots);
+    }
+
+    let previous_indexes = items
+        .iter()
+        .map(|(previous_index, item)| item.as_ref().map(|_| *previous_index))
+        .collect();
+    game.inventory = items.into_iter().map(|(_, item)| item).collect();
+
+    ExecuteSortInventoryReport { previous_indexes }
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests_int {
+    use crate::{
+        command::sort_inventory::{execute, ExecuteSortInventoryReport},
+        generator::game::new_testing,
+        the_world::{
+            damage_types::DamageType,
+            inventory_sort::{InventorySortKey, SortOrder},
+            item::test_util::create_item,
+            item_resource::Type,
+            modifier_cost::Cost,
+        },
+    };
+
+    #[test]
+    fn test_execute_sort_by_flat_damage() {
+        let mut game = new_testing(Some([1; 16]));
+        game.inventory[3] = None;
+
+        assert_eq!(
+            ExecuteSortInventoryReport {
+                previous_indexes: vec![
+                    Some(1),
+                    Some(0),
+                    Some(2),
+                    Some(4),
+                    Some(5),
+                    Some(6),
+                    Some(7),
+                    Some(8)
+                ],
+            },
+            execute(
+                &mut game,
+                &InventorySortKey::FlatDamage(Some(DamageType::Fire)),
+                SortOrder::Descending,
+                false
+            )
+        );
+        assert_eq!(8, game.inventory.len());
+        assert_eq!(
+            1,
+            InventorySortKey::FlatDamage(Some(DamageType::Fire))
+                .sort_value(game.inventory[0].as_ref().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_execute_sort_empty_last() {
+        let mut game = new_testing(Some([1; 16]));
+        game.inventory.truncate(3);
+        game.inventory[0] = Some(create_item(&game));
+        game.inventory.insert(1, None);
+
+        assert_eq!(
+            ExecuteSortInventoryReport {
+                previous_indexes: vec![Some(2), Some(3), Some(0), None],
+            },
+            execute(
+                &mut game,
+                &InventorySortKey::Modifiers,
+                SortOrder::Ascending,
+                true
+            )
+        );
+        assert_eq!(4, game.inventory.len());
+        assert_eq!(2, game.inventory[2].as_ref().unwrap().modifiers.len());
+        assert!(game.inventory[3].is_none());
+    }
+
+    #[test]
+    fn test_sort_value() {
+        let game = new_testing(Some([1; 16]));
+        let mut item = create_item(&game);
+        item.modifiers[0]
+            .costs
+            .push(Cost::FlatItemResource(Type::Mana, 5));
+        item.modifiers[1]
+            .costs
+            .push(Cost::FlatItemResource(Type::Mana, 5));
+
+        assert_eq!(2, InventorySortKey::CostBurden.sort_value(&item));
+        assert_eq!(0, InventorySortKey::FlatDamage(None).sort_value(&item));
+        assert_eq!(
+            100 * 9,
+            InventorySortKey::FlatDamage(None).sort_value(&game.equipped_items[1])
+        );
+        assert_eq!(
+            game.places.len() as u64,
+            InventorySortKey::PlacesCount.sort_value(&item)
+        );
+    }
+}
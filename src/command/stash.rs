@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    my_little_rpg_errors::{ErrorCode, MyError},
+    the_world::stash::Stash,
+    Game,
+};
+
+mod tests;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteStashReport {
+    inventory_index: usize,
+    stash_tab: Box<str>,
+    stash_index: usize,
+    stash: Stash,
+}
+
+pub fn execute_stash_item_json(
+    game: &mut Game,
+    inventory_index: usize,
+    stash_tab: &str,
+) -> Result<Value, MyError> {
+    execute_stash_item(game, inventory_index, stash_tab).map(|result| json!(result))
+}
+
+// Moves the item out of the inventory to the end of the stash tab, the tab is created when needed.
+pub fn execute_stash_item(
+    game: &mut Game,
+    inventory_index: usize,
+    stash_tab: &str,
+) -> Result<ExecuteStashReport, MyError> {
+    if game.inventory.len() <= inventory_index {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::InventoryIndexOutOfRange,
+            format!(
+                "inventory_index {} is not within the range of the inventory {}",
+                inventory_index,
+                game.inventory.len()
+            ),
+        ));
+    }
+    if game.inventory[inventory_index].is_none() {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::InventorySlotEmpty,
+            format!("inventory_index {inventory_index} is empty."),
+        ));
+    }
+    if game.stash.capacity <= game.stash.items_count() {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::StashFull,
+            format!(
+                "The stash is full, it can hold {} items. Expand it with ExpandStash.",
+                game.stash.capacity
+            ),
+        ));
+    }
+
+    let item = game.inventory[inventory_index].take().unwrap_or_else(|| {
+        panic!("Item at index {inventory_index} did exist earlier but does not anymore.")
+    });
+    let tab = game.stash.tabs.entry(stash_tab.into()).or_default();
+    tab.push(item);
+
+    Ok(ExecuteStashReport {
+        inventory_index,
+        stash_tab: stash_tab.into(),
+        stash_index: tab.len() - 1,
+        stash: game.stash.clone(),
+    })
+}
+
+pub fn execute_unstash_item_json(
+    game: &mut Game,
+    stash_tab: &str,
+    stash_index: usize,
+) -> Result<Value, MyError> {
+    execute_unstash_item(game, stash_tab, stash_index).map(|result| json!(result))
+}
+
+// Moves the item back into the first empty inventory slot, or to the end of the inventory.
+pub fn execute_unstash_item(
+    game: &mut Game,
+    stash_tab: &str,
+    stash_index: usize,
+) -> Result<ExecuteStashReport, MyError> {
+    let Some(tab) = game.stash.tabs.get_mut(stash_tab) else {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::StashTabNotFound,
+            format!(
+                "There is no stash tab named {stash_tab}, the tabs are {:?}",
+                game.stash.tabs.keys().collect::<Vec<_>>()
+            ),
+        ));
+    };
+    if tab.len() <= stash_index {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::StashIndexOutOfRange,
+            format!(
+                "stash_index {} is not within the range of the stash tab {} {}",
+                stash_index,
+                stash_tab,
+                tab.len()
+            ),
+        ));
+    }
+
+    let item = tab.remove(stash_index);
+    if tab.is_empty() {
+        game.stash.tabs.remove(stash_tab);
+    }
+    let inventory_index = match game.inventory.iter().position(Option::is_none) {
+        Some(inventory_index) => {
+            game.inventory[inventory_index] = Some(item);
+            inventory_index
+        }
+        None => {
+            game.inventory.push(Some(item));
+            game.inventory.len() - 1
+        }
+    };
+
+    Ok(ExecuteStashReport {
+        inventory_index,
+        stash_tab: stash_tab.into(),
+        stash_index,
+        stash: game.stash.clone(),
+    })
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests_int {
+    use crate::{
+        command::stash::{execute_stash_item, execute_unstash_item},
+        generator::game::new_testing,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        Game,
+    };
+
+    #[test]
+    fn test_execute_stash_item() {
+        let mut game = new_testing(Some([1; 16]));
+        let item = game.inventory[2].clone().unwrap();
+
+        assert!(execute_stash_item(&mut game, 2, "keepers").is_ok());
+        assert!(game.inventory[2].is_none());
+        assert_eq!(9, game.inventory.len());
+        assert_eq!(Some(&vec![item]), game.stash.tabs.get("keepers"));
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::InventorySlotEmpty,
+                "inventory_index 2 is empty.".to_string()
+            )),
+            execute_stash_item(&mut game, 2, "keepers")
+        );
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::InventoryIndexOutOfRange,
+                "inventory_index 9 is not within the range of the inventory 9".to_string()
+            )),
+            execute_stash_item(&mut game, 9, "keepers")
+        );
+
+        game.stash.capacity = 2;
+        assert!(execute_stash_item(&mut game, 0, "fodder").is_ok());
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::StashFull,
+                "The stash is full, it can hold 2 items. Expand it with ExpandStash.".to_string()
+            )),
+            execute_stash_item(&mut game, 1, "fodder")
+        );
+        assert!(game.inventory[1].is_some());
+    }
+
+    #[test]
+    fn test_execute_unstash_item() {
+        let mut game = new_testing(Some([1; 16]));
+        let item = game.inventory[3].clone().unwrap();
+        execute_stash_item(&mut game, 3, "keepers").unwrap();
+        execute_stash_item(&mut game, 4, "keepers").unwrap();
+        execute_stash_item(&mut game, 5, "keepers").unwrap();
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::StashTabNotFound,
+                "There is no stash tab named fodder, the tabs are [\"keepers\"]".to_string()
+            )),
+            execute_unstash_item(&mut game, "fodder", 0)
+        );
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::StashIndexOutOfRange,
+                "stash_index 3 is not within the range of the stash tab keepers 3".to_string()
+            )),
+            execute_unstash_item(&mut game, "keepers", 3)
+        );
+
+        assert!(execute_unstash_item(&mut game, "keepers", 0).is_ok());
+        assert_eq!(Some(item), game.inventory[3]);
+        assert_eq!(2, game.stash.items_count());
+
+        game.inventory.retain(Option::is_some);
+        assert!(execute_unstash_item(&mut game, "keepers", 1).is_ok());
+        assert!(execute_unstash_item(&mut game, "keepers", 0).is_ok());
+        assert_eq!(9, game.inventory.len());
+        assert!(game.stash.tabs.is_empty());
+    }
+
+    #[test]
+    fn test_load_game_without_stash() {
+        let game = new_testing(Some([1; 16]));
+        let mut game_json = serde_json::to_value(&game).unwrap();
+        game_json.as_object_mut().unwrap().remove("stash");
+
+        let loaded_game: Game = serde_json::from_value(game_json).unwrap();
+
+        assert_eq!(game, loaded_game);
+    }
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    my_little_rpg_errors::{ErrorCode, MyError},
+    the_world::game_history::GameHistory,
+    Game,
+};
+
+mod tests;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteUndoRedoReport {
+    undo_count: usize,
+    redo_count: usize,
+}
+
+pub fn execute_undo_json(
+    game: &mut Game,
+    game_history: &mut GameHistory,
+) -> Result<Value, MyError> {
+    execute_undo(game, game_history).map(|result| json!(result))
+}
+
+pub fn execute_undo(
+    game: &mut Game,
+    game_history: &mut GameHistory,
+) -> Result<ExecuteUndoRedoReport, MyError> {
+    check_not_changed_by_other_session(game, game_history)?;
+    if !game_history.undo(game) {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::NothingToUndo,
+            "There is nothing to undo.".to_string(),
+        ));
+    }
+
+    Ok(create_report(game_history))
+}
+
+pub fn execute_redo_json(
+    game: &mut Game,
+    game_history: &mut GameHistory,
+) -> Result<Value, MyError> {
+    execute_redo(game, game_history).map(|result| json!(result))
+}
+
+pub fn execute_redo(
+    game: &mut Game,
+    game_history: &mut GameHistory,
+) -> Result<ExecuteUndoRedoReport, MyError> {
+    check_not_changed_by_other_session(game, game_history)?;
+    if !game_history.redo(game) {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::NothingToRedo,
+            "There is nothing to redo.".to_string(),
+        ));
+    }
+
+    Ok(create_report(game_history))
+}
+
+// The history is cleared, since none of its snapshots can be restored without throwing away what
+// the other session did.
+fn check_not_changed_by_other_session(
+    game: &Game,
+    game_history: &mut GameHistory,
+) -> Result<(), MyError> {
+    if !game_history.is_changed_by_other_session(game) {
+        return Ok(());
+    }
+    game_history.clear();
+    Err(MyError::create_execute_command_error(
+        ErrorCode::GameChangedByOtherSession,
+        "Another session changed the game since your last command, undoing would throw its \
+         changes away. The undo history was cleared."
+            .to_string(),
+    ))
+}
+
+fn create_report(game_history: &GameHistory) -> ExecuteUndoRedoReport {
+    ExecuteUndoRedoReport {
+        undo_count: game_history.undo_count(),
+        redo_count: game_history.redo_count(),
+    }
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests_int {
+    use crate::{
+        command::{
+            r#move::execute as execute_move_command,
+            undo_redo::{execute_redo, execute_undo},
+        },
+        generator::game::new_testing,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        the_world::game_history::GameHistory,
+    };
+
+    #[test]
+    fn test_execute_undo_redo() {
+        let mut game = new_testing(Some([1; 16]));
+        let mut game_history = GameHistory::new(10);
+        let original_game = game.clone();
+
+        let game_before = game.clone();
+        let _move_result = execute_move_command(&mut game, 0);
+        game_history.record(game_before, &mut game);
+        let moved_game = game.clone();
+        assert_ne!(original_game, moved_game);
+
+        assert!(execute_undo(&mut game, &mut game_history).is_ok());
+        assert_eq!(original_game, game);
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::NothingToUndo,
+                "There is nothing to undo.".to_string()
+            )),
+            execute_undo(&mut game, &mut game_history)
+        );
+
+        assert!(execute_redo(&mut game, &mut game_history).is_ok());
+        assert_eq!(moved_game, game);
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::NothingToRedo,
+                "There is nothing to redo.".to_string()
+            )),
+            execute_redo(&mut game, &mut game_history)
+        );
+    }
+
+    #[test]
+    fn test_undo_after_another_session_changed_the_game() {
+        let mut game = new_testing(Some([1; 16]));
+        let mut game_history = GameHistory::new(10);
+        let mut other_game_history = GameHistory::new(10);
+
+        let game_before = game.clone();
+        let _move_result = execute_move_command(&mut game, 0);
+        game_history.record(game_before, &mut game);
+        let game_before = game.clone();
+        let _move_result = execute_move_command(&mut game, 0);
+        other_game_history.record(game_before, &mut game);
+        let moved_twice_game = game.clone();
+
+        assert!(matches!(
+            execute_undo(&mut game, &mut game_history),
+            Err(MyError::ExecuteCommand {
+                code: ErrorCode::GameChangedByOtherSession,
+                ..
+            })
+        ));
+        assert_eq!(moved_twice_game, game);
+        assert_eq!(0, game_history.undo_count());
+
+        assert!(execute_undo(&mut game, &mut other_game_history).is_ok());
+        assert_eq!(1, game.statistics.moves_count);
+    }
+
+    #[test]
+    fn test_new_command_clears_redo() {
+        let mut game = new_testing(Some([1; 16]));
+        let mut game_history = GameHistory::new(10);
+
+        let game_before = game.clone();
+        let _move_result = execute_move_command(&mut game, 0);
+        game_history.record(game_before, &mut game);
+        execute_undo(&mut game, &mut game_history).unwrap();
+        assert_eq!(1, game_history.redo_count());
+
+        let game_before = game.clone();
+        let _move_result = execute_move_command(&mut game, 0);
+        game_history.record(game_before, &mut game);
+        assert_eq!(0, game_history.redo_count());
+        assert_eq!(1, game_history.undo_count());
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let mut game = new_testing(Some([1; 16]));
+        let mut game_history = GameHistory::new(3);
+
+        for _i in 0..5 {
+            let game_before = game.clone();
+            let _move_result = execute_move_command(&mut game, 0);
+            game_history.record(game_before, &mut game);
+        }
+        assert_eq!(3, game_history.undo_count());
+
+        let mut game_history = GameHistory::new(0);
+        let game_before = game.clone();
+        let _move_result = execute_move_command(&mut game, 0);
+        game_history.record(game_before, &mut game);
+        assert_eq!(0, game_history.undo_count());
+    }
+}
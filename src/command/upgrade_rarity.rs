@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    my_little_rpg_errors::{ErrorCode, MyError},
+    the_world::{
+        index_specifier::{
+            calculate_absolute_item_indexes, expand_item_queries, ErrorConditions, IndexSpecifier,
+        },
+        item::Item,
+        rarity::Rarity,
+    },
+    Game,
+};
+
+mod tests;
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ExecuteUpgradeRarityReport {
+    new_item: Item,
+    paid_cost: usize,
+    new_cost: Option<usize>,
+}
+
+pub fn execute_upgrade_rarity_json(
+    game: &mut Game,
+    inventory_index: usize,
+    sacrifice_item_indexes: Vec<IndexSpecifier>,
+) -> Result<Value, MyError> {
+    execute_upgrade_rarity(game, inventory_index, sacrifice_item_indexes)
+        .map(|result| json!(result))
+}
+
+// Raises the rarity of the item by one tier, by sacrificing items that are at least as rare.
+pub fn execute_upgrade_rarity(
+    game: &mut Game,
+    inventory_index: usize,
+    sacrifice_item_indexes: Vec<IndexSpecifier>,
+) -> Result<ExecuteUpgradeRarityReport, MyError> {
+    if game.inventory.len() <= inventory_index {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::InventoryIndexOutOfRange,
+            format!(
+                "inventory_index {} is not within the range of the inventory {}",
+                inventory_index,
+                game.inventory.len()
+            ),
+        ));
+    }
+    let inventory_item = game.inventory[inventory_index].as_ref().ok_or_else(|| {
+        MyError::create_execute_command_error(
+            ErrorCode::InventorySlotEmpty,
+            format!("inventory_index {inventory_index} is empty."),
+        )
+    })?;
+    let Some(new_rarity) = inventory_item.rarity.next() else {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::RarityLimitReached,
+            format!(
+                "inventory_index {inventory_index} is already {:?}, it can not be upgraded any \
+                 further.",
+                inventory_item.rarity
+            ),
+        ));
+    };
+
+    let error_conditions = get_index_specifier_error_conditions(inventory_item.rarity);
+    let mut sacrifice_item_indexes = expand_item_queries(
+        game,
+        inventory_index,
+        sacrifice_item_indexes,
+        &error_conditions,
+    );
+
+    let cost = execute_upgrade_rarity_calculate_cost(game, inventory_index).unwrap_or(0);
+    if sacrifice_item_indexes.len() < cost {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::NotEnoughSacrifices,
+            format!(
+                "upgrade_rarity needs {} items to be sacrificed but you only provided {}",
+                cost,
+                sacrifice_item_indexes.len()
+            ),
+        ));
+    }
+    sacrifice_item_indexes.truncate(cost);
+
+    let calculated_sacrifice_item_indexes = calculate_absolute_item_indexes(
+        game,
+        inventory_index,
+        &sacrifice_item_indexes,
+        &error_conditions,
+    )?;
+
+    for sacrifice_item_index in calculated_sacrifice_item_indexes {
+        game.inventory[sacrifice_item_index] = None;
+    }
+
+    let inventory_item = game.inventory[inventory_index].as_mut().unwrap_or_else(|| {
+        panic!("Item at index {inventory_index} did exist earlier but does not anymore.")
+    });
+    inventory_item.rarity = new_rarity;
+
+    Ok(ExecuteUpgradeRarityReport {
+        new_item: inventory_item.clone(),
+        paid_cost: cost,
+        new_cost: execute_upgrade_rarity_calculate_cost(game, inventory_index),
+    })
+}
+
+fn get_index_specifier_error_conditions(rarity: Rarity) -> ErrorConditions {
+    let rare_enough_condition = move |sacrifice_item_index: usize, sacrificed_item: &Item| {
+        if sacrificed_item.rarity < rarity {
+            Some(MyError::create_execute_command_error(
+                ErrorCode::SacrificeRarityTooLow,
+                format!(
+                    "sacrifice_item_index {sacrifice_item_index} need to be at least {rarity:?} \
+                     but it was {:?}",
+                    sacrificed_item.rarity
+                ),
+            ))
+        } else {
+            None
+        }
+    };
+    vec![Box::new(rare_enough_condition)]
+}
+
+// The number of items to sacrifice, None when there is no item or it can not be upgraded.
+pub fn execute_upgrade_rarity_calculate_cost(
+    game: &Game,
+    inventory_index: usize,
+) -> Option<usize> {
+    game.inventory[inventory_index]
+        .as_ref()
+        .filter(|item| item.rarity.next().is_some())
+        .map(|item| {
+            let cost = game.rules.costs.upgrade_rarity.cost(item.rarity.tier());
+            usize::try_from(cost).unwrap_or(usize::MAX)
+        })
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests_int {
+    use crate::{
+        command::upgrade_rarity::{execute_upgrade_rarity, execute_upgrade_rarity_calculate_cost},
+        generator::game::new_testing,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        the_world::{index_specifier::IndexSpecifier, rarity::Rarity},
+    };
+
+    #[test]
+    fn test_execute_upgrade_rarity() {
+        let mut game = new_testing(Some([1; 16]));
+        assert_eq!(Some(2), execute_upgrade_rarity_calculate_cost(&game, 0));
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::NotEnoughSacrifices,
+                "upgrade_rarity needs 2 items to be sacrificed but you only provided 1".to_string()
+            )),
+            execute_upgrade_rarity(&mut game, 0, vec![IndexSpecifier::Absolute(1)])
+        );
+
+        let result = execute_upgrade_rarity(
+            &mut game,
+            0,
+            vec![IndexSpecifier::Absolute(1), IndexSpecifier::Absolute(2)],
+        )
+        .unwrap();
+        assert_eq!(Rarity::Magic, result.new_item.rarity);
+        assert_eq!(2, result.paid_cost);
+        assert_eq!(Some(8), result.new_cost);
+        assert_eq!(Rarity::Magic, game.inventory[0].as_ref().unwrap().rarity);
+        assert_eq!(None, game.inventory[1]);
+        assert_eq!(None, game.inventory[2]);
+
+        game.inventory[3].as_mut().unwrap().rarity = Rarity::Rare;
+        game.inventory.push(game.inventory[3].clone());
+        game.inventory.push(game.inventory[3].clone());
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::SacrificeRarityTooLow,
+                "sacrifice_item_index 4 need to be at least Magic but it was Common".to_string()
+            )),
+            execute_upgrade_rarity(
+                &mut game,
+                0,
+                (3..11).map(IndexSpecifier::Absolute).collect()
+            )
+        );
+        assert_eq!(Rarity::Magic, game.inventory[0].as_ref().unwrap().rarity);
+    }
+
+    #[test]
+    fn test_execute_upgrade_rarity_limit() {
+        let mut game = new_testing(Some([1; 16]));
+        game.inventory[0].as_mut().unwrap().rarity = Rarity::Legendary;
+        assert_eq!(None, execute_upgrade_rarity_calculate_cost(&game, 0));
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::RarityLimitReached,
+                "inventory_index 0 is already Legendary, it can not be upgraded any further."
+                    .to_string()
+            )),
+            execute_upgrade_rarity(&mut game, 0, vec![IndexSpecifier::Absolute(1)])
+        );
+
+        game.inventory[0] = None;
+        assert_eq!(None, execute_upgrade_rarity_calculate_cost(&game, 0));
+    }
+}
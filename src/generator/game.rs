@@ -1,7 +1,7 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use rand::{RngCore, SeedableRng};
-use rand_pcg::{Lcg64Xsh32, Pcg32};
+use rand_pcg::Pcg32;
 
 use crate::{
     generator::place::new as new_place,
@@ -9,12 +9,17 @@ use crate::{
     the_world::{
         damage_types::DamageType,
         difficulty::Difficulty,
+        game_history::Generation,
         game_statistics::GameStatistics,
         item::{CraftingInfo, Item},
         item_modifier::Modifier,
         item_resource::Type,
         modifier_cost::Cost,
         modifier_gain::Gain,
+        random_generators::RandomGenerators,
+        rarity::Rarity,
+        rules::Rules,
+        stash::Stash,
     },
     Game,
 };
@@ -22,6 +27,14 @@ use crate::{
 mod tests;
 
 pub fn new(seed: Option<[u8; 16]>) -> Game {
+    new_with_rules(seed, Arc::default())
+}
+
+// The rules are part of the game before the first place is generated, so its reward follows them.
+pub fn new_with_rules(
+    seed: Option<[u8; 16]>,
+    rules: Arc<Rules>,
+) -> Game {
     let mut min_resistance = HashMap::new();
     min_resistance.insert(DamageType::Physical, 1);
 
@@ -45,9 +58,14 @@ pub fn new(seed: Option<[u8; 16]>) -> Game {
             possible_rolls: difficulty.clone(),
             places_count: 1,
         },
+        locked: false,
+        id: 0,
+        name: None,
+        notes: None,
+        rarity: Rarity::Common,
     }];
 
-    let (seed, random_generator) = create_random_generator(seed);
+    let (seed, random_generators) = create_random_generators(seed);
 
     let game_statistics = GameStatistics {
         moves_count: 0,
@@ -64,28 +82,35 @@ pub fn new(seed: Option<[u8; 16]>) -> Game {
         treasure: HashMap::new(),
         item_resources: HashMap::new(),
         inventory: Vec::new(),
+        stash: Stash::default(),
         seed,
-        random_generator_state: random_generator,
+        random_generators,
         statistics: game_statistics,
+        next_item_id: 1,
+        rules,
+        save_root: Arc::default(),
+        generation: Generation::default(),
     };
 
     let new_place = new_place(&mut game);
     game.places.push(new_place);
+    game.assign_missing_item_ids();
 
     game
 }
 
-fn create_random_generator(seed_optional: Option<[u8; 16]>) -> ([u8; 16], Lcg64Xsh32) {
-    let seed = seed_optional.unwrap_or({
-        let mut new_seed: [u8; 16] = [1; 16];
-        Pcg32::from_entropy().fill_bytes(&mut new_seed);
-        new_seed
-    });
+pub fn new_seed() -> [u8; 16] {
+    let mut new_seed: [u8; 16] = [1; 16];
+    Pcg32::from_entropy().fill_bytes(&mut new_seed);
+    new_seed
+}
+
+fn create_random_generators(seed_optional: Option<[u8; 16]>) -> ([u8; 16], RandomGenerators) {
+    let seed = seed_optional.unwrap_or_else(new_seed);
 
     println!("Using seed: {}", encode_hex(&seed));
 
-    let random_generator = Pcg32::from_seed(seed);
-    (seed, random_generator)
+    (seed, RandomGenerators::new(seed))
 }
 
 #[allow(dead_code)]
@@ -132,6 +157,11 @@ pub fn new_testing(seed: Option<[u8; 16]>) -> Game {
             possible_rolls: difficulty.clone(),
             places_count: 10,
         },
+        locked: false,
+        id: 0,
+        name: None,
+        notes: None,
+        rarity: Rarity::Common,
     };
     equipped_items.push(item);
 
@@ -150,6 +180,11 @@ pub fn new_testing(seed: Option<[u8; 16]>) -> Game {
             possible_rolls: difficulty.clone(),
             places_count: 10,
         },
+        locked: false,
+        id: 0,
+        name: None,
+        notes: None,
+        rarity: Rarity::Common,
     };
     equipped_items.push(item);
 
@@ -165,10 +200,15 @@ pub fn new_testing(seed: Option<[u8; 16]>) -> Game {
                 possible_rolls: difficulty.clone(),
                 places_count: 10,
             },
+            locked: false,
+            id: 0,
+            name: None,
+            notes: None,
+            rarity: Rarity::Common,
         }));
     }
 
-    let (seed, random_generator) = create_random_generator(seed);
+    let (seed, random_generators) = create_random_generators(seed);
 
     let game_statistics = GameStatistics {
         moves_count: 0,
@@ -185,15 +225,21 @@ pub fn new_testing(seed: Option<[u8; 16]>) -> Game {
         treasure: HashMap::new(),
         item_resources: HashMap::new(),
         inventory,
+        stash: Stash::default(),
         seed,
-        random_generator_state: random_generator,
+        random_generators,
         statistics: game_statistics,
+        next_item_id: 1,
+        rules: Arc::default(),
+        save_root: Arc::default(),
+        generation: Generation::default(),
     };
 
     for _i in 0..10 {
         let new_place = new_place(&mut game);
         game.places.push(new_place);
     }
+    game.assign_missing_item_ids();
 
     game
 }
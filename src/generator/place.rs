@@ -37,7 +37,8 @@ pub fn new(game: &mut Game) -> Place {
         game.difficulty.max_simultaneous_resistances as usize,
     );
     let resistance_numbers = game
-        .random_generator_state
+        .random_generators
+        .places
         .gen_range(minimum_elements..=maximum_elements);
 
     let mut possible_attack_types: Vec<&DamageType> =
@@ -48,14 +49,15 @@ pub fn new(game: &mut Game) -> Place {
     let mut count_elements = 0;
     while count_elements < resistance_numbers {
         let attack_type = *possible_attack_types
-            .choose(&mut game.random_generator_state)
+            .choose(&mut game.random_generators.places)
             .unwrap();
 
         let min_value = game.difficulty.min_resistance.get(attack_type).unwrap();
         let max_value = game.difficulty.max_resistance.get(attack_type).unwrap();
 
         let resistance_value = game
-            .random_generator_state
+            .random_generators
+            .places
             .gen_range(*min_value..=*max_value);
         resistance.insert(attack_type.clone(), resistance_value);
         resistance_sum += resistance_value;
@@ -74,14 +76,14 @@ pub fn new(game: &mut Game) -> Place {
         .sum::<u64>();
     let average_possible_resistance_values =
         possible_resistance_values_sum / relevant_attack_types.len() as u64;
-    let reward_from_difficulty = max(
-        1,
-        average_possible_resistance_values / max(game.places.len(), 1) as u64,
-    );
+    let reward_from_difficulty =
+        average_possible_resistance_values / max(game.places.len(), 1) as u64;
 
     reward.insert(
         TreasureType::Gold,
-        reward_from_resistance + reward_from_difficulty,
+        game.rules
+            .place_reward
+            .reward(reward_from_resistance, reward_from_difficulty),
     );
 
     Place {
@@ -0,0 +1,30 @@
+//! The rules of My Little RPG as a library, so bots, analysis tools and tests can play the game
+//! directly instead of over TCP.
+//!
+//! A [`Game`] is created from an optional seed and changed by executing [`Command`]s on it. The
+//! results are the same JSON values the server sends, and the state of the game can be read
+//! through the getters on [`Game`].
+//!
+//! ```
+//! use my_little_rpg::{Command, Game};
+//!
+//! let mut game = Game::new(Some([1; 16]));
+//! let result = game.execute(Command::Move(0));
+//!
+//! assert!(result.is_ok());
+//! assert_eq!(1, game.statistics().moves_count());
+//! assert_eq!(1, game.places().len());
+//! ```
+//!
+//! Commands that manage several games or the undo history, like `NewGame` or `Undo`, need a
+//! [`parser::session::Session`] and fail with `COMMAND_NEEDS_SESSION` when executed on a game.
+
+pub mod command;
+pub mod generator;
+pub mod my_little_rpg_errors;
+pub mod parser;
+pub mod the_world;
+
+pub use command::commands::Command;
+pub use my_little_rpg_errors::{ErrorCode, MyError};
+pub use the_world::game::Game;
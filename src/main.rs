@@ -1,16 +1,21 @@
-extern crate core;
+use std::{process, time::Duration};
 
+use serde_json::json;
 use structopt::StructOpt;
 
-use the_world::game::Game;
-
-use crate::parser::{hex_encoder::decode_hex, tcp_listener::Listener};
-
-mod command;
-mod generator;
-mod my_little_rpg_errors;
-mod parser;
-mod the_world;
+use my_little_rpg::{
+    parser::{
+        autosave::Autosave,
+        hex_encoder::decode_seed,
+        journal::{read_journal, Journal},
+        replay::replay,
+        tcp_listener::Listener,
+    },
+    the_world::{
+        rules::Rules,
+        save_root::{SaveRoot, DEFAULT_SAVE_ROOT},
+    },
+};
 
 #[derive(Debug, StructOpt)]
 pub struct Settings {
@@ -24,19 +29,141 @@ pub struct Settings {
 
     #[structopt(short, long, parse(try_from_str = parse_seed), help = "32 hexidecimal representation of the seed. Example: e66832fd2e73fec455149e08b9c08bc1")]
     pub(crate) seed: Option<[u8; 16]>,
+
+    #[structopt(
+        long,
+        default_value = "300",
+        help = "Seconds a session may stay idle before the connection is closed."
+    )]
+    pub(crate) session_timeout: u64,
+
+    #[structopt(
+        long,
+        default_value = "20",
+        help = "How many commands a session can undo, 0 turns undo off."
+    )]
+    pub(crate) history_depth: usize,
+
+    #[structopt(
+        long,
+        help = "Append every executed command and its result to this file, so the session can be \
+                replayed."
+    )]
+    pub(crate) journal: Option<String>,
+
+    #[structopt(
+        long,
+        help = "Replay the journal in this file, verify every result matches and exit instead of \
+                starting the server."
+    )]
+    pub(crate) replay: Option<String>,
+
+    #[structopt(
+        long,
+        help = "Play by the costs, roll weights and rewards in this JSON file instead of the \
+                default rules."
+    )]
+    pub(crate) rules: Option<String>,
+
+    #[structopt(
+        long,
+        default_value = DEFAULT_SAVE_ROOT,
+        help = "The folder save games are kept in. Clients can only save, load and manage save \
+                games inside of it."
+    )]
+    pub(crate) save_root: String,
+
+    #[structopt(
+        long,
+        help = "Sign save games with the key in this file, so save games changed by anyone \
+                without the key fail to load. Without a key the checksum of a save game only \
+                catches corruption, since anybody can calculate it again."
+    )]
+    pub(crate) save_key_file: Option<String>,
+
+    #[structopt(
+        long,
+        default_value = "100",
+        help = "Autosave every game into the .autosave folder of the save root every this many \
+                commands that change a game, and when the server is stopped. 0 only autosaves when the server is \
+                stopped."
+    )]
+    pub(crate) autosave_every: u64,
+
+    #[structopt(
+        long,
+        conflicts_with = "autosave-every",
+        help = "Never autosave, not even when the server is stopped."
+    )]
+    pub(crate) no_autosave: bool,
+
+    #[structopt(
+        long,
+        conflicts_with = "journal",
+        help = "Continue the autosaved games instead of starting a new game. Can not be combined \
+                with a journal, since the journal could not be replayed from the seed."
+    )]
+    pub(crate) resume: bool,
 }
 
 fn parse_seed(src: &str) -> Result<[u8; 16], String> {
-    if src.len() != 32 {
-        return Err("32 Hexidecimals as a string requires 32 chars!".to_string());
-    }
-    match decode_hex(src) {
-        Err(e) => Err(format!("{e:?}")),
-        Ok(r) => Ok(r.try_into().expect("We know the size fits!")), //
-    }
+    decode_seed(src).map_err(|e| format!("{e:?}"))
 }
 
 fn main() {
     let opt = Settings::from_args();
-    Listener::new(opt.port).listen(opt.seed);
+
+    if let Some(replay_path) = opt.replay {
+        match read_journal(&replay_path).and_then(replay) {
+            Ok(report) => {
+                println!("{}", json!(report));
+                if !report.is_success() {
+                    process::exit(1);
+                }
+            }
+            Err(error) => {
+                println!("{}", json!(error));
+                process::exit(1);
+            }
+        }
+        return;
+    }
+
+    let rules = match opt.rules.as_deref().map(Rules::load).transpose() {
+        Ok(rules) => rules.unwrap_or_default(),
+        Err(error) => {
+            println!("{}", json!(error));
+            process::exit(1);
+        }
+    };
+    let save_root = SaveRoot::new(opt.save_root);
+    let save_root = match opt.save_key_file.as_deref() {
+        Some(key_path) => {
+            match save_root.with_signing_key_file(key_path) {
+                Ok(save_root) => save_root,
+                Err(error) => {
+                    println!("{}", json!(error));
+                    process::exit(1);
+                }
+            }
+        }
+        None => save_root,
+    };
+    let journal = match opt.journal.as_deref().map(Journal::open).transpose() {
+        Ok(journal) => journal,
+        Err(error) => {
+            println!("{}", json!(error));
+            process::exit(1);
+        }
+    };
+    Listener::new(
+        opt.port,
+        Duration::from_secs(opt.session_timeout),
+        opt.history_depth,
+        journal,
+        rules,
+        save_root,
+        (!opt.no_autosave).then(|| Autosave::new(opt.autosave_every)),
+    )
+    .listen(opt.seed, opt.resume);
 }
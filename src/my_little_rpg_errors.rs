@@ -1,17 +1,105 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Eq, Hash)]
+#[serde(tag = "kind")]
 pub enum MyError {
-    ParseCommand { error_message: Box<str> },
-    Network { error_message: Box<str> },
-    SaveLoad { error_message: Box<str> },
-    ExecuteCommand { error_message: Box<str> },
+    ParseCommand {
+        code: ErrorCode,
+        error_message: Box<str>,
+    },
+    Network {
+        code: ErrorCode,
+        error_message: Box<str>,
+    },
+    SaveLoad {
+        code: ErrorCode,
+        error_message: Box<str>,
+    },
+    ExecuteCommand {
+        code: ErrorCode,
+        error_message: Box<str>,
+    },
     MoveCommand {
+        code: ErrorCode,
         error_message: Box<str>,
         item_report: Box<str>,
     },
 }
 
+// Stable machine readable codes, so clients do not need to parse the error messages. Existing
+// codes must never be renamed or removed.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    EmptyCommand,
+    UnknownCommand,
+    MissingCommandArguments,
+    InvalidNumber,
+    InvalidIndexSpecifier,
+    InvalidDamageType,
+    InvalidQuery,
+    InvalidSeed,
+    InvalidJsonCommand,
+    InvalidEncoding,
+    CommandTooLong,
+    NetworkFailure,
+    SaveFolderNotCreated,
+    SaveFailed,
+    LoadFailed,
+    InvalidSaveName,
+    InvalidSavePath,
+    SaveNotFound,
+    SaveAlreadyExists,
+    UnsupportedSaveVersion,
+    SaveChecksumMismatch,
+    JournalFailed,
+    InvalidJournal,
+    GameAlreadyExists,
+    InvalidGameName,
+    GameNotFound,
+    GameOwnedByOtherSession,
+    NothingToUndo,
+    NothingToRedo,
+    GameChangedByOtherSession,
+    CommandNotPreviewable,
+    CommandNeedsSession,
+    CommandPanicked,
+    PlaceNotFound,
+    NotEnoughDamage,
+    ItemCostNotFulfilled,
+    CantPayItemResourceCost,
+    CantPayCraftingCost,
+    InventoryIndexOutOfRange,
+    InventorySlotEmpty,
+    InventoryEmpty,
+    ItemLocked,
+    NothingToSalvage,
+    InvalidSortKey,
+    StashFull,
+    StashTabNotFound,
+    StashIndexOutOfRange,
+    ItemNotFound,
+    InvalidItemName,
+    ItemNameTaken,
+    InvalidRules,
+    InvalidRarity,
+    RarityLimitReached,
+    SacrificeRarityTooLow,
+    EquipmentIndexOutOfRange,
+    SameEquipmentIndex,
+    ModifierIndexOutOfRange,
+    ModifierLimitReached,
+    NotEnoughSacrifices,
+    SacrificeIsCraftedItem,
+    SacrificeAlreadySelected,
+    SacrificeNotEnoughModifiers,
+    NoSacrificeFound,
+    NoElementAvailable,
+    MaximumElementsReached,
+    MaximumSimultaneousElementsReached,
+    MinimumSimultaneousElementsReached,
+}
+
 impl From<MyError> for Box<str> {
     fn from(error: MyError) -> Self {
         format!("{error:?}").into()
@@ -19,36 +107,54 @@ impl From<MyError> for Box<str> {
 }
 
 impl MyError {
-    pub fn create_parse_command_error(error_message: String) -> MyError {
+    pub fn create_parse_command_error(
+        code: ErrorCode,
+        error_message: String,
+    ) -> MyError {
         MyError::ParseCommand {
+            code,
             error_message: error_message.into(),
         }
     }
 
-    pub fn create_network_error(error_message: String) -> MyError {
+    pub fn create_network_error(
+        code: ErrorCode,
+        error_message: String,
+    ) -> MyError {
         MyError::Network {
+            code,
             error_message: error_message.into(),
         }
     }
 
-    pub fn create_save_load_error(error_message: String) -> MyError {
+    pub fn create_save_load_error(
+        code: ErrorCode,
+        error_message: String,
+    ) -> MyError {
         MyError::SaveLoad {
+            code,
             error_message: error_message.into(),
         }
     }
 
     pub fn create_move_command_error(
+        code: ErrorCode,
         error_message: String,
         item_report: String,
     ) -> MyError {
         MyError::MoveCommand {
+            code,
             error_message: error_message.into(),
             item_report: item_report.into(),
         }
     }
 
-    pub fn create_execute_command_error(error_message: String) -> MyError {
+    pub fn create_execute_command_error(
+        code: ErrorCode,
+        error_message: String,
+    ) -> MyError {
         MyError::ExecuteCommand {
+            code,
             error_message: error_message.into(),
         }
     }
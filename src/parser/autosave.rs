@@ -0,0 +1,129 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Condvar, Mutex, MutexGuard, PoisonError,
+    },
+    thread,
+};
+
+use crate::{
+    command::{
+        manage_saves::list_saves_in,
+        save_load::{load_save_file, write_save_file},
+    },
+    my_little_rpg_errors::MyError,
+    parser::save_format::SaveFormat,
+    the_world::{
+        game_registry::{lock_game, GameRegistry},
+        rules::Rules,
+        save_root::SaveRoot,
+    },
+    Game,
+};
+
+mod tests;
+
+// Saves every game of the server every so many commands that changed a game, so a crash loses at
+// most that many commands. Saving every 0 commands only saves when the server is stopped. The games
+// are saved on a thread of their own, so the command that reached the count does not wait for it.
+pub struct Autosave {
+    every_commands: u64,
+    commands_count: AtomicU64,
+    // Set when the games should be saved, the autosave thread sleeps until then.
+    save_requested: Mutex<bool>,
+    wake_up: Condvar,
+}
+
+impl Autosave {
+    pub fn new(every_commands: u64) -> Self {
+        Self {
+            every_commands,
+            commands_count: AtomicU64::new(0),
+            save_requested: Mutex::new(false),
+            wake_up: Condvar::new(),
+        }
+    }
+
+    // Starts the autosave thread, which runs until the server exits.
+    pub fn start(
+        self: &Arc<Self>,
+        game_registry: GameRegistry,
+    ) {
+        if self.every_commands == 0 {
+            return;
+        }
+        let autosave = self.clone();
+        thread::spawn(move || {
+            loop {
+                autosave.wait_for_save_request();
+                autosave_games(&game_registry);
+            }
+        });
+    }
+
+    // Called after every command that can change a game, commands that only read do not need
+    // saving.
+    pub fn record_command(&self) {
+        if self.every_commands == 0 {
+            return;
+        }
+        let commands_count = self.commands_count.fetch_add(1, Ordering::Relaxed) + 1;
+        if commands_count.is_multiple_of(self.every_commands) {
+            *self.lock_save_requested() = true;
+            self.wake_up.notify_one();
+        }
+    }
+
+    // Requests made while the games were being saved are combined into the next save.
+    fn wait_for_save_request(&self) {
+        let mut save_requested = self
+            .wake_up
+            .wait_while(self.lock_save_requested(), |save_requested| {
+                !*save_requested
+            })
+            .unwrap_or_else(PoisonError::into_inner);
+        *save_requested = false;
+    }
+
+    fn lock_save_requested(&self) -> MutexGuard<'_, bool> {
+        self.save_requested
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+// Every game is saved while it is locked, so never in the middle of a command. A game that can not
+// be saved does not keep the others from being saved.
+pub fn autosave_games(game_registry: &GameRegistry) -> Vec<MyError> {
+    let mut errors = Vec::new();
+    for (name, game) in game_registry.list() {
+        let game = lock_game(&game);
+        let result = game
+            .save_root
+            .autosave_file(&name, SaveFormat::Json)
+            .and_then(|file_path| write_save_file(&game, &file_path, SaveFormat::Json));
+        if let Err(error) = result {
+            println!("Failed autosaving the game {name}: {error:?}");
+            errors.push(error);
+        }
+    }
+    errors
+}
+
+// The autosaved games, played by the rules of the server. There are none when nothing was
+// autosaved yet.
+pub fn load_autosaved_games(
+    save_root: &Arc<SaveRoot>,
+    rules: &Arc<Rules>,
+) -> Result<Vec<(Box<str>, Game)>, MyError> {
+    list_saves_in(&save_root.autosave_folder())?
+        .saves()
+        .iter()
+        .map(|save| {
+            let file_path = save_root.autosave_file(save.name(), save.format())?;
+            let mut game = load_save_file(save_root, &file_path)?;
+            game.rules = rules.clone();
+            Ok((Box::from(save.name()), game))
+        })
+        .collect()
+}
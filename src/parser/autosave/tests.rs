@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests_int {
+    use std::{fs, sync::Arc, thread, time::Duration};
+
+    use crate::{
+        command::{
+            manage_saves::{execute_delete_save, execute_list_saves},
+            save_load::execute_save_command,
+        },
+        generator::game::new_testing,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        parser::{
+            autosave::{autosave_games, load_autosaved_games, Autosave},
+            save_format::SaveFormat,
+        },
+        the_world::{
+            game_registry::{lock_game, GameRegistry},
+            rules::Rules,
+            save_root::{SaveRoot, AUTOSAVE_FOLDER},
+        },
+    };
+
+    fn new_game_registry(save_root: &str) -> GameRegistry {
+        let mut game = new_testing(Some([1; 16]));
+        game.set_save_root(SaveRoot::new(save_root));
+        GameRegistry::new(game)
+    }
+
+    // The games are saved by the autosave thread, so the test waits for it.
+    fn wait_for_file(path: &str) -> bool {
+        (0..500).any(|_| {
+            thread::sleep(Duration::from_millis(10));
+            fs::exists(path).unwrap()
+        })
+    }
+
+    #[test]
+    fn autosave_every_commands() {
+        let game_registry = new_game_registry("./testing_autosave_every/");
+        let autosave = Arc::new(Autosave::new(2));
+        autosave.start(game_registry.clone());
+
+        autosave.record_command();
+        thread::sleep(Duration::from_millis(100));
+        assert!(!fs::exists("./testing_autosave_every/.autosave/default.json").unwrap());
+        autosave.record_command();
+        assert!(wait_for_file(
+            "./testing_autosave_every/.autosave/default.json"
+        ));
+
+        fs::remove_dir_all("./testing_autosave_every/")
+            .expect("Had trouble cleanup after autosave every commands");
+    }
+
+    #[test]
+    fn autosave_and_resume() {
+        let game_registry = new_game_registry("./testing_autosave_resume/");
+        let save_root = game_registry.save_root();
+        let mut second_game = new_testing(Some([2; 16]));
+        second_game.save_root = save_root.clone();
+        game_registry.insert("second", second_game, None).unwrap();
+
+        assert!(autosave_games(&game_registry).is_empty());
+
+        let games = load_autosaved_games(&save_root, &Arc::new(Rules::default())).unwrap();
+        assert_eq!(
+            vec!["default", "second"],
+            games.iter().map(|(name, _)| &**name).collect::<Vec<_>>()
+        );
+        for (name, game) in games {
+            assert_eq!(*lock_game(&game_registry.get(&name).unwrap()), game);
+        }
+
+        fs::remove_dir_all("./testing_autosave_resume/")
+            .expect("Had trouble cleanup after autosave and resume");
+    }
+
+    #[test]
+    fn autosaves_are_out_of_reach_of_clients() {
+        let game_registry = new_game_registry("./testing_autosave_reach/");
+        let save_root = game_registry.save_root();
+        assert!(autosave_games(&game_registry).is_empty());
+        let game = lock_game(&game_registry.select_default().game).clone();
+
+        assert!(execute_list_saves(&save_root, None)
+            .unwrap()
+            .saves()
+            .is_empty());
+        for result in [
+            execute_list_saves(&save_root, Some(AUTOSAVE_FOLDER.into())).map(|_| ()),
+            execute_delete_save(&save_root, "default", Some(AUTOSAVE_FOLDER.into())).map(|_| ()),
+            execute_save_command(
+                &game,
+                "default",
+                Some(AUTOSAVE_FOLDER.into()),
+                SaveFormat::Json,
+            )
+            .map(|_| ()),
+        ] {
+            assert!(matches!(
+                result,
+                Err(MyError::SaveLoad {
+                    code: ErrorCode::InvalidSavePath,
+                    ..
+                })
+            ));
+        }
+        assert_eq!(
+            1,
+            load_autosaved_games(&save_root, &Arc::new(Rules::default()))
+                .unwrap()
+                .len()
+        );
+
+        fs::remove_dir_all("./testing_autosave_reach/")
+            .expect("Had trouble cleanup after autosaves out of reach");
+    }
+
+    #[test]
+    fn resume_without_autosaves() {
+        let save_root = Arc::new(SaveRoot::new("./testing_autosave_nothing/"));
+
+        assert!(
+            load_autosaved_games(&save_root, &Arc::new(Rules::default()))
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -1,4 +1,11 @@
-use crate::{my_little_rpg_errors::MyError, the_world::index_specifier::IndexSpecifier};
+use crate::{
+    my_little_rpg_errors::{ErrorCode, MyError},
+    parser::item_query_parser::try_parse_item_query,
+    the_world::{
+        damage_types::DamageType, index_specifier::IndexSpecifier, item_reference::ItemReference,
+        rarity::Rarity,
+    },
+};
 
 mod tests;
 
@@ -7,13 +14,94 @@ pub fn try_parse_usize(string_to_parse: &str) -> Result<usize, MyError> {
         let error_message = format!(
             "The following parameter {string_to_parse}, got the following error while parsing: {error:?}"
         );
-        MyError::create_parse_command_error(error_message)
+        MyError::create_parse_command_error(ErrorCode::InvalidNumber, error_message)
     })
 }
 
+pub fn try_parse_damage_type(string_to_parse: &str) -> Result<DamageType, MyError> {
+    DamageType::get_all()
+        .into_iter()
+        .find(|damage_type| format!("{damage_type:?}") == string_to_parse)
+        .ok_or_else(|| {
+            let error_message = format!(
+                "The following parameter {string_to_parse}, is not a damage type. The damage types \
+                 are {:?}",
+                DamageType::get_all()
+            );
+            MyError::create_parse_command_error(ErrorCode::InvalidDamageType, error_message)
+        })
+}
+
+pub fn try_parse_rarity(string_to_parse: &str) -> Result<Rarity, MyError> {
+    Rarity::get_all()
+        .into_iter()
+        .find(|rarity| format!("{rarity:?}") == string_to_parse)
+        .ok_or_else(|| {
+            let error_message = format!(
+                "The following parameter {string_to_parse}, is not a rarity. The rarities are {:?}",
+                Rarity::get_all()
+            );
+            MyError::create_parse_command_error(ErrorCode::InvalidRarity, error_message)
+        })
+}
+
+// Words can not be item names, so they are never mistaken for one in a command.
+const RESERVED_WORDS: [&str; 4] = ["where", "and", "or", "not"];
+
+// An item is referenced by its index, by its id like #12 or by its name, which starts with a letter.
+pub fn try_parse_item_reference(string_to_parse: &str) -> Result<ItemReference, MyError> {
+    if let Some(id) = string_to_parse.strip_prefix('#') {
+        try_parse_usize(id).map(|id| ItemReference::Id(u64::try_from(id).unwrap_or(u64::MAX)))
+    } else if is_item_name(string_to_parse) {
+        Ok(ItemReference::Name(string_to_parse.into()))
+    } else {
+        try_parse_usize(string_to_parse).map(ItemReference::Index)
+    }
+}
+
+pub fn is_item_name(string_to_parse: &str) -> bool {
+    string_to_parse
+        .chars()
+        .next()
+        .is_some_and(char::is_alphabetic)
+        && !string_to_parse.contains([',', '#'])
+        && !string_to_parse.contains(char::is_whitespace)
+        && !RESERVED_WORDS.contains(&string_to_parse)
+}
+
+// The sacrifices are a comma separated list of indexes, where followed by an item query selecting
+// them by their modifiers, or both like: 3,+1 where cost:none
+// Relative indexes are relative to the crafted item, so they are only checked when it is given by
+// index.
+pub fn try_parse_sacrifice_indexes(
+    command_parts: &[&str],
+    crafted_item: &ItemReference,
+) -> Result<Vec<IndexSpecifier>, MyError> {
+    let relative_too = match crafted_item {
+        ItemReference::Index(index) => Some(*index),
+        _ => None,
+    };
+    let (indexes, query) = match command_parts {
+        ["where", query @ ..] => (None, Some(query)),
+        [indexes, "where", query @ ..] => (Some(indexes), Some(query)),
+        [indexes, ..] => (Some(indexes), None),
+        [] => (None, None),
+    };
+    let mut index_specifiers = match indexes {
+        Some(indexes) => try_parse_possible_relative_indexes(indexes, relative_too)?,
+        None => Vec::new(),
+    };
+    if let Some(query) = query {
+        index_specifiers.push(IndexSpecifier::Query(try_parse_item_query(
+            &query.join(" "),
+        )?));
+    }
+    Ok(index_specifiers)
+}
+
 pub fn try_parse_possible_relative_indexes(
     command_parts: &str,
-    relative_too: usize,
+    relative_too: Option<usize>,
 ) -> Result<Vec<IndexSpecifier>, MyError> {
     command_parts.split(',').map(|index_specifier| {
         match index_specifier.chars().next() {
@@ -33,23 +121,35 @@ pub fn try_parse_possible_relative_indexes(
                     "underflow",
                 ).map(IndexSpecifier::RelativeNegative)
             }
-            _ => try_parse_usize(index_specifier).map(IndexSpecifier::Absolute),
+            _ => {
+                try_parse_item_reference(index_specifier).map(|item_reference| {
+                    match item_reference {
+                        ItemReference::Index(index) => IndexSpecifier::Absolute(index),
+                        ItemReference::Id(id) => IndexSpecifier::Id(id),
+                        ItemReference::Name(name) => IndexSpecifier::Name(name),
+                    }
+                })
+            }
         }
     }).collect()
 }
 
 fn try_parse_possible_relative_indexes_get_absolute(
-    relative_too: usize,
+    relative_too: Option<usize>,
     s: &&str,
     operation: fn(usize, usize) -> Option<usize>,
     flow_type: &str,
 ) -> Result<usize, MyError> {
     try_parse_usize(&s[1..s.len()]).map(|relative_index_diff| {
+        let Some(relative_too) = relative_too else {
+            return Ok(relative_index_diff);
+        };
         operation(relative_too, relative_index_diff).map_or_else(
             || {
-                Err(MyError::create_parse_command_error(format!(
-                    "{relative_too}{s} created an {flow_type}!"
-                )))
+                Err(MyError::create_parse_command_error(
+                    ErrorCode::InvalidIndexSpecifier,
+                    format!("{relative_too}{s} created an {flow_type}!"),
+                ))
             },
             |_| Ok(relative_index_diff),
         )
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests_int {
     use crate::{
-        my_little_rpg_errors::MyError,
+        my_little_rpg_errors::{ErrorCode, MyError},
         parser::basetype_parser::try_parse_usize,
     };
     use crate::parser::basetype_parser::try_parse_possible_relative_indexes;
@@ -16,6 +16,7 @@ mod tests_int {
     fn test_try_parse_usize_error_negative_number() {
         assert_eq!(
             MyError::ParseCommand {
+                code: ErrorCode::InvalidNumber,
                 error_message: Box::from(
                     "The following parameter -100, got the following error while parsing: \
                          ParseIntError { kind: InvalidDigit }"
@@ -29,6 +30,7 @@ mod tests_int {
     fn test_try_parse_usize_error_not_a_number() {
         assert_eq!(
             MyError::ParseCommand {
+                code: ErrorCode::InvalidNumber,
                 error_message: Box::from(
                     "The following parameter abd, got the following error while parsing: ParseIntError { kind: InvalidDigit }"
                 ),
@@ -45,6 +47,6 @@ mod tests_int {
                 IndexSpecifier::RelativePositive(1),
                 IndexSpecifier::RelativeNegative(1),
             ],
-            try_parse_possible_relative_indexes("100,+1,-1", 2).unwrap());
+            try_parse_possible_relative_indexes("100,+1,-1", Some(2)).unwrap());
     }
 }
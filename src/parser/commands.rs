@@ -1,14 +1,30 @@
+pub use crate::command::commands::Command;
 use crate::{
     command::commands::Command::{
-        AddModifier, Equip, ExpandElements, ExpandEquipmentSlots, ExpandMaxElement,
-        ExpandMaxSimultaneousElement, ExpandMinElement, ExpandMinSimultaneousElement, ExpandPlaces,
-        Help, LoadTheWorld, Move, ReduceDifficulty, ReorderInventory, RerollModifier, SaveTheWorld,
-        State, SwapEquipment,
+        AddModifier, DeleteSave, Equip, Equipment, ExpandElements, ExpandEquipmentSlots,
+        ExpandMaxElement, ExpandMaxSimultaneousElement, ExpandMinElement,
+        ExpandMinSimultaneousElement, ExpandPlaces, ExpandStash, Help, Inventory, ListGames,
+        ListSaves, LoadTheWorld, Lock, Move, NameItem, NewGame, NoteItem, OptimizeLoadout, Places,
+        Preview, Redo, ReduceDifficulty, RenameSave, ReorderInventory, RerollModifier, Salvage,
+        SaveInfo, SaveTheWorld, SelectGame, Simulate, SortInventory, StashItem, State,
+        SwapEquipment, Undo, Unlock, UnstashItem, UpgradeRarity,
+    },
+    my_little_rpg_errors::{ErrorCode, MyError},
+    parser::{
+        basetype_parser::{
+            is_item_name, try_parse_damage_type, try_parse_item_reference,
+            try_parse_sacrifice_indexes, try_parse_usize,
+        },
+        hex_encoder::decode_seed,
+        item_query_parser::try_parse_item_query,
+        save_format::SaveFormat,
+    },
+    the_world::{
+        inventory_sort::{InventorySortKey, SortOrder},
+        item_reference::ItemReference,
+        state_query::{ItemFilter, PlaceFilter, StateQuery},
     },
-    my_little_rpg_errors::MyError,
 };
-pub use crate::command::commands::Command;
-use crate::parser::basetype_parser::{try_parse_possible_relative_indexes, try_parse_usize};
 
 mod tests;
 
@@ -17,9 +33,9 @@ impl Command {
         vec![
             State,
             Move(0),
-            Equip(0, 0),
-            SwapEquipment(0, 0),
-            RerollModifier(0, 0, Vec::new()),
+            Equip(ItemReference::Index(0), ItemReference::Index(0)),
+            SwapEquipment(ItemReference::Index(0), ItemReference::Index(0)),
+            RerollModifier(ItemReference::Index(0), 0, Vec::new()),
             ExpandPlaces,
             ExpandElements,
             ExpandMaxElement,
@@ -28,21 +44,118 @@ impl Command {
             ExpandMinSimultaneousElement,
             ExpandEquipmentSlots,
             ReduceDifficulty,
-            AddModifier(0, Vec::new()),
+            AddModifier(ItemReference::Index(0), Vec::new()),
             Help,
             ReorderInventory,
-            SaveTheWorld("String".into(), None),
+            SaveTheWorld("String".into(), None, SaveFormat::Json),
             LoadTheWorld("String".into(), None),
+            NewGame("String".into(), None),
+            SelectGame("String".into()),
+            ListGames,
+            Undo,
+            Redo,
+            Preview(Box::new(State)),
+            Simulate(None),
+            OptimizeLoadout(None, false),
+            Places(StateQuery::all()),
+            Equipment(StateQuery::all()),
+            Inventory(StateQuery::all()),
+            Salvage(Vec::new(), None),
+            Lock(ItemReference::Index(0)),
+            Unlock(ItemReference::Index(0)),
+            SortInventory(InventorySortKey::Modifiers, SortOrder::Ascending, false),
+            StashItem(ItemReference::Index(0), "String".into()),
+            UnstashItem("String".into(), ItemReference::Index(0)),
+            ExpandStash,
+            NameItem(ItemReference::Index(0), None),
+            NoteItem(ItemReference::Index(0), None),
+            UpgradeRarity(ItemReference::Index(0), Vec::new()),
+            ListSaves(None),
+            DeleteSave("String".into(), None),
+            RenameSave("String".into(), "String".into(), None),
+            SaveInfo("String".into(), None),
         ]
     }
 
+    pub fn name(&self) -> &'static str {
+        match self {
+            State => "State",
+            Move(_) => "Move",
+            Equip(_, _) => "Equip",
+            SwapEquipment(_, _) => "SwapEquipment",
+            RerollModifier(_, _, _) => "RerollModifier",
+            ExpandPlaces => "ExpandPlaces",
+            ExpandElements => "ExpandElements",
+            ExpandMaxElement => "ExpandMaxElement",
+            ExpandMinElement => "ExpandMinElement",
+            ExpandMaxSimultaneousElement => "ExpandMaxSimultaneousElement",
+            ExpandMinSimultaneousElement => "ExpandMinSimultaneousElement",
+            ExpandEquipmentSlots => "ExpandEquipmentSlots",
+            ReduceDifficulty => "ReduceDifficulty",
+            AddModifier(_, _) => "AddModifier",
+            Help => "Help",
+            ReorderInventory => "ReorderInventory",
+            SaveTheWorld(_, _, _) => "SaveTheWorld",
+            LoadTheWorld(_, _) => "LoadTheWorld",
+            NewGame(_, _) => "NewGame",
+            SelectGame(_) => "SelectGame",
+            ListGames => "ListGames",
+            Undo => "Undo",
+            Redo => "Redo",
+            Preview(_) => "Preview",
+            Simulate(_) => "Simulate",
+            OptimizeLoadout(_, _) => "OptimizeLoadout",
+            Places(_) => "Places",
+            Equipment(_) => "Equipment",
+            Inventory(_) => "Inventory",
+            Salvage(_, _) => "Salvage",
+            Lock(_) => "Lock",
+            Unlock(_) => "Unlock",
+            SortInventory(_, _, _) => "SortInventory",
+            StashItem(_, _) => "StashItem",
+            UnstashItem(_, _) => "UnstashItem",
+            ExpandStash => "ExpandStash",
+            NameItem(_, _) => "NameItem",
+            NoteItem(_, _) => "NoteItem",
+            UpgradeRarity(_, _) => "UpgradeRarity",
+            ListSaves(_) => "ListSaves",
+            DeleteSave(_, _) => "DeleteSave",
+            RenameSave(_, _, _) => "RenameSave",
+            SaveInfo(_, _) => "SaveInfo",
+        }
+    }
+
+    // Commands that never change the game, so there is nothing to undo after them.
+    pub fn is_read_only(&self) -> bool {
+        matches!(
+            self,
+            State
+                | Help
+                | Preview(_)
+                | Simulate(_)
+                | OptimizeLoadout(_, false)
+                | Places(_)
+                | Equipment(_)
+                | Inventory(_)
+                | ListGames
+                | SaveTheWorld(_, _, _)
+                | ListSaves(_)
+                | DeleteSave(_, _)
+                | RenameSave(_, _, _)
+                | SaveInfo(_, _)
+        )
+    }
+
     fn try_parse_move(command_parts: &Vec<&str>) -> Result<Command, MyError> {
         if command_parts.len() < 2 {
             let error_message = format!(
                 "Trouble parsing move command, it needs the index of the place. Got \
                  {command_parts:?}"
             );
-            Err(MyError::create_parse_command_error(error_message))
+            Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ))
         } else {
             try_parse_usize(command_parts[1]).map(Move)
         }
@@ -54,29 +167,52 @@ impl Command {
                 "Trouble parsing AddModifier command, it needs the index of the item and a list \
                  comma seperated list of items to sacrifice. Got {command_parts:?}"
             );
-            Err(MyError::create_parse_command_error(error_message))
+            Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ))
         } else {
-            let inventory_position = try_parse_usize(command_parts[1])?;
-            try_parse_possible_relative_indexes(command_parts[2], inventory_position).map(
+            let inventory_item = try_parse_item_reference(command_parts[1])?;
+            try_parse_sacrifice_indexes(&command_parts[2..], &inventory_item).map(
                 |parsed_sacrifice_item_indexes| {
-                    AddModifier(inventory_position, parsed_sacrifice_item_indexes)
+                    AddModifier(inventory_item, parsed_sacrifice_item_indexes)
                 },
             )
         }
     }
 
+    fn try_parse_upgrade_rarity(command_parts: &[&str]) -> Result<Command, MyError> {
+        if command_parts.len() < 2 {
+            let error_message = format!(
+                "Trouble parsing UpgradeRarity command, it needs the index of the item and a \
+                 comma seperated list of items to sacrifice. Got {command_parts:?}"
+            );
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
+        }
+
+        let inventory_item = try_parse_item_reference(command_parts[1])?;
+        try_parse_sacrifice_indexes(&command_parts[2..], &inventory_item)
+            .map(|sacrifice_item_indexes| UpgradeRarity(inventory_item, sacrifice_item_indexes))
+    }
+
     fn try_parse_equip(command_parts: &Vec<&str>) -> Result<Command, MyError> {
         if command_parts.len() < 3 {
             let error_message = format!(
                 "Trouble parsing Equip command, it needs index of inventory and index of \
                  equipment slot. Got {command_parts:?}"
             );
-            return Err(MyError::create_parse_command_error(error_message));
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
         }
 
-        let inventory_position = try_parse_usize(command_parts[1])?;
-        let equipped_item_position = try_parse_usize(command_parts[2])?;
-        Ok(Equip(inventory_position, equipped_item_position))
+        let inventory_item = try_parse_item_reference(command_parts[1])?;
+        let equipped_item = try_parse_item_reference(command_parts[2])?;
+        Ok(Equip(inventory_item, equipped_item))
     }
 
     fn try_parse_swap_equipment(command_parts: &Vec<&str>) -> Result<Command, MyError> {
@@ -85,31 +221,36 @@ impl Command {
                 "Trouble parsing SwapEquipment command, it needs index of inventory and index of \
                  equipment slot. Got {command_parts:?}",
             );
-            return Err(MyError::create_parse_command_error(error_message));
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
         }
 
-        let equipped_item_position_1 = try_parse_usize(command_parts[1])?;
-        let equipped_item_position_2 = try_parse_usize(command_parts[2])?;
-        Ok(SwapEquipment(
-            equipped_item_position_1,
-            equipped_item_position_2,
-        ))
+        let equipped_item_1 = try_parse_item_reference(command_parts[1])?;
+        let equipped_item_2 = try_parse_item_reference(command_parts[2])?;
+        Ok(SwapEquipment(equipped_item_1, equipped_item_2))
     }
 
     fn try_parse_reroll_modifier(command_parts: &Vec<&str>) -> Result<Command, MyError> {
         if command_parts.len() < 4 {
             let error_message = format!(
                 "Trouble parsing RerollModifier command, it needs index of inventory, index of \
-                 modifier and a list comma seperated list of items to sacrifice. Got {command_parts:?}"
+                 modifier and a list comma seperated list of items to sacrifice. Got \
+                 {command_parts:?}"
             );
-            return Err(MyError::create_parse_command_error(error_message));
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
         }
 
-        let inventory_index = try_parse_usize(command_parts[1])?;
+        let inventory_item = try_parse_item_reference(command_parts[1])?;
         let modifier_index = try_parse_usize(command_parts[2])?;
-        let parsed_sacrifice_item_indexes = try_parse_possible_relative_indexes(command_parts[3], inventory_index)?;
+        let parsed_sacrifice_item_indexes =
+            try_parse_sacrifice_indexes(&command_parts[3..], &inventory_item)?;
         Ok(RerollModifier(
-            inventory_index,
+            inventory_item,
             modifier_index,
             parsed_sacrifice_item_indexes,
         ))
@@ -119,29 +260,40 @@ impl Command {
         if command_parts.len() < 2 {
             let error_message = format!(
                 "Trouble parsing SaveTheWorld command, it needs a save game name and optionally a \
-                 path to the savegame (remember to end the path with /). Default location is \
-                 ./save_games/. Got {command_parts:?}"
+                 folder for the savegame inside the save games folder of the server. Got \
+                 {command_parts:?}"
             );
-            return Err(MyError::create_parse_command_error(error_message));
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
         }
 
+        // binary at the end selects the format, unless it is the name of the save game.
+        let (format, command_parts) = match command_parts.split_last() {
+            Some((&"binary", rest)) if rest.len() >= 2 => (SaveFormat::Binary, rest),
+            _ => (SaveFormat::Json, &command_parts[..]),
+        };
         let save_game_name = Box::from(command_parts[1]);
         let save_game_path = if command_parts.len() < 3 {
             None
         } else {
             Some(Box::from(command_parts[2]))
         };
-        Ok(SaveTheWorld(save_game_name, save_game_path))
+        Ok(SaveTheWorld(save_game_name, save_game_path, format))
     }
 
     fn try_parse_load_the_world(command_parts: &Vec<&str>) -> Result<Command, MyError> {
         if command_parts.len() < 2 {
             let error_message = format!(
                 "Trouble parsing LoadTheWorld command, it needs a save game name and optionally a \
-                 path to the savegame (remember to end the path with /). Default location is \
-                 ./save_games/. Got {command_parts:?}"
+                 folder for the savegame inside the save games folder of the server. Got \
+                 {command_parts:?}"
             );
-            return Err(MyError::create_parse_command_error(error_message));
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
         }
 
         let save_game_name = Box::from(command_parts[1]);
@@ -152,6 +304,434 @@ impl Command {
         };
         Ok(LoadTheWorld(save_game_name, save_game_path))
     }
+
+    fn try_parse_list_saves(command_parts: &[&str]) -> Result<Command, MyError> {
+        let save_game_path = command_parts
+            .get(1)
+            .map(|save_game_path| Box::from(*save_game_path));
+        Ok(ListSaves(save_game_path))
+    }
+
+    fn try_parse_delete_save(command_parts: &[&str]) -> Result<Command, MyError> {
+        if command_parts.len() < 2 {
+            let error_message = format!(
+                "Trouble parsing DeleteSave command, it needs a save game name and optionally the \
+                 folder of the savegame. Got {command_parts:?}"
+            );
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
+        }
+
+        let save_game_path = command_parts
+            .get(2)
+            .map(|save_game_path| Box::from(*save_game_path));
+        Ok(DeleteSave(command_parts[1].into(), save_game_path))
+    }
+
+    fn try_parse_rename_save(command_parts: &[&str]) -> Result<Command, MyError> {
+        if command_parts.len() < 3 {
+            let error_message = format!(
+                "Trouble parsing RenameSave command, it needs the current and the new save game \
+                 name and optionally the folder of the savegame. Got {command_parts:?}"
+            );
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
+        }
+
+        let save_game_path = command_parts
+            .get(3)
+            .map(|save_game_path| Box::from(*save_game_path));
+        Ok(RenameSave(
+            command_parts[1].into(),
+            command_parts[2].into(),
+            save_game_path,
+        ))
+    }
+
+    fn try_parse_save_info(command_parts: &[&str]) -> Result<Command, MyError> {
+        if command_parts.len() < 2 {
+            let error_message = format!(
+                "Trouble parsing SaveInfo command, it needs a save game name and optionally the \
+                 folder of the savegame. Got {command_parts:?}"
+            );
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
+        }
+
+        let save_game_path = command_parts
+            .get(2)
+            .map(|save_game_path| Box::from(*save_game_path));
+        Ok(SaveInfo(command_parts[1].into(), save_game_path))
+    }
+
+    fn try_parse_new_game(command_parts: &Vec<&str>) -> Result<Command, MyError> {
+        if command_parts.len() < 2 {
+            let error_message = format!(
+                "Trouble parsing NewGame command, it needs a game name and optionally a seed of \
+                 32 hexidecimals. Got {command_parts:?}"
+            );
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
+        }
+
+        let game_name = Box::from(command_parts[1]);
+        let seed = if command_parts.len() < 3 {
+            None
+        } else {
+            Some(decode_seed(command_parts[2])?)
+        };
+        Ok(NewGame(game_name, seed))
+    }
+
+    fn try_parse_select_game(command_parts: &Vec<&str>) -> Result<Command, MyError> {
+        if command_parts.len() < 2 {
+            let error_message = format!(
+                "Trouble parsing SelectGame command, it needs a game name. Got {command_parts:?}"
+            );
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
+        }
+
+        Ok(SelectGame(Box::from(command_parts[1])))
+    }
+
+    fn try_parse_preview(command_parts: &Vec<&str>) -> Result<Command, MyError> {
+        if command_parts.len() < 2 {
+            let error_message = format!(
+                "Trouble parsing Preview command, it needs the command to preview. Got \
+                 {command_parts:?}"
+            );
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
+        }
+
+        Command::try_from(Box::from(command_parts[1..].join(" ")))
+            .map(|command| Preview(Box::new(command)))
+    }
+
+    fn try_parse_simulate(command_parts: &[&str]) -> Result<Command, MyError> {
+        if command_parts.len() < 2 {
+            return Ok(Simulate(None));
+        }
+
+        try_parse_usize(command_parts[1]).map(|place_index| Simulate(Some(place_index)))
+    }
+
+    fn try_parse_optimize_loadout(command_parts: &[&str]) -> Result<Command, MyError> {
+        if command_parts.len() < 2 {
+            let error_message = format!(
+                "Trouble parsing OptimizeLoadout command, it needs the index of a place or all, \
+                 optionally followed by apply. Got {command_parts:?}"
+            );
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
+        }
+
+        let place_index = match command_parts[1] {
+            "all" => None,
+            place_index => Some(try_parse_usize(place_index)?),
+        };
+        let apply = command_parts.get(2) == Some(&"apply");
+        Ok(OptimizeLoadout(place_index, apply))
+    }
+
+    fn try_parse_lock(command_parts: &[&str]) -> Result<Command, MyError> {
+        if command_parts.len() < 2 {
+            let error_message = format!(
+                "Trouble parsing {} command, it needs the index of an item in the inventory. Got \
+                 {command_parts:?}",
+                command_parts[0]
+            );
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
+        }
+
+        let inventory_item = try_parse_item_reference(command_parts[1])?;
+        match command_parts[0] {
+            "Lock" => Ok(Lock(inventory_item)),
+            _ => Ok(Unlock(inventory_item)),
+        }
+    }
+
+    fn try_parse_stash_item(command_parts: &[&str]) -> Result<Command, MyError> {
+        if command_parts.len() < 3 {
+            let error_message = format!(
+                "Trouble parsing StashItem command, it needs the index of an item in the \
+                 inventory and the name of a stash tab. Got {command_parts:?}"
+            );
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
+        }
+
+        let inventory_item = try_parse_item_reference(command_parts[1])?;
+        Ok(StashItem(inventory_item, Box::from(command_parts[2])))
+    }
+
+    fn try_parse_unstash_item(command_parts: &[&str]) -> Result<Command, MyError> {
+        if command_parts.len() < 3 {
+            let error_message = format!(
+                "Trouble parsing UnstashItem command, it needs the name of a stash tab and the \
+                 index of an item in that tab. Got {command_parts:?}"
+            );
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
+        }
+
+        let stash_item = try_parse_item_reference(command_parts[2])?;
+        Ok(UnstashItem(Box::from(command_parts[1]), stash_item))
+    }
+
+    fn try_parse_name_item(command_parts: &[&str]) -> Result<Command, MyError> {
+        if command_parts.len() < 2 {
+            let error_message = format!(
+                "Trouble parsing NameItem command, it needs the index of an item in the inventory \
+                 and optionally a name. Got {command_parts:?}"
+            );
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
+        }
+
+        let inventory_item = try_parse_item_reference(command_parts[1])?;
+        let name = match command_parts.get(2) {
+            None => None,
+            Some(name) if command_parts.len() == 3 && is_item_name(name) => Some(Box::from(*name)),
+            Some(_) => {
+                let error_message = format!(
+                    "An item name is one word that starts with a letter, without , or #, and is \
+                     not where, and, or or not. Got {command_parts:?}"
+                );
+                return Err(MyError::create_parse_command_error(
+                    ErrorCode::InvalidItemName,
+                    error_message,
+                ));
+            }
+        };
+        Ok(NameItem(inventory_item, name))
+    }
+
+    // The notes are the rest of the command, without notes they are removed.
+    fn try_parse_note_item(command_parts: &[&str]) -> Result<Command, MyError> {
+        if command_parts.len() < 2 {
+            let error_message = format!(
+                "Trouble parsing NoteItem command, it needs the index of an item in the inventory \
+                 and optionally the notes. Got {command_parts:?}"
+            );
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
+        }
+
+        let inventory_item = try_parse_item_reference(command_parts[1])?;
+        let notes = Some(command_parts[2..].join(" "))
+            .filter(|notes| !notes.is_empty())
+            .map(Box::from);
+        Ok(NoteItem(inventory_item, notes))
+    }
+
+    // The sort key is followed by an optional ascending or descending and an optional empty_last,
+    // like: SortInventory flat_damage:Fire descending empty_last
+    fn try_parse_sort_inventory(command_parts: &[&str]) -> Result<Command, MyError> {
+        if command_parts.len() < 2 {
+            let error_message = format!(
+                "Trouble parsing SortInventory command, it needs a sort key, one of modifiers, \
+                 flat_damage, flat_damage:DamageType, cost_burden or places_count. Got \
+                 {command_parts:?}"
+            );
+            return Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                error_message,
+            ));
+        }
+
+        let sort_key = match command_parts[1] {
+            "modifiers" => InventorySortKey::Modifiers,
+            "flat_damage" => InventorySortKey::FlatDamage(None),
+            "cost_burden" => InventorySortKey::CostBurden,
+            "places_count" => InventorySortKey::PlacesCount,
+            sort_key => {
+                match sort_key.strip_prefix("flat_damage:") {
+                    Some(damage_type) => {
+                        InventorySortKey::FlatDamage(Some(try_parse_damage_type(damage_type)?))
+                    }
+                    None => {
+                        let error_message = format!(
+                            "{sort_key} is not a sort key of the SortInventory command. Got \
+                             {command_parts:?}"
+                        );
+                        return Err(MyError::create_parse_command_error(
+                            ErrorCode::InvalidSortKey,
+                            error_message,
+                        ));
+                    }
+                }
+            }
+        };
+
+        let mut sort_order = SortOrder::Ascending;
+        let mut keep_empty_slots = false;
+        for command_part in &command_parts[2..] {
+            match *command_part {
+                "ascending" => sort_order = SortOrder::Ascending,
+                "descending" => sort_order = SortOrder::Descending,
+                "empty_last" => keep_empty_slots = true,
+                command_part => {
+                    let error_message = format!(
+                        "{command_part} is not an option of the SortInventory command, it can be \
+                         ascending, descending or empty_last. Got {command_parts:?}"
+                    );
+                    return Err(MyError::create_parse_command_error(
+                        ErrorCode::InvalidSortKey,
+                        error_message,
+                    ));
+                }
+            }
+        }
+        Ok(SortInventory(sort_key, sort_order, keep_empty_slots))
+    }
+
+    // The items are a comma separated list of indexes, where followed by an item query, or both.
+    fn try_parse_salvage(command_parts: &[&str]) -> Result<Command, MyError> {
+        let (indexes, query) = match command_parts[1..] {
+            [] => {
+                let error_message = format!(
+                    "Trouble parsing Salvage command, it needs a comma seperated list of indexes \
+                     of the items to salvage or where followed by an item query. Got \
+                     {command_parts:?}"
+                );
+                return Err(MyError::create_parse_command_error(
+                    ErrorCode::MissingCommandArguments,
+                    error_message,
+                ));
+            }
+            ["where", ..] => (None, Some(&command_parts[2..])),
+            [indexes, "where", ..] => (Some(indexes), Some(&command_parts[3..])),
+            [indexes, ..] => (Some(indexes), None),
+        };
+
+        let inventory_items = match indexes {
+            Some(indexes) => {
+                indexes
+                    .split(',')
+                    .map(try_parse_item_reference)
+                    .collect::<Result<_, _>>()?
+            }
+            None => Vec::new(),
+        };
+        let query = query
+            .map(|query| try_parse_item_query(&query.join(" ")))
+            .transpose()?;
+        Ok(Salvage(inventory_items, query))
+    }
+
+    // The arguments are pairs of a keyword and a value, like: Inventory min_modifiers 3 limit 10
+    // The value of where is the rest of the command, since an item query can contain spaces.
+    fn try_parse_state_query<F>(
+        command_parts: &[&str],
+        try_parse_filter: fn(&str, &str) -> Option<Result<F, MyError>>,
+    ) -> Result<StateQuery<F>, MyError> {
+        let (arguments, where_query) = match command_parts
+            .iter()
+            .position(|command_part| *command_part == "where")
+        {
+            Some(where_index) => {
+                (
+                    &command_parts[1..where_index],
+                    Some(command_parts[where_index + 1..].join(" ")),
+                )
+            }
+            None => (&command_parts[1..], None),
+        };
+
+        let mut keyword_values = Vec::new();
+        for argument in arguments.chunks(2) {
+            let [keyword, value] = argument else {
+                let error_message = format!(
+                    "Trouble parsing {} command, every keyword needs a value. Got \
+                     {command_parts:?}",
+                    command_parts[0]
+                );
+                return Err(MyError::create_parse_command_error(
+                    ErrorCode::MissingCommandArguments,
+                    error_message,
+                ));
+            };
+            keyword_values.push((*keyword, *value));
+        }
+        if let Some(where_query) = &where_query {
+            keyword_values.push(("where", where_query));
+        }
+
+        let mut query = StateQuery::all();
+        for (keyword, value) in keyword_values {
+            match keyword {
+                "offset" => query.offset = try_parse_usize(value)?,
+                "limit" => query.limit = Some(try_parse_usize(value)?),
+                keyword => {
+                    match try_parse_filter(keyword, value) {
+                        Some(filter) => query.filters.push(filter?),
+                        None => {
+                            let error_message = format!(
+                                "{keyword} is not a keyword of the {} command. Got \
+                                 {command_parts:?}",
+                                command_parts[0]
+                            );
+                            return Err(MyError::create_parse_command_error(
+                                ErrorCode::InvalidQuery,
+                                error_message,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(query)
+    }
+
+    fn try_parse_place_filter(
+        keyword: &str,
+        value: &str,
+    ) -> Option<Result<PlaceFilter, MyError>> {
+        match keyword {
+            "resistance" => Some(try_parse_damage_type(value).map(PlaceFilter::Resistance)),
+            "no_resistance" => Some(try_parse_damage_type(value).map(PlaceFilter::NoResistance)),
+            _ => None,
+        }
+    }
+
+    fn try_parse_item_filter(
+        keyword: &str,
+        value: &str,
+    ) -> Option<Result<ItemFilter, MyError>> {
+        match keyword {
+            "min_modifiers" => Some(try_parse_usize(value).map(ItemFilter::MinModifiers)),
+            "max_modifiers" => Some(try_parse_usize(value).map(ItemFilter::MaxModifiers)),
+            "where" => Some(try_parse_item_query(value).map(ItemFilter::Query)),
+            _ => None,
+        }
+    }
 }
 
 impl TryFrom<Box<str>> for Command {
@@ -161,8 +741,12 @@ impl TryFrom<Box<str>> for Command {
         let command_parts = value.trim().split(' ').collect::<Vec<&str>>();
 
         if command_parts.is_empty() {
-            let error_message = "The given command String were empty. Try the help command for options.";
-            Err(MyError::create_parse_command_error(error_message.into()))
+            let error_message =
+                "The given command String were empty. Try the help command for options.";
+            Err(MyError::create_parse_command_error(
+                ErrorCode::EmptyCommand,
+                error_message.into(),
+            ))
         } else {
             match *command_parts.first().unwrap() {
                 "State" => Ok(State),
@@ -171,11 +755,15 @@ impl TryFrom<Box<str>> for Command {
                 "ExpandMaxElement" => Ok(ExpandMaxElement),
                 "ExpandMinElement" => Ok(ExpandMinElement),
                 "ExpandEquipmentSlots" => Ok(ExpandEquipmentSlots),
+                "ExpandStash" => Ok(ExpandStash),
                 "ReduceDifficulty" => Ok(ReduceDifficulty),
                 "ExpandMaxSimultaneousElement" => Ok(ExpandMaxSimultaneousElement),
                 "ExpandMinSimultaneousElement" => Ok(ExpandMinSimultaneousElement),
                 "Help" => Ok(Help),
                 "ReorderInventory" => Ok(ReorderInventory),
+                "ListGames" => Ok(ListGames),
+                "Undo" => Ok(Undo),
+                "Redo" => Ok(Redo),
                 "Move" => Self::try_parse_move(&command_parts),
                 "AddModifier" => Self::try_parse_add_modifier(&command_parts),
                 "Equip" => Self::try_parse_equip(&command_parts),
@@ -183,9 +771,41 @@ impl TryFrom<Box<str>> for Command {
                 "RerollModifier" => Self::try_parse_reroll_modifier(&command_parts),
                 "SaveTheWorld" => Self::try_parse_save_the_world(&command_parts),
                 "LoadTheWorld" => Self::try_parse_load_the_world(&command_parts),
+                "ListSaves" => Self::try_parse_list_saves(&command_parts),
+                "DeleteSave" => Self::try_parse_delete_save(&command_parts),
+                "RenameSave" => Self::try_parse_rename_save(&command_parts),
+                "SaveInfo" => Self::try_parse_save_info(&command_parts),
+                "NewGame" => Self::try_parse_new_game(&command_parts),
+                "SelectGame" => Self::try_parse_select_game(&command_parts),
+                "Preview" => Self::try_parse_preview(&command_parts),
+                "Simulate" => Self::try_parse_simulate(&command_parts),
+                "OptimizeLoadout" => Self::try_parse_optimize_loadout(&command_parts),
+                "Places" => {
+                    Self::try_parse_state_query(&command_parts, Self::try_parse_place_filter)
+                        .map(Places)
+                }
+                "Equipment" => {
+                    Self::try_parse_state_query(&command_parts, Self::try_parse_item_filter)
+                        .map(Equipment)
+                }
+                "Salvage" => Self::try_parse_salvage(&command_parts),
+                "Lock" | "Unlock" => Self::try_parse_lock(&command_parts),
+                "SortInventory" => Self::try_parse_sort_inventory(&command_parts),
+                "StashItem" => Self::try_parse_stash_item(&command_parts),
+                "UnstashItem" => Self::try_parse_unstash_item(&command_parts),
+                "NameItem" => Self::try_parse_name_item(&command_parts),
+                "NoteItem" => Self::try_parse_note_item(&command_parts),
+                "UpgradeRarity" => Self::try_parse_upgrade_rarity(&command_parts),
+                "Inventory" => {
+                    Self::try_parse_state_query(&command_parts, Self::try_parse_item_filter)
+                        .map(Inventory)
+                }
                 _ => {
                     let error_message = format!("Command not known. Got {command_parts:?}");
-                    Err(MyError::create_parse_command_error(error_message))
+                    Err(MyError::create_parse_command_error(
+                        ErrorCode::UnknownCommand,
+                        error_message,
+                    ))
                 }
             }
         }
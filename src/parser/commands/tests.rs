@@ -1,8 +1,16 @@
 #[cfg(test)]
 mod tests_int {
     use crate::{
-        my_little_rpg_errors::MyError, parser::commands::Command,
-        the_world::index_specifier::IndexSpecifier,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        parser::{commands::Command, save_format::SaveFormat},
+        the_world::{
+            damage_types::DamageType,
+            index_specifier::IndexSpecifier,
+            inventory_sort::{InventorySortKey, SortOrder},
+            item_query::{Comparison, ItemQuery},
+            item_reference::ItemReference,
+            state_query::{ItemFilter, PlaceFilter, StateQuery},
+        },
     };
 
     #[test]
@@ -59,6 +67,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
                 "Trouble parsing move command, it needs the index of the place. Got [\"Move\"]"
                     .to_string()
             )),
@@ -66,6 +75,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidNumber,
                 "The following parameter -1, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
@@ -74,6 +84,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidNumber,
                 "The following parameter B, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
@@ -83,7 +94,7 @@ mod tests_int {
 
         assert_eq!(
             Command::AddModifier(
-                22,
+                ItemReference::Index(22),
                 vec![
                     IndexSpecifier::Absolute(1),
                     IndexSpecifier::Absolute(2),
@@ -95,7 +106,7 @@ mod tests_int {
         //TODO remove all the boxes and replace with pure into
         assert_eq!(
             Command::AddModifier(
-                22,
+                ItemReference::Index(22),
                 vec![
                     IndexSpecifier::RelativePositive(1),
                     IndexSpecifier::RelativeNegative(2),
@@ -106,6 +117,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
                 "Trouble parsing AddModifier command, it needs the index of the item and a list \
                  comma seperated list of items to sacrifice. Got [\"AddModifier\"]"
                     .to_string()
@@ -114,6 +126,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidNumber,
                 "The following parameter -1, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
@@ -122,6 +135,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidNumber,
                 "The following parameter -1, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
@@ -130,22 +144,25 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
-                "The following parameter B, got the following error while parsing: ParseIntError \
+                ErrorCode::InvalidNumber,
+                "The following parameter 1B, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
             )),
-            Command::try_from(Into::<Box<str>>::into("AddModifier B 1,2,3"))
+            Command::try_from(Into::<Box<str>>::into("AddModifier 1B 1,2,3"))
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
-                "The following parameter b, got the following error while parsing: ParseIntError \
+                ErrorCode::InvalidNumber,
+                "The following parameter 1b, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
             )),
-            Command::try_from(Into::<Box<str>>::into("AddModifier 1 b"))
+            Command::try_from(Into::<Box<str>>::into("AddModifier 1 1b"))
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidNumber,
                 "The following parameter b, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
@@ -154,6 +171,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidNumber,
                 "The following parameter b, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
@@ -162,17 +180,19 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidIndexSpecifier,
                 "1-22 created an underflow!".to_string()
             )),
             Command::try_from(Into::<Box<str>>::into("AddModifier 1 -22"))
         );
 
         assert_eq!(
-            Command::Equip(21, 22),
+            Command::Equip(ItemReference::Index(21), ItemReference::Index(22)),
             Command::try_from(Into::<Box<str>>::into("Equip 21 22")).unwrap()
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
                 "Trouble parsing Equip command, it needs index of inventory and index of \
                  equipment slot. Got [\"Equip\"]"
                     .to_string()
@@ -181,6 +201,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidNumber,
                 "The following parameter -1, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
@@ -189,6 +210,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidNumber,
                 "The following parameter -1, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
@@ -197,27 +219,30 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
-                "The following parameter B, got the following error while parsing: ParseIntError \
+                ErrorCode::InvalidNumber,
+                "The following parameter 1B, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
             )),
-            Command::try_from(Into::<Box<str>>::into("Equip B 22"))
+            Command::try_from(Into::<Box<str>>::into("Equip 1B 22"))
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
-                "The following parameter B, got the following error while parsing: ParseIntError \
+                ErrorCode::InvalidNumber,
+                "The following parameter 1B, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
             )),
-            Command::try_from(Into::<Box<str>>::into("Equip 21 B"))
+            Command::try_from(Into::<Box<str>>::into("Equip 21 1B"))
         );
 
         assert_eq!(
-            Command::SwapEquipment(21, 22),
+            Command::SwapEquipment(ItemReference::Index(21), ItemReference::Index(22)),
             Command::try_from(Into::<Box<str>>::into("SwapEquipment 21 22")).unwrap()
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
                 "Trouble parsing SwapEquipment command, it needs index of inventory and index of \
                  equipment slot. Got [\"SwapEquipment\"]"
                     .to_string()
@@ -226,6 +251,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidNumber,
                 "The following parameter -1, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
@@ -234,6 +260,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidNumber,
                 "The following parameter -1, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
@@ -242,24 +269,26 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
-                "The following parameter B, got the following error while parsing: ParseIntError \
+                ErrorCode::InvalidNumber,
+                "The following parameter 1B, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
             )),
-            Command::try_from(Into::<Box<str>>::into("SwapEquipment B 22"))
+            Command::try_from(Into::<Box<str>>::into("SwapEquipment 1B 22"))
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
-                "The following parameter B, got the following error while parsing: ParseIntError \
+                ErrorCode::InvalidNumber,
+                "The following parameter 1B, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
             )),
-            Command::try_from(Into::<Box<str>>::into("SwapEquipment 21 B"))
+            Command::try_from(Into::<Box<str>>::into("SwapEquipment 21 1B"))
         );
 
         assert_eq!(
             Command::RerollModifier(
-                21,
+                ItemReference::Index(21),
                 22,
                 vec![
                     IndexSpecifier::Absolute(1),
@@ -271,7 +300,7 @@ mod tests_int {
         );
         assert_eq!(
             Command::RerollModifier(
-                21,
+                ItemReference::Index(21),
                 22,
                 vec![
                     IndexSpecifier::RelativePositive(1),
@@ -283,6 +312,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
                 "Trouble parsing RerollModifier command, it needs index of inventory, index of \
                  modifier and a list comma seperated list of items to sacrifice. Got \
                  [\"RerollModifier\"]"
@@ -292,6 +322,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
                 "Trouble parsing RerollModifier command, it needs index of inventory, index of \
                  modifier and a list comma seperated list of items to sacrifice. Got \
                  [\"RerollModifier\", \"1\"]"
@@ -301,6 +332,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
                 "Trouble parsing RerollModifier command, it needs index of inventory, index of \
                  modifier and a list comma seperated list of items to sacrifice. Got \
                  [\"RerollModifier\", \"1\", \"1\"]"
@@ -310,6 +342,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidNumber,
                 "The following parameter -1, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
@@ -318,6 +351,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidNumber,
                 "The following parameter -1, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
@@ -326,14 +360,16 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
-                "The following parameter B, got the following error while parsing: ParseIntError \
+                ErrorCode::InvalidNumber,
+                "The following parameter 1B, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
             )),
-            Command::try_from(Into::<Box<str>>::into("RerollModifier B 22 1,2,3"))
+            Command::try_from(Into::<Box<str>>::into("RerollModifier 1B 22 1,2,3"))
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidNumber,
                 "The following parameter B, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
@@ -342,14 +378,16 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
-                "The following parameter a, got the following error while parsing: ParseIntError \
+                ErrorCode::InvalidNumber,
+                "The following parameter 1a, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
             )),
-            Command::try_from(Into::<Box<str>>::into("RerollModifier 21 22 a"))
+            Command::try_from(Into::<Box<str>>::into("RerollModifier 21 22 1a"))
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidNumber,
                 "The following parameter a, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
@@ -358,6 +396,7 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidNumber,
                 "The following parameter a, got the following error while parsing: ParseIntError \
                  { kind: InvalidDigit }"
                     .to_string()
@@ -366,24 +405,34 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidIndexSpecifier,
                 "21-23 created an underflow!".to_string()
             )),
             Command::try_from(Into::<Box<str>>::into("RerollModifier 21 22 -23"))
         );
 
         assert_eq!(
-            Command::SaveTheWorld("a".into(), Some("b".into())),
+            Command::SaveTheWorld("a".into(), Some("b".into()), SaveFormat::Json),
             Command::try_from(Into::<Box<str>>::into("SaveTheWorld a b")).unwrap()
         );
         assert_eq!(
-            Command::SaveTheWorld("a".into(), None),
+            Command::SaveTheWorld("a".into(), None, SaveFormat::Json),
             Command::try_from(Into::<Box<str>>::into("SaveTheWorld a")).unwrap()
         );
+        assert_eq!(
+            Command::SaveTheWorld("a".into(), Some("b".into()), SaveFormat::Binary),
+            Command::try_from(Into::<Box<str>>::into("SaveTheWorld a b binary")).unwrap()
+        );
+        assert_eq!(
+            Command::SaveTheWorld("binary".into(), None, SaveFormat::Json),
+            Command::try_from(Into::<Box<str>>::into("SaveTheWorld binary")).unwrap()
+        );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
                 "Trouble parsing SaveTheWorld command, it needs a save game name and optionally a \
-                 path to the savegame (remember to end the path with /). Default location is \
-                 ./save_games/. Got [\"SaveTheWorld\"]"
+                 folder for the savegame inside the save games folder of the server. Got \
+                 [\"SaveTheWorld\"]"
                     .to_string()
             )),
             Command::try_from(Into::<Box<str>>::into("SaveTheWorld"))
@@ -399,16 +448,388 @@ mod tests_int {
         );
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
                 "Trouble parsing LoadTheWorld command, it needs a save game name and optionally a \
-                 path to the savegame (remember to end the path with /). Default location is \
-                 ./save_games/. Got [\"LoadTheWorld\"]"
+                 folder for the savegame inside the save games folder of the server. Got \
+                 [\"LoadTheWorld\"]"
                     .to_string()
             )),
             Command::try_from(Into::<Box<str>>::into("LoadTheWorld"))
         );
 
+        assert_eq!(
+            Command::ListSaves(None),
+            Command::try_from(Into::<Box<str>>::into("ListSaves")).unwrap()
+        );
+        assert_eq!(
+            Command::ListSaves(Some("b".into())),
+            Command::try_from(Into::<Box<str>>::into("ListSaves b")).unwrap()
+        );
+        assert_eq!(
+            Command::DeleteSave("a".into(), Some("b".into())),
+            Command::try_from(Into::<Box<str>>::into("DeleteSave a b")).unwrap()
+        );
+        assert_eq!(
+            Command::RenameSave("a".into(), "c".into(), None),
+            Command::try_from(Into::<Box<str>>::into("RenameSave a c")).unwrap()
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                "Trouble parsing RenameSave command, it needs the current and the new save game \
+                 name and optionally the folder of the savegame. Got [\"RenameSave\", \"a\"]"
+                    .to_string()
+            )),
+            Command::try_from(Into::<Box<str>>::into("RenameSave a"))
+        );
+        assert_eq!(
+            Command::SaveInfo("a".into(), None),
+            Command::try_from(Into::<Box<str>>::into("SaveInfo a")).unwrap()
+        );
+
+        assert_eq!(
+            Command::NewGame("a".into(), Some([1; 16])),
+            Command::try_from(Into::<Box<str>>::into(
+                "NewGame a 01010101010101010101010101010101"
+            ))
+            .unwrap()
+        );
+        assert_eq!(
+            Command::NewGame("a".into(), None),
+            Command::try_from(Into::<Box<str>>::into("NewGame a")).unwrap()
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                "Trouble parsing NewGame command, it needs a game name and optionally a seed of \
+                 32 hexidecimals. Got [\"NewGame\"]"
+                    .to_string()
+            )),
+            Command::try_from(Into::<Box<str>>::into("NewGame"))
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidSeed,
+                "32 Hexidecimals as a string requires 32 chars!".to_string()
+            )),
+            Command::try_from(Into::<Box<str>>::into("NewGame a 0101"))
+        );
+
+        assert_eq!(
+            Command::SelectGame("a".into()),
+            Command::try_from(Into::<Box<str>>::into("SelectGame a")).unwrap()
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                "Trouble parsing SelectGame command, it needs a game name. Got [\"SelectGame\"]"
+                    .to_string()
+            )),
+            Command::try_from(Into::<Box<str>>::into("SelectGame"))
+        );
+        assert_eq!(
+            Command::ListGames,
+            Command::try_from(Into::<Box<str>>::into("ListGames")).unwrap()
+        );
+        assert_eq!(
+            Command::Undo,
+            Command::try_from(Into::<Box<str>>::into("Undo")).unwrap()
+        );
+        assert_eq!(
+            Command::Redo,
+            Command::try_from(Into::<Box<str>>::into("Redo")).unwrap()
+        );
+        assert_eq!(
+            Command::Simulate(None),
+            Command::try_from(Into::<Box<str>>::into("Simulate")).unwrap()
+        );
+        assert_eq!(
+            Command::Simulate(Some(3)),
+            Command::try_from(Into::<Box<str>>::into("Simulate 3")).unwrap()
+        );
+        assert_eq!(
+            Command::OptimizeLoadout(Some(2), false),
+            Command::try_from(Into::<Box<str>>::into("OptimizeLoadout 2")).unwrap()
+        );
+        assert_eq!(
+            Command::OptimizeLoadout(None, true),
+            Command::try_from(Into::<Box<str>>::into("OptimizeLoadout all apply")).unwrap()
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                "Trouble parsing OptimizeLoadout command, it needs the index of a place or all, \
+                 optionally followed by apply. Got [\"OptimizeLoadout\"]"
+                    .to_string()
+            )),
+            Command::try_from(Into::<Box<str>>::into("OptimizeLoadout"))
+        );
+        assert_eq!(
+            Command::Inventory(StateQuery::all()),
+            Command::try_from(Into::<Box<str>>::into("Inventory")).unwrap()
+        );
+        assert_eq!(
+            Command::Inventory(StateQuery::new(
+                20,
+                Some(10),
+                vec![ItemFilter::MinModifiers(3)]
+            )),
+            Command::try_from(Into::<Box<str>>::into(
+                "Inventory min_modifiers 3 offset 20 limit 10"
+            ))
+            .unwrap()
+        );
+        assert_eq!(
+            Command::Equipment(StateQuery::new(0, None, vec![ItemFilter::MaxModifiers(1)])),
+            Command::try_from(Into::<Box<str>>::into("Equipment max_modifiers 1")).unwrap()
+        );
+        assert_eq!(
+            Command::Places(StateQuery::new(
+                0,
+                None,
+                vec![
+                    PlaceFilter::Resistance(DamageType::Fire),
+                    PlaceFilter::NoResistance(DamageType::Holy)
+                ]
+            )),
+            Command::try_from(Into::<Box<str>>::into(
+                "Places resistance Fire no_resistance Holy"
+            ))
+            .unwrap()
+        );
+        assert_eq!(
+            Command::Inventory(StateQuery::new(
+                0,
+                Some(5),
+                vec![ItemFilter::Query(ItemQuery::And(vec![
+                    ItemQuery::NoCosts,
+                    ItemQuery::Modifiers(Comparison::GreaterOrEqual, 2)
+                ]))]
+            )),
+            Command::try_from(Into::<Box<str>>::into(
+                "Inventory limit 5 where cost:none and modifiers>=2"
+            ))
+            .unwrap()
+        );
+        assert_eq!(
+            Command::RerollModifier(
+                ItemReference::Index(3),
+                1,
+                vec![IndexSpecifier::Query(ItemQuery::NoCosts)]
+            ),
+            Command::try_from(Into::<Box<str>>::into("RerollModifier 3 1 where cost:none"))
+                .unwrap()
+        );
+        assert_eq!(
+            Command::AddModifier(
+                ItemReference::Index(3),
+                vec![
+                    IndexSpecifier::Absolute(1),
+                    IndexSpecifier::RelativePositive(1),
+                    IndexSpecifier::Query(ItemQuery::Not(Box::new(ItemQuery::NoGains)))
+                ]
+            ),
+            Command::try_from(Into::<Box<str>>::into(
+                "AddModifier 3 1,+1 where not gain:none"
+            ))
+            .unwrap()
+        );
+        assert_eq!(
+            Command::Lock(ItemReference::Index(4)),
+            Command::try_from(Into::<Box<str>>::into("Lock 4")).unwrap()
+        );
+        assert_eq!(
+            Command::Unlock(ItemReference::Index(2)),
+            Command::try_from(Into::<Box<str>>::into("Unlock 2")).unwrap()
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                "Trouble parsing Unlock command, it needs the index of an item in the inventory. \
+                 Got [\"Unlock\"]"
+                    .to_string()
+            )),
+            Command::try_from(Into::<Box<str>>::into("Unlock"))
+        );
+        assert_eq!(
+            Command::SortInventory(InventorySortKey::Modifiers, SortOrder::Ascending, false),
+            Command::try_from(Into::<Box<str>>::into("SortInventory modifiers")).unwrap()
+        );
+        assert_eq!(
+            Command::SortInventory(
+                InventorySortKey::FlatDamage(Some(DamageType::Fire)),
+                SortOrder::Descending,
+                true
+            ),
+            Command::try_from(Into::<Box<str>>::into(
+                "SortInventory flat_damage:Fire descending empty_last"
+            ))
+            .unwrap()
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidSortKey,
+                "damage is not a sort key of the SortInventory command. Got [\"SortInventory\", \
+                 \"damage\"]"
+                    .to_string()
+            )),
+            Command::try_from(Into::<Box<str>>::into("SortInventory damage"))
+        );
+        assert_eq!(
+            Command::Equip(ItemReference::Id(12), ItemReference::Name("sword".into())),
+            Command::try_from(Into::<Box<str>>::into("Equip #12 sword")).unwrap()
+        );
+        assert_eq!(
+            Command::AddModifier(
+                ItemReference::Name("sword".into()),
+                vec![
+                    IndexSpecifier::Id(4),
+                    IndexSpecifier::Name("fodder".into()),
+                    IndexSpecifier::RelativeNegative(3)
+                ]
+            ),
+            Command::try_from(Into::<Box<str>>::into("AddModifier sword #4,fodder,-3")).unwrap()
+        );
+        assert_eq!(
+            Command::NameItem(ItemReference::Id(4), Some("sword".into())),
+            Command::try_from(Into::<Box<str>>::into("NameItem #4 sword")).unwrap()
+        );
+        assert_eq!(
+            Command::NameItem(ItemReference::Name("sword".into()), None),
+            Command::try_from(Into::<Box<str>>::into("NameItem sword")).unwrap()
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidItemName,
+                "An item name is one word that starts with a letter, without , or #, and is not \
+                 where, and, or or not. Got [\"NameItem\", \"1\", \"where\"]"
+                    .to_string()
+            )),
+            Command::try_from(Into::<Box<str>>::into("NameItem 1 where"))
+        );
+        assert_eq!(
+            Command::NoteItem(ItemReference::Index(1), Some("keep for fire".into())),
+            Command::try_from(Into::<Box<str>>::into("NoteItem 1 keep for fire")).unwrap()
+        );
+        assert_eq!(
+            Command::NoteItem(ItemReference::Index(1), None),
+            Command::try_from(Into::<Box<str>>::into("NoteItem 1")).unwrap()
+        );
+        assert_eq!(
+            Command::UpgradeRarity(
+                ItemReference::Name("sword".into()),
+                vec![IndexSpecifier::Absolute(2), IndexSpecifier::RelativePositive(1)]
+            ),
+            Command::try_from(Into::<Box<str>>::into("UpgradeRarity sword 2,+1")).unwrap()
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                "Trouble parsing UpgradeRarity command, it needs the index of the item and a \
+                 comma seperated list of items to sacrifice. Got [\"UpgradeRarity\"]"
+                    .to_string()
+            )),
+            Command::try_from(Into::<Box<str>>::into("UpgradeRarity"))
+        );
+        assert_eq!(
+            Command::StashItem(ItemReference::Index(3), "keepers".into()),
+            Command::try_from(Into::<Box<str>>::into("StashItem 3 keepers")).unwrap()
+        );
+        assert_eq!(
+            Command::UnstashItem("keepers".into(), ItemReference::Index(0)),
+            Command::try_from(Into::<Box<str>>::into("UnstashItem keepers 0")).unwrap()
+        );
+        assert_eq!(
+            Command::ExpandStash,
+            Command::try_from(Into::<Box<str>>::into("ExpandStash")).unwrap()
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                "Trouble parsing StashItem command, it needs the index of an item in the \
+                 inventory and the name of a stash tab. Got [\"StashItem\", \"3\"]"
+                    .to_string()
+            )),
+            Command::try_from(Into::<Box<str>>::into("StashItem 3"))
+        );
+        assert_eq!(
+            Command::Salvage(vec![ItemReference::Index(1), ItemReference::Index(4)], None),
+            Command::try_from(Into::<Box<str>>::into("Salvage 1,4")).unwrap()
+        );
+        assert_eq!(
+            Command::Salvage(
+                vec![ItemReference::Index(2)],
+                Some(ItemQuery::Modifiers(Comparison::Less, 2))
+            ),
+            Command::try_from(Into::<Box<str>>::into("Salvage 2 where modifiers<2")).unwrap()
+        );
+        assert_eq!(
+            Command::Salvage(Vec::new(), Some(ItemQuery::NoCosts)),
+            Command::try_from(Into::<Box<str>>::into("Salvage where cost:none")).unwrap()
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                "Trouble parsing Salvage command, it needs a comma seperated list of indexes of \
+                 the items to salvage or where followed by an item query. Got [\"Salvage\"]"
+                    .to_string()
+            )),
+            Command::try_from(Into::<Box<str>>::into("Salvage"))
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidQuery,
+                "resistance is not a keyword of the Inventory command. Got [\"Inventory\", \
+                 \"resistance\", \"Fire\"]"
+                    .to_string()
+            )),
+            Command::try_from(Into::<Box<str>>::into("Inventory resistance Fire"))
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                "Trouble parsing Places command, every keyword needs a value. Got [\"Places\", \
+                 \"limit\"]"
+                    .to_string()
+            )),
+            Command::try_from(Into::<Box<str>>::into("Places limit"))
+        );
+        assert!(matches!(
+            Command::try_from(Into::<Box<str>>::into("Places resistance Ice")),
+            Err(MyError::ParseCommand {
+                code: ErrorCode::InvalidDamageType,
+                ..
+            })
+        ));
+        assert_eq!(
+            Command::Preview(Box::new(Command::Equip(
+                ItemReference::Index(1),
+                ItemReference::Index(2)
+            ))),
+            Command::try_from(Into::<Box<str>>::into("Preview Equip 1 2")).unwrap()
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::MissingCommandArguments,
+                "Trouble parsing Preview command, it needs the command to preview. Got \
+                 [\"Preview\"]"
+                    .to_string()
+            )),
+            Command::try_from(Into::<Box<str>>::into("Preview"))
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidNumber,
+                "The following parameter a, got the following error while parsing: ParseIntError \
+                 { kind: InvalidDigit }"
+                    .to_string()
+            )),
+            Command::try_from(Into::<Box<str>>::into("Preview Move a"))
+        );
+
         assert_eq!(
             Err(MyError::create_parse_command_error(
+                ErrorCode::UnknownCommand,
                 "Command not known. Got [\"InvalidCommand\"]".to_string()
             )),
             Command::try_from(Into::<Box<str>>::into("InvalidCommand"))
@@ -1,6 +1,6 @@
 use std::fmt::Write;
 
-use crate::my_little_rpg_errors::MyError;
+use crate::my_little_rpg_errors::{ErrorCode, MyError};
 
 mod tests;
 
@@ -10,12 +10,23 @@ pub fn decode_hex(s: &str) -> Result<Vec<u8>, MyError> {
     if offending_chars.is_empty() {
         decode_hex_unsafe(&s)
     } else {
-        Err(MyError::create_parse_command_error(format!(
-            "{offending_chars:?} is not hexdigit(s)!"
-        )))
+        Err(MyError::create_parse_command_error(
+            ErrorCode::InvalidSeed,
+            format!("{offending_chars:?} is not hexdigit(s)!"),
+        ))
     }
 }
 
+pub fn decode_seed(s: &str) -> Result<[u8; 16], MyError> {
+    if s.len() != 32 {
+        return Err(MyError::create_parse_command_error(
+            ErrorCode::InvalidSeed,
+            "32 Hexidecimals as a string requires 32 chars!".to_string(),
+        ));
+    }
+    decode_hex(s).map(|seed| seed.try_into().expect("We know the size fits!"))
+}
+
 fn list_non_ascii_hexdigit_chars(s: &str) -> Vec<char> {
     s.chars().filter(|c| !char::is_ascii_hexdigit(c)).collect()
 }
@@ -24,7 +35,11 @@ fn decode_hex_unsafe(s: &&str) -> Result<Vec<u8>, MyError> {
     (0..s.len())
         .step_by(2)
         .map(|i| u8::from_str_radix(&s[i..i + 2], 16))
-        .map(|i| i.map_err(|s| MyError::create_parse_command_error(s.to_string())))
+        .map(|i| {
+            i.map_err(|s| {
+                MyError::create_parse_command_error(ErrorCode::InvalidSeed, s.to_string())
+            })
+        })
         .collect()
 }
 
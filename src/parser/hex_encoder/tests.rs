@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests_int {
-    use crate::{decode_hex, parser::hex_encoder::encode_hex};
+    use crate::{
+        my_little_rpg_errors::{ErrorCode, MyError},
+        parser::hex_encoder::{decode_hex, decode_seed, encode_hex},
+    };
 
     //TODO add more tests
     #[test]
@@ -13,4 +16,16 @@ mod tests_int {
 
         assert_eq!(data, decoded);
     }
+
+    #[test]
+    fn decode_seed_wrong_length() {
+        assert_eq!([1; 16], decode_seed(&encode_hex(&[1; 16])).unwrap());
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidSeed,
+                "32 Hexidecimals as a string requires 32 chars!".to_string()
+            )),
+            decode_seed("0101")
+        );
+    }
 }
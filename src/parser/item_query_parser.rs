@@ -0,0 +1,186 @@
+use crate::{
+    my_little_rpg_errors::{ErrorCode, MyError},
+    parser::basetype_parser::{try_parse_rarity, try_parse_usize},
+    the_world::item_query::{
+        Comparison, ItemQuery, ModifierPartQuery, ValueComparison, COST_NAMES, GAIN_NAMES,
+    },
+};
+
+mod tests;
+
+// Longer operators first, so >= is not read as >.
+const COMPARISONS: [(&str, Comparison); 6] = [
+    (">=", Comparison::GreaterOrEqual),
+    ("<=", Comparison::LessOrEqual),
+    ("!=", Comparison::NotEqual),
+    (">", Comparison::Greater),
+    ("<", Comparison::Less),
+    ("=", Comparison::Equal),
+];
+
+// Parses queries like: gain:FlatDamage(Fire)>50 and cost:none and modifiers>=3 and rarity>=Magic
+// Terms are joined with and, which binds stronger than or, and can be negated with not.
+pub fn try_parse_item_query(query: &str) -> Result<ItemQuery, MyError> {
+    let words: Vec<&str> = query.split_whitespace().collect();
+    if words.is_empty() {
+        return Err(create_invalid_query_error(format!(
+            "The item query is empty. Got {query:?}"
+        )));
+    }
+
+    let mut or_queries = Vec::new();
+    for and_words in words.split(|word| *word == "or") {
+        let mut and_queries = Vec::new();
+        for term_words in and_words.split(|word| *word == "and") {
+            and_queries.push(try_parse_term(term_words, query)?);
+        }
+        or_queries.push(flatten(and_queries, ItemQuery::And));
+    }
+    Ok(flatten(or_queries, ItemQuery::Or))
+}
+
+fn flatten(
+    mut queries: Vec<ItemQuery>,
+    combine: fn(Vec<ItemQuery>) -> ItemQuery,
+) -> ItemQuery {
+    if queries.len() == 1 {
+        queries.remove(0)
+    } else {
+        combine(queries)
+    }
+}
+
+fn try_parse_term(
+    term_words: &[&str],
+    query: &str,
+) -> Result<ItemQuery, MyError> {
+    match term_words {
+        ["not", rest @ ..] => {
+            try_parse_term(rest, query).map(|term| ItemQuery::Not(Box::new(term)))
+        }
+        [term] => try_parse_condition(term),
+        _ => {
+            Err(create_invalid_query_error(format!(
+                "Every condition in the item query needs to be joined with and or or. Got \
+                 {query:?}"
+            )))
+        }
+    }
+}
+
+fn try_parse_condition(condition: &str) -> Result<ItemQuery, MyError> {
+    match condition {
+        "gain:none" => return Ok(ItemQuery::NoGains),
+        "cost:none" => return Ok(ItemQuery::NoCosts),
+        _ => {}
+    }
+    if condition.starts_with("rarity") {
+        return try_parse_rarity_condition(condition);
+    }
+
+    let (subject, comparison) = split_comparison(condition)?;
+    if subject == "modifiers" {
+        return match comparison {
+            Some((comparison, value)) => {
+                Ok(ItemQuery::Modifiers(
+                    comparison,
+                    usize::try_from(value).unwrap_or(usize::MAX),
+                ))
+            }
+            None => {
+                Err(create_invalid_query_error(format!(
+                    "modifiers needs a comparison like modifiers>=3. Got {condition:?}"
+                )))
+            }
+        };
+    }
+
+    if let Some(gain) = subject.strip_prefix("gain:") {
+        try_parse_modifier_part(gain, comparison, &GAIN_NAMES).map(ItemQuery::Gain)
+    } else if let Some(cost) = subject.strip_prefix("cost:") {
+        try_parse_modifier_part(cost, comparison, &COST_NAMES).map(ItemQuery::Cost)
+    } else {
+        Err(create_invalid_query_error(format!(
+            "A condition needs to start with gain:, cost:, modifiers or rarity. Got {condition:?}"
+        )))
+    }
+}
+
+// Rarities compare by their tier, so rarity>=Rare matches Rare and Legendary items.
+fn try_parse_rarity_condition(condition: &str) -> Result<ItemQuery, MyError> {
+    match find_comparison(condition) {
+        Some(("rarity", comparison, value)) => {
+            try_parse_rarity(value).map(|rarity| ItemQuery::Rarity(comparison, rarity))
+        }
+        _ => {
+            Err(create_invalid_query_error(format!(
+                "rarity needs a comparison like rarity>=Magic. Got {condition:?}"
+            )))
+        }
+    }
+}
+
+fn split_comparison(condition: &str) -> Result<(&str, Option<ValueComparison>), MyError> {
+    let Some((subject, comparison, value)) = find_comparison(condition) else {
+        return Ok((condition, None));
+    };
+
+    let value = try_parse_usize(value)?;
+    Ok((
+        subject,
+        Some((comparison, u64::try_from(value).unwrap_or(u64::MAX))),
+    ))
+}
+
+// Splits the condition at its first operator, into what is compared, how and the value.
+fn find_comparison(condition: &str) -> Option<(&str, Comparison, &str)> {
+    COMPARISONS
+        .iter()
+        .filter_map(|(operator, comparison)| {
+            condition
+                .find(operator)
+                .map(|position| (position, *operator, *comparison))
+        })
+        .min_by_key(|(position, operator, _)| (*position, usize::MAX - operator.len()))
+        .map(|(position, operator, comparison)| {
+            (
+                &condition[..position],
+                comparison,
+                &condition[position + operator.len()..],
+            )
+        })
+}
+
+fn try_parse_modifier_part(
+    modifier_part: &str,
+    comparison: Option<ValueComparison>,
+    names: &[&str],
+) -> Result<ModifierPartQuery, MyError> {
+    let (name, argument) = match modifier_part.split_once('(') {
+        Some((name, argument)) => {
+            let argument = argument.strip_suffix(')').ok_or_else(|| {
+                create_invalid_query_error(format!(
+                    "The argument of {name} needs to end with ). Got {modifier_part:?}"
+                ))
+            })?;
+            (name, Some(Box::from(argument)))
+        }
+        None => (modifier_part, None),
+    };
+
+    if !names.contains(&name) {
+        return Err(create_invalid_query_error(format!(
+            "{name} is not known, it needs to be one of {names:?}"
+        )));
+    }
+
+    Ok(ModifierPartQuery {
+        name: name.into(),
+        argument,
+        comparison,
+    })
+}
+
+fn create_invalid_query_error(error_message: String) -> MyError {
+    MyError::create_parse_command_error(ErrorCode::InvalidQuery, error_message)
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tests_int {
+    use crate::{
+        my_little_rpg_errors::{ErrorCode, MyError},
+        parser::item_query_parser::try_parse_item_query,
+        the_world::{
+            item_query::{Comparison, ItemQuery, ModifierPartQuery},
+            rarity::Rarity,
+        },
+    };
+
+    #[test]
+    fn test_try_parse_item_query() {
+        assert_eq!(
+            Ok(ItemQuery::And(vec![
+                ItemQuery::Gain(ModifierPartQuery {
+                    name: "FlatDamage".into(),
+                    argument: Some("Fire".into()),
+                    comparison: Some((Comparison::Greater, 50)),
+                }),
+                ItemQuery::NoCosts,
+                ItemQuery::Modifiers(Comparison::GreaterOrEqual, 3),
+            ])),
+            try_parse_item_query("gain:FlatDamage(Fire)>50 and cost:none and modifiers>=3")
+        );
+        assert_eq!(
+            Ok(ItemQuery::Or(vec![
+                ItemQuery::And(vec![
+                    ItemQuery::Modifiers(Comparison::Less, 2),
+                    ItemQuery::Not(Box::new(ItemQuery::NoGains)),
+                ]),
+                ItemQuery::Cost(ModifierPartQuery {
+                    name: "MinWinsInARow".into(),
+                    argument: None,
+                    comparison: None,
+                }),
+            ])),
+            try_parse_item_query("modifiers<2 and not gain:none or cost:MinWinsInARow")
+        );
+        assert_eq!(
+            Ok(ItemQuery::Modifiers(Comparison::NotEqual, 0)),
+            try_parse_item_query("modifiers!=0")
+        );
+        assert_eq!(
+            Ok(ItemQuery::And(vec![
+                ItemQuery::Rarity(Comparison::GreaterOrEqual, Rarity::Rare),
+                ItemQuery::NoCosts,
+            ])),
+            try_parse_item_query("rarity>=Rare and cost:none")
+        );
+    }
+
+    #[test]
+    fn test_try_parse_item_query_rarity_errors() {
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidQuery,
+                "rarity needs a comparison like rarity>=Magic. Got \"rarity\"".to_string()
+            )),
+            try_parse_item_query("rarity")
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidRarity,
+                "The following parameter Epic, is not a rarity. The rarities are [Common, Magic, \
+                 Rare, Legendary]"
+                    .to_string()
+            )),
+            try_parse_item_query("rarity=Epic")
+        );
+    }
+
+    #[test]
+    fn test_try_parse_item_query_errors() {
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidQuery,
+                "The item query is empty. Got \"\"".to_string()
+            )),
+            try_parse_item_query("")
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidQuery,
+                "Every condition in the item query needs to be joined with and or or. Got \
+                 \"cost:none modifiers>1\""
+                    .to_string()
+            )),
+            try_parse_item_query("cost:none modifiers>1")
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidQuery,
+                "MinWinsInARow is not known, it needs to be one of [\"FlatDamage\", \
+                 \"PercentageIncreaseDamage\", \"FlatItemResource\", \"FlatResistanceReduction\", \
+                 \"PercentageIncreaseResistanceReduction\", \
+                 \"FlatDamageAgainstHighestResistance\", \
+                 \"PercentageIncreaseDamageAgainstHighestResistance\", \
+                 \"FlatDamageAgainstLowestResistance\", \
+                 \"PercentageIncreaseDamageAgainstLowestResistance\", \
+                 \"PercentageIncreaseTreasure\", \"FlatIncreaseRewardedItems\"]"
+                    .to_string()
+            )),
+            try_parse_item_query("gain:MinWinsInARow")
+        );
+        assert_eq!(
+            Err(MyError::create_parse_command_error(
+                ErrorCode::InvalidQuery,
+                "modifiers needs a comparison like modifiers>=3. Got \"modifiers\"".to_string()
+            )),
+            try_parse_item_query("modifiers")
+        );
+        assert!(matches!(
+            try_parse_item_query("gain:FlatDamage>a"),
+            Err(MyError::ParseCommand {
+                code: ErrorCode::InvalidNumber,
+                ..
+            })
+        ));
+        assert!(matches!(
+            try_parse_item_query("damage>1"),
+            Err(MyError::ParseCommand {
+                code: ErrorCode::InvalidQuery,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,215 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::Write,
+    sync::{Mutex, MutexGuard, PoisonError, RwLock},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    generator::game::new_seed,
+    my_little_rpg_errors::{ErrorCode, MyError},
+    parser::{commands::Command, hex_encoder::encode_hex, session::Session},
+    the_world::{game_registry::GameRegistry, rules::Rules},
+    Game,
+};
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub enum JournalEntry {
+    // Written every time the server starts, with what is needed to recreate the default game and
+    // the sessions.
+    Started {
+        seed: Box<str>,
+        history_depth: usize,
+        #[serde(default)]
+        rules: Box<Rules>,
+    },
+    Executed {
+        session: u64,
+        command: Command,
+        result: Result<Value, MyError>,
+        // The game LoadTheWorld loaded, since the save game may be changed or gone when replaying.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        loaded_game: Option<Box<Game>>,
+    },
+    // Written when a connection opens, with the game the session starts out playing.
+    Connected {
+        session: u64,
+    },
+    // Written when a connection closes, its games can be selected by other sessions from then on.
+    Closed {
+        session: u64,
+    },
+}
+
+// Appends every executed command and its result to a file, one JSON entry per line. Commands that
+// manage the games are written in the order they were executed in, every other command in the order
+// it was executed in on its game. Commands on different games do not wait for each other.
+pub struct Journal {
+    file: Mutex<File>,
+    // Held shared by commands playing a game and exclusively by commands managing the games.
+    order: RwLock<()>,
+}
+
+impl Journal {
+    pub fn open(path: &str) -> Result<Self, MyError> {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map(|file| {
+                Self {
+                    file: Mutex::new(file),
+                    order: RwLock::new(()),
+                }
+            })
+            .map_err(|error| {
+                MyError::create_save_load_error(
+                    ErrorCode::JournalFailed,
+                    format!("Failed opening the journal {path}! Reason: {error}"),
+                )
+            })
+    }
+
+    pub fn record_start(
+        &self,
+        seed: [u8; 16],
+        rules: &Rules,
+        history_depth: usize,
+    ) {
+        let entry = JournalEntry::Started {
+            seed: encode_hex(&seed),
+            history_depth,
+            rules: Box::new(rules.clone()),
+        };
+        Self::write_entry(&mut self.lock_file(), &entry);
+    }
+
+    // The entry is written while the game is still locked, so the entries of a game are in the same
+    // order as its commands were executed even when sessions share the game.
+    pub fn execute_and_record(
+        &self,
+        session: &mut Session,
+        game_registry: &GameRegistry,
+        command: Command,
+    ) -> Result<Value, MyError> {
+        let command = resolve_seed(command);
+        let session_id = session.id();
+        let record = |result: &Result<Value, MyError>, game: Option<&Game>| {
+            let loaded_game = match (&command, result) {
+                (Command::LoadTheWorld(_, _), Ok(_)) => game.cloned().map(Box::new),
+                _ => None,
+            };
+            let entry = JournalEntry::Executed {
+                session: session_id,
+                command: command.clone(),
+                result: result.clone(),
+                loaded_game,
+            };
+            Self::write_entry(&mut self.lock_file(), &entry);
+        };
+        if manages_games(&command) {
+            let _order = self.order.write().unwrap_or_else(PoisonError::into_inner);
+            session.execute_and_inspect(game_registry, command.clone(), record)
+        } else {
+            let _order = self.order.read().unwrap_or_else(PoisonError::into_inner);
+            session.execute_and_inspect(game_registry, command.clone(), record)
+        }
+    }
+
+    // The game of the new session is added while no other command executes, so a command listing
+    // the games is recorded either before or after the session connected.
+    pub fn record_connected(
+        &self,
+        session_id: u64,
+        game_registry: &GameRegistry,
+        history_depth: usize,
+    ) -> Session {
+        let _order = self.order.write().unwrap_or_else(PoisonError::into_inner);
+        let mut file = self.lock_file();
+        let session = Session::new(session_id, game_registry, history_depth);
+        Self::write_entry(
+            &mut file,
+            &JournalEntry::Connected {
+                session: session_id,
+            },
+        );
+        session
+    }
+
+    // The games are released while no other command executes, so no command selecting them is
+    // recorded before the session was closed.
+    pub fn record_closed(
+        &self,
+        session: &Session,
+        game_registry: &GameRegistry,
+    ) {
+        let _order = self.order.write().unwrap_or_else(PoisonError::into_inner);
+        let mut file = self.lock_file();
+        game_registry.release(session.id());
+        Self::write_entry(
+            &mut file,
+            &JournalEntry::Closed {
+                session: session.id(),
+            },
+        );
+    }
+
+    // A broken journal should not stop the game, so the error is only logged.
+    fn write_entry(
+        file: &mut File,
+        entry: &JournalEntry,
+    ) {
+        if let Err(error) = writeln!(file, "{}", json!(entry)) {
+            println!("Failed writing to the journal, got the following error: {error}");
+        }
+    }
+
+    fn lock_file(&self) -> MutexGuard<'_, File> {
+        self.file.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+pub fn read_journal(path: &str) -> Result<Vec<JournalEntry>, MyError> {
+    let journal = fs::read_to_string(path).map_err(|error| {
+        MyError::create_save_load_error(
+            ErrorCode::JournalFailed,
+            format!("Failed reading the journal {path}! Reason: {error}"),
+        )
+    })?;
+
+    journal
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line).map_err(|error| {
+                MyError::create_save_load_error(
+                    ErrorCode::InvalidJournal,
+                    format!(
+                        "Failed parsing line {} of the journal! Reason: {error}",
+                        index + 1
+                    ),
+                )
+            })
+        })
+        .collect()
+}
+
+// These commands read or change more than the game of the session, so nothing else may execute
+// at the same time.
+fn manages_games(command: &Command) -> bool {
+    matches!(
+        command,
+        Command::NewGame(_, _) | Command::SelectGame(_) | Command::ListGames
+    )
+}
+
+// A new game without a seed gets a random one, which would make the journal impossible to replay.
+fn resolve_seed(command: Command) -> Command {
+    match command {
+        Command::NewGame(name, None) => Command::NewGame(name, Some(new_seed())),
+        command => command,
+    }
+}
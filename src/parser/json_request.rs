@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    my_little_rpg_errors::{ErrorCode, MyError},
+    parser::commands::Command,
+};
+
+mod tests;
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct JsonRequest {
+    #[serde(default)]
+    id: Option<Value>,
+    command: Command,
+}
+
+// A request starting with { is a JSON object like {"id": 7, "command": {"Move": 0}}, everything
+// else is parsed as a text command like "Move 0". Returns the id to echo in the response together
+// with the parsed command.
+pub fn parse_request(request: Box<str>) -> (Option<Value>, Result<Command, MyError>) {
+    if !request.trim_start().starts_with('{') {
+        return (None, Command::try_from(request));
+    }
+
+    match serde_json::from_str::<JsonRequest>(&request) {
+        Ok(json_request) => (json_request.id, Ok(json_request.command)),
+        Err(error) => {
+            let id = serde_json::from_str::<Value>(&request)
+                .ok()
+                .and_then(|value| value.get("id").cloned());
+            (
+                id,
+                Err(MyError::create_parse_command_error(
+                    ErrorCode::InvalidJsonCommand,
+                    format!("Failed parsing the JSON command, got error: {error}"),
+                )),
+            )
+        }
+    }
+}
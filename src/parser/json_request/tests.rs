@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests_int {
+    use serde_json::json;
+
+    use crate::{
+        my_little_rpg_errors::{ErrorCode, MyError},
+        parser::{commands::Command, json_request::parse_request, save_format::SaveFormat},
+        the_world::{
+            index_specifier::IndexSpecifier,
+            item_reference::ItemReference,
+            state_query::{ItemFilter, StateQuery},
+        },
+    };
+
+    #[test]
+    fn parse_text_request() {
+        assert_eq!((None, Ok(Command::Move(2))), parse_request("Move 2".into()));
+    }
+
+    #[test]
+    fn parse_json_request() {
+        assert_eq!(
+            (
+                Some(json!(7)),
+                Ok(Command::RerollModifier(
+                    ItemReference::Index(3),
+                    1,
+                    vec![
+                        IndexSpecifier::RelativePositive(1),
+                        IndexSpecifier::RelativeNegative(2)
+                    ]
+                ))
+            ),
+            parse_request(
+                r#"{"id": 7, "command": {"RerollModifier": [3, 1, [{"RelativePositive": 1}, {"RelativeNegative": 2}]]}}"#
+                    .into()
+            )
+        );
+        assert_eq!(
+            (
+                None,
+                Ok(Command::Equip(
+                    ItemReference::Id(12),
+                    ItemReference::Name("sword".into())
+                ))
+            ),
+            parse_request(r##"{"command": {"Equip": ["#12", "sword"]}}"##.into())
+        );
+        assert_eq!(
+            (None, Ok(Command::State)),
+            parse_request(r#"{"command": "State"}"#.into())
+        );
+        assert_eq!(
+            (
+                Some(json!("a")),
+                Ok(Command::SaveTheWorld("save".into(), None, SaveFormat::Json))
+            ),
+            parse_request(r#"{"id": "a", "command": {"SaveTheWorld": ["save", null]}}"#.into())
+        );
+        assert_eq!(
+            (
+                None,
+                Ok(Command::Inventory(StateQuery::new(
+                    0,
+                    Some(5),
+                    vec![ItemFilter::MinModifiers(2)]
+                )))
+            ),
+            parse_request(
+                r#"{"command": {"Inventory": {"limit": 5, "filters": [{"MinModifiers": 2}]}}}"#
+                    .into()
+            )
+        );
+    }
+
+    #[test]
+    fn parse_json_request_error_keeps_id() {
+        assert_eq!(
+            (
+                Some(json!(7)),
+                Err(MyError::create_parse_command_error(
+                    ErrorCode::InvalidJsonCommand,
+                    "Failed parsing the JSON command, got error: invalid type: string \"a\", \
+                     expected usize at line 1 column 33"
+                        .to_string()
+                ))
+            ),
+            parse_request(r#"{"id": 7, "command": {"Move": "a"}}"#.into())
+        );
+    }
+}
@@ -1,4 +1,12 @@
+pub mod autosave;
 pub mod commands;
 pub mod hex_encoder;
+pub mod journal;
+pub mod json_request;
+pub mod replay;
+pub mod response;
+pub mod save_format;
+pub mod session;
 pub mod tcp_listener;
-mod basetype_parser;
+pub(crate) mod basetype_parser;
+mod item_query_parser;
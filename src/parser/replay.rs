@@ -0,0 +1,136 @@
+use std::{collections::HashMap, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    generator::game::new_with_rules,
+    my_little_rpg_errors::{ErrorCode, MyError},
+    parser::{
+        commands::Command, hex_encoder::decode_seed, journal::JournalEntry, session::Session,
+    },
+    the_world::game_registry::GameRegistry,
+};
+
+mod tests;
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ReplayMismatch {
+    entry: usize,
+    session: u64,
+    command: Command,
+    expected: Result<Value, MyError>,
+    actual: Result<Value, MyError>,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ReplayReport {
+    replayed_commands: usize,
+    mismatches: Vec<ReplayMismatch>,
+}
+
+impl ReplayReport {
+    pub fn is_success(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+// Executes the journal again from the start and compares every result with the recorded one.
+// SaveTheWorld, DeleteSave and RenameSave are not executed, so replaying does not change the save
+// games. LoadTheWorld loads the recorded game instead of the save game, which may have changed
+// since.
+pub fn replay(entries: Vec<JournalEntry>) -> Result<ReplayReport, MyError> {
+    let mut game_registry = None;
+    let mut history_depth = 0;
+    let mut sessions: HashMap<u64, Session> = HashMap::new();
+    let mut report = ReplayReport {
+        replayed_commands: 0,
+        mismatches: Vec::new(),
+    };
+
+    for (index, entry) in entries.into_iter().enumerate() {
+        match entry {
+            JournalEntry::Started {
+                seed,
+                history_depth: started_history_depth,
+                rules,
+            } => {
+                let game = new_with_rules(Some(decode_seed(&seed)?), Arc::new(*rules));
+                game_registry = Some(GameRegistry::new(game));
+                history_depth = started_history_depth;
+                sessions.clear();
+            }
+            JournalEntry::Executed {
+                session,
+                command,
+                result,
+                loaded_game,
+            } => {
+                let game_registry = game_registry.as_ref().ok_or_else(|| {
+                    MyError::create_save_load_error(
+                        ErrorCode::InvalidJournal,
+                        format!(
+                            "Entry {} of the journal was executed before the server was started.",
+                            index + 1
+                        ),
+                    )
+                })?;
+                let replayed_session = sessions.get_mut(&session).ok_or_else(|| {
+                    MyError::create_save_load_error(
+                        ErrorCode::InvalidJournal,
+                        format!(
+                            "Entry {} of the journal was executed before session {session} \
+                             connected.",
+                            index + 1
+                        ),
+                    )
+                })?;
+                let actual = match (&command, loaded_game) {
+                    (
+                        Command::SaveTheWorld(_, _, _)
+                        | Command::DeleteSave(_, _)
+                        | Command::RenameSave(_, _, _),
+                        _,
+                    ) => result.clone(),
+                    (Command::LoadTheWorld(_, _), Some(loaded_game)) => {
+                        replayed_session.load_game(*loaded_game)
+                    }
+                    // A failed load did not change anything.
+                    (Command::LoadTheWorld(_, _), None) if result.is_err() => result.clone(),
+                    _ => replayed_session.execute(game_registry, command.clone()),
+                };
+
+                report.replayed_commands += 1;
+                if actual != result {
+                    report.mismatches.push(ReplayMismatch {
+                        entry: index + 1,
+                        session,
+                        command,
+                        expected: result,
+                        actual,
+                    });
+                }
+            }
+            JournalEntry::Connected { session } => {
+                let game_registry = game_registry.as_ref().ok_or_else(|| {
+                    MyError::create_save_load_error(
+                        ErrorCode::InvalidJournal,
+                        format!(
+                            "Entry {} of the journal connected before the server was started.",
+                            index + 1
+                        ),
+                    )
+                })?;
+                sessions.insert(session, Session::new(session, game_registry, history_depth));
+            }
+            JournalEntry::Closed { session } => {
+                sessions.remove(&session);
+                if let Some(game_registry) = &game_registry {
+                    game_registry.release(session);
+                }
+            }
+        }
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,213 @@
+#[cfg(test)]
+mod tests_int {
+    use std::{fs, thread};
+
+    use serde_json::json;
+
+    use crate::{
+        generator::game::new,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        parser::{
+            commands::Command,
+            journal::{read_journal, Journal, JournalEntry},
+            replay::replay,
+            save_format::SaveFormat,
+        },
+        the_world::{game_registry::GameRegistry, save_root::SaveRoot},
+    };
+
+    fn record_journal(path: &str) -> Vec<JournalEntry> {
+        let journal = Journal::open(path).unwrap();
+        let game = new(Some([1; 16]));
+        journal.record_start(game.seed, &game.rules, 5);
+        let game_registry = GameRegistry::new(game);
+        let mut session_1 = journal.record_connected(0, &game_registry, 5);
+        let mut session_2 = journal.record_connected(1, &game_registry, 5);
+
+        for command in [Command::Move(0), Command::Move(0), Command::ExpandPlaces] {
+            let _result = journal.execute_and_record(&mut session_1, &game_registry, command);
+        }
+        for command in [
+            Command::NewGame("other".into(), None),
+            Command::Move(0),
+            Command::Undo,
+            Command::ListGames,
+        ] {
+            let _result = journal.execute_and_record(&mut session_2, &game_registry, command);
+        }
+        journal.record_closed(&session_2, &game_registry);
+        for command in [Command::SelectGame("other".into()), Command::State] {
+            let _result = journal.execute_and_record(&mut session_1, &game_registry, command);
+        }
+
+        read_journal(path).unwrap()
+    }
+
+    #[test]
+    fn test_replay_journal() {
+        fs::create_dir_all("./testing_journal/").unwrap();
+        let entries = record_journal("./testing_journal/replay.journal");
+        fs::remove_dir_all("./testing_journal/").unwrap();
+
+        assert_eq!(13, entries.len());
+        assert!(matches!(
+            &entries[6],
+            JournalEntry::Executed {
+                command: Command::NewGame(_, Some(_)),
+                ..
+            }
+        ));
+        assert!(matches!(
+            &entries[11],
+            JournalEntry::Executed { result: Ok(_), .. }
+        ));
+
+        let report = replay(entries).unwrap();
+        assert!(report.is_success());
+        assert_eq!(9, report.replayed_commands);
+    }
+
+    // Sessions sharing the default game and sessions playing their own game execute at the same
+    // time, the journal still replays.
+    #[test]
+    fn test_replay_concurrent_sessions() {
+        fs::create_dir_all("./testing_journal_3/").unwrap();
+        let journal = Journal::open("./testing_journal_3/replay.journal").unwrap();
+        let game = new(Some([1; 16]));
+        journal.record_start(game.seed, &game.rules, 5);
+        let game_registry = GameRegistry::new(game);
+
+        thread::scope(|scope| {
+            for session_id in 0..4 {
+                let journal = &journal;
+                let game_registry = &game_registry;
+                scope.spawn(move || {
+                    let mut session = journal.record_connected(session_id, game_registry, 5);
+                    let command = match session_id % 2 {
+                        0 => Command::NewGame(format!("game_{session_id}").into(), None),
+                        _ => Command::SelectGame("default".into()),
+                    };
+                    let _result = journal.execute_and_record(&mut session, game_registry, command);
+                    for command in [Command::Move(0), Command::ExpandPlaces, Command::Undo]
+                        .into_iter()
+                        .cycle()
+                        .take(30)
+                    {
+                        let _result =
+                            journal.execute_and_record(&mut session, game_registry, command);
+                    }
+                    let _result =
+                        journal.execute_and_record(&mut session, game_registry, Command::ListGames);
+                    journal.record_closed(&session, game_registry);
+                });
+            }
+        });
+        let entries = read_journal("./testing_journal_3/replay.journal").unwrap();
+        fs::remove_dir_all("./testing_journal_3/").unwrap();
+
+        let report = replay(entries).unwrap();
+        assert!(report.is_success(), "{report:?}");
+        assert_eq!(128, report.replayed_commands);
+    }
+
+    // The save game is gone when replaying, the recorded game is loaded instead.
+    #[test]
+    fn test_replay_load_without_the_save_game() {
+        let journal_path = "./testing_journal_4/replay.journal";
+        let save_root = SaveRoot::new("./testing_journal_4/saves/");
+        fs::create_dir_all("./testing_journal_4/").unwrap();
+        let journal = Journal::open(journal_path).unwrap();
+        let mut game = new(Some([1; 16]));
+        game.set_save_root(save_root);
+        journal.record_start(game.seed, &game.rules, 5);
+        let game_registry = GameRegistry::new(game);
+        let mut session = journal.record_connected(0, &game_registry, 5);
+
+        for command in [
+            Command::Move(0),
+            Command::SaveTheWorld("replay".into(), None, SaveFormat::Json),
+            Command::Move(0),
+            Command::LoadTheWorld("missing".into(), None),
+            Command::LoadTheWorld("replay".into(), None),
+            Command::State,
+            Command::Undo,
+            Command::State,
+        ] {
+            let _result = journal.execute_and_record(&mut session, &game_registry, command);
+        }
+        let entries = read_journal(journal_path).unwrap();
+        fs::remove_dir_all("./testing_journal_4/").unwrap();
+
+        assert!(matches!(
+            &entries[6],
+            JournalEntry::Executed {
+                result: Ok(_),
+                loaded_game: Some(_),
+                ..
+            }
+        ));
+        let report = replay(entries).unwrap();
+        assert!(report.is_success(), "{report:?}");
+    }
+
+    #[test]
+    fn test_replay_finds_mismatch() {
+        fs::create_dir_all("./testing_journal_2/").unwrap();
+        let mut entries = record_journal("./testing_journal_2/replay.journal");
+        fs::remove_dir_all("./testing_journal_2/").unwrap();
+
+        if let JournalEntry::Executed { result, .. } = &mut entries[4] {
+            *result = Ok(json!("tampered"));
+        }
+
+        let report = replay(entries).unwrap();
+        assert!(!report.is_success());
+        assert_eq!(1, report.mismatches.len());
+        assert_eq!(5, report.mismatches[0].entry);
+        assert_eq!(Command::Move(0), report.mismatches[0].command);
+    }
+
+    #[test]
+    fn test_replay_needs_start() {
+        let entries = vec![JournalEntry::Executed {
+            session: 0,
+            command: Command::State,
+            result: Ok(json!("a")),
+            loaded_game: None,
+        }];
+
+        assert_eq!(
+            Err(MyError::create_save_load_error(
+                ErrorCode::InvalidJournal,
+                "Entry 1 of the journal was executed before the server was started.".to_string()
+            )),
+            replay(entries)
+        );
+    }
+
+    #[test]
+    fn test_replay_needs_connected_session() {
+        let game = new(Some([1; 16]));
+        let entries = vec![
+            JournalEntry::Started {
+                seed: "01010101010101010101010101010101".into(),
+                history_depth: 5,
+                rules: Box::new((*game.rules).clone()),
+            },
+            JournalEntry::Executed {
+                session: 0,
+                command: Command::State,
+                result: Ok(json!("a")),
+                loaded_game: None,
+            },
+        ];
+
+        assert_eq!(
+            Err(MyError::create_save_load_error(
+                ErrorCode::InvalidJournal,
+                "Entry 2 of the journal was executed before session 0 connected.".to_string()
+            )),
+            replay(entries)
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::my_little_rpg_errors::MyError;
+
+mod tests;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Error,
+}
+
+// Every response has the same shape, so clients can check the status instead of guessing from the
+// JSON whether the command succeeded. The command is missing when the request could not be parsed.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Response {
+    status: Status,
+    command: Option<Box<str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<MyError>,
+}
+
+impl Response {
+    pub fn new(
+        id: Option<Value>,
+        command: Option<&str>,
+        result: Result<Value, MyError>,
+    ) -> Self {
+        let command = command.map(Box::from);
+        match result {
+            Ok(result) => {
+                Response {
+                    status: Status::Ok,
+                    command,
+                    id,
+                    result: Some(result),
+                    error: None,
+                }
+            }
+            Err(error) => {
+                Response {
+                    status: Status::Error,
+                    command,
+                    id,
+                    result: None,
+                    error: Some(error),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests_int {
+    use serde_json::json;
+
+    use crate::{
+        my_little_rpg_errors::{ErrorCode, MyError},
+        parser::{commands::Command, response::Response},
+        the_world::item_reference::ItemReference,
+    };
+
+    #[test]
+    fn ok_response() {
+        assert_eq!(
+            json!({"status": "ok", "command": "Move", "result": "a"}),
+            json!(Response::new(
+                None,
+                Some(Command::Move(0).name()),
+                Ok(json!("a"))
+            ))
+        );
+        assert_eq!(
+            json!({"status": "ok", "command": "State", "id": 7, "result": "a"}),
+            json!(Response::new(
+                Some(json!(7)),
+                Some(Command::State.name()),
+                Ok(json!("a"))
+            ))
+        );
+    }
+
+    #[test]
+    fn error_response() {
+        let error = MyError::create_execute_command_error(
+            ErrorCode::InventoryIndexOutOfRange,
+            "a".to_string(),
+        );
+        assert_eq!(
+            json!({
+                "status": "error",
+                "command": "Equip",
+                "id": "b",
+                "error": {
+                    "kind": "ExecuteCommand",
+                    "code": "INVENTORY_INDEX_OUT_OF_RANGE",
+                    "error_message": "a"
+                }
+            }),
+            json!(Response::new(
+                Some(json!("b")),
+                Some(Command::Equip(ItemReference::Index(0), ItemReference::Index(0)).name()),
+                Err(error)
+            ))
+        );
+    }
+
+    #[test]
+    fn unparsable_command_response() {
+        let error = MyError::create_parse_command_error(ErrorCode::UnknownCommand, "a".to_string());
+        assert_eq!(
+            json!({
+                "status": "error",
+                "command": null,
+                "error": {
+                    "kind": "ParseCommand",
+                    "code": "UNKNOWN_COMMAND",
+                    "error_message": "a"
+                }
+            }),
+            json!(Response::new(None, None, Err(error)))
+        );
+    }
+}
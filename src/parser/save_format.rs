@@ -0,0 +1,317 @@
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    my_little_rpg_errors::{ErrorCode, MyError},
+    parser::hex_encoder::{decode_hex, encode_hex},
+    the_world::{random_generators::RandomGenerators, rarity::Rarity, stash::Stash},
+    Game,
+};
+
+mod tests;
+
+// Raise the version, and add a migration from the previous version, whenever a change to the game
+// or its items changes what a save game looks like.
+pub const CURRENT_SAVE_VERSION: u32 = 1;
+
+// Every migration upgrades a save game from the version of its index to the next version.
+const MIGRATIONS: [fn(&mut Value); CURRENT_SAVE_VERSION as usize] = [migrate_0_to_1];
+
+// Binary save games start with these bytes, everything else is read as JSON.
+const BINARY_SAVE_MAGIC: &[u8] = b"MLRPGSAV";
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize, Eq, Hash, Default)]
+pub enum SaveFormat {
+    #[default]
+    Json,
+    // The same save game as MessagePack, which is smaller and not readable by hand.
+    Binary,
+}
+
+impl SaveFormat {
+    pub fn get_all() -> [SaveFormat; 2] {
+        [SaveFormat::Json, SaveFormat::Binary]
+    }
+
+    pub fn from_extension(extension: &str) -> Option<SaveFormat> {
+        SaveFormat::get_all()
+            .into_iter()
+            .find(|format| format.extension() == extension)
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            SaveFormat::Json => "json",
+            SaveFormat::Binary => "sav",
+        }
+    }
+}
+
+// Save games before version 1 are the game without an envelope and without a checksum.
+#[derive(Serialize, Deserialize)]
+struct SaveEnvelope<G> {
+    version: u32,
+    game: G,
+    checksum: Box<str>,
+}
+
+// The checksum covers the format and the version together with the game, so a save game can not be
+// passed off as another version or format without changing its checksum.
+#[derive(Serialize)]
+struct ChecksummedSave<'a> {
+    format: SaveFormat,
+    version: u32,
+    game: &'a Value,
+}
+
+// What loading a save game found out about it, besides the game.
+pub(crate) struct LoadedSave {
+    pub(crate) game: Game,
+    pub(crate) format: SaveFormat,
+    // False for save games from before checksums, which can not be verified.
+    pub(crate) checksum_verified: bool,
+}
+
+// The checksum covers the game, so a save game changed after saving fails to load. Without a signing
+// key anybody can calculate the checksum again, so it only catches corrupted save games. With a
+// signing key only the server can create a matching checksum.
+pub fn write_save(
+    game: &Game,
+    format: SaveFormat,
+    signing_key: Option<&[u8]>,
+) -> Vec<u8> {
+    let game = json!(game);
+    let envelope = json!(SaveEnvelope {
+        version: CURRENT_SAVE_VERSION,
+        checksum: encode_hex(&calculate_checksum(
+            &checksummed_data(format, CURRENT_SAVE_VERSION, &game),
+            signing_key
+        )),
+        game,
+    });
+    match format {
+        SaveFormat::Json => format!("{envelope}").into_bytes(),
+        SaveFormat::Binary => {
+            let mut data = BINARY_SAVE_MAGIC.to_vec();
+            data.extend(rmp_serde::to_vec(&envelope).expect("A JSON value can always be encoded."));
+            data
+        }
+    }
+}
+
+// Reads a save game of any version up to the current one, migrating it to the current game.
+pub fn read_save(
+    data: &[u8],
+    signing_key: Option<&[u8]>,
+) -> Result<Game, MyError> {
+    read_save_details(data, signing_key).map(|loaded_save| loaded_save.game)
+}
+
+pub(crate) fn read_save_details(
+    data: &[u8],
+    signing_key: Option<&[u8]>,
+) -> Result<LoadedSave, MyError> {
+    let (format, save) = match data.strip_prefix(BINARY_SAVE_MAGIC) {
+        Some(data) => {
+            let save = rmp_serde::from_slice::<Value>(data).map_err(|error| {
+                create_load_failed_error(format!("the binary save game is corrupted, {error}"))
+            })?;
+            (SaveFormat::Binary, save)
+        }
+        None => {
+            let save = serde_json::from_slice::<Value>(data)
+                .map_err(|error| create_load_failed_error(error.to_string()))?;
+            (SaveFormat::Json, save)
+        }
+    };
+    let (version, checksum, mut game) = match save {
+        Value::Object(mut envelope)
+            if envelope.contains_key("version") && envelope.contains_key("game") =>
+        {
+            let version = envelope
+                .get("version")
+                .and_then(Value::as_u64)
+                .and_then(|version| u32::try_from(version).ok())
+                .ok_or_else(|| {
+                    create_load_failed_error(format!(
+                        "the version {} is not a number.",
+                        envelope["version"]
+                    ))
+                })?;
+            let checksum = envelope
+                .remove("checksum")
+                .and_then(|checksum| checksum.as_str().map(Box::from));
+            (
+                version,
+                checksum,
+                envelope.remove("game").unwrap_or_default(),
+            )
+        }
+        game => (0, None, game),
+    };
+
+    if CURRENT_SAVE_VERSION < version {
+        return Err(MyError::create_save_load_error(
+            ErrorCode::UnsupportedSaveVersion,
+            format!(
+                "The save game has version {version}, but this server only knows versions up to \
+                 {CURRENT_SAVE_VERSION}. Upgrade the server to load it."
+            ),
+        ));
+    }
+    let checksum_verified = match checksum {
+        Some(checksum) => {
+            verify_checksum(
+                &checksummed_data(format, version, &game),
+                &checksum,
+                signing_key,
+            )?;
+            true
+        }
+        // Anybody could remove the envelope, so with a signing key every save game needs a checksum.
+        None if version == 0 && signing_key.is_none() => false,
+        None if version == 0 => {
+            return Err(create_checksum_mismatch_error(
+                "The save game is from before checksums, so it can not be verified with the \
+                 signing key.",
+            ));
+        }
+        None => {
+            return Err(create_checksum_mismatch_error(
+                "The save game has no checksum, it was changed after it was saved.",
+            ));
+        }
+    };
+    for migration in &MIGRATIONS[version as usize..] {
+        migration(&mut game);
+    }
+
+    serde_json::from_value::<Game>(game)
+        .map_err(|error| create_load_failed_error(error.to_string()))
+        .map(|mut game| {
+            game.assign_missing_item_ids();
+            LoadedSave {
+                game,
+                format,
+                checksum_verified,
+            }
+        })
+}
+
+// The game is checksummed as compact JSON. serde_json sorts the keys of objects, so the same game
+// always has the same checksum.
+fn checksummed_data(
+    format: SaveFormat,
+    version: u32,
+    game: &Value,
+) -> Vec<u8> {
+    serde_json::to_vec(&ChecksummedSave {
+        format,
+        version,
+        game,
+    })
+    .expect("A JSON value can always be written.")
+}
+
+fn calculate_checksum(
+    data: &[u8],
+    signing_key: Option<&[u8]>,
+) -> Vec<u8> {
+    match signing_key {
+        Some(signing_key) => {
+            create_mac(signing_key, data)
+                .finalize()
+                .into_bytes()
+                .to_vec()
+        }
+        None => Sha256::digest(data).to_vec(),
+    }
+}
+
+fn verify_checksum(
+    data: &[u8],
+    checksum: &str,
+    signing_key: Option<&[u8]>,
+) -> Result<(), MyError> {
+    let is_valid = decode_hex(checksum).is_ok_and(|checksum| {
+        match signing_key {
+            Some(signing_key) => {
+                create_mac(signing_key, data)
+                    .verify_slice(&checksum)
+                    .is_ok()
+            }
+            None => calculate_checksum(data, None) == checksum,
+        }
+    });
+    if is_valid {
+        Ok(())
+    } else {
+        Err(create_checksum_mismatch_error(
+            "The checksum of the save game does not match, it was corrupted or changed after it \
+             was saved, or saved with a different signing key.",
+        ))
+    }
+}
+
+fn create_mac(
+    signing_key: &[u8],
+    data: &[u8],
+) -> Hmac<Sha256> {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(signing_key).expect("HMAC takes keys of any size.");
+    mac.update(data);
+    mac
+}
+
+// Version 1 added the envelope with the version and the checksum, the stash, item ids, names, notes,
+// locks and rarities, and split the random generator into one stream per part of the game. Items
+// get their ids when the game is loaded.
+fn migrate_0_to_1(game: &mut Value) {
+    let Some(game) = game.as_object_mut() else {
+        return;
+    };
+    game.entry("stash")
+        .or_insert_with(|| json!(Stash::default()));
+    game.entry("next_item_id").or_insert(json!(0));
+
+    for items in ["equipped_items", "inventory"] {
+        let items = game.get_mut(items).and_then(Value::as_array_mut);
+        for item in items.into_iter().flatten().filter_map(Value::as_object_mut) {
+            item.entry("id").or_insert(json!(0));
+            item.entry("name").or_insert(Value::Null);
+            item.entry("notes").or_insert(Value::Null);
+            item.entry("rarity").or_insert(json!(Rarity::default()));
+            item.entry("locked").or_insert(json!(false));
+        }
+    }
+    split_random_generator(game);
+}
+
+// The old random generator keeps generating the places, loot and crafting start their streams from
+// the seed.
+fn split_random_generator(game: &mut Map<String, Value>) {
+    let Some(seed) = game
+        .get("seed")
+        .and_then(|seed| serde_json::from_value::<[u8; 16]>(seed.clone()).ok())
+    else {
+        return;
+    };
+    let mut random_generators = json!(RandomGenerators::new(seed));
+    if let Some(random_generator_state) = game.remove("random_generator_state") {
+        random_generators["places"] = random_generator_state;
+    }
+    game.insert("random_generators".to_string(), random_generators);
+}
+
+fn create_load_failed_error(reason: String) -> MyError {
+    MyError::create_save_load_error(
+        ErrorCode::LoadFailed,
+        format!("Failed loading the world! Reason: {reason}"),
+    )
+}
+
+fn create_checksum_mismatch_error(error_message: &str) -> MyError {
+    MyError::create_save_load_error(ErrorCode::SaveChecksumMismatch, error_message.to_string())
+}
@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod tests_int {
+    use rand::Rng;
+    use serde_json::{json, Value};
+
+    use crate::{
+        generator::game::new_testing,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        parser::save_format::{
+            read_save, read_save_details, write_save, SaveFormat, BINARY_SAVE_MAGIC,
+            CURRENT_SAVE_VERSION,
+        },
+        the_world::rarity::Rarity,
+    };
+
+    // Written by the versions of the game the save games belong to, from new_testing(Some([1; 16])).
+    const GOLDEN_SAVES: [&[u8]; CURRENT_SAVE_VERSION as usize + 1] = [
+        include_bytes!("golden/v0.json"),
+        include_bytes!("golden/v1.json"),
+    ];
+    // Binary save games exist since version 1.
+    const GOLDEN_BINARY_SAVES: [&[u8]; CURRENT_SAVE_VERSION as usize] =
+        [include_bytes!("golden/v1.sav")];
+
+    #[test]
+    fn every_version_loads_as_the_current_game() {
+        for (version, golden_save) in GOLDEN_SAVES.iter().enumerate() {
+            assert_eq!(
+                new_testing(Some([1; 16])),
+                read_save(golden_save, None).unwrap(),
+                "version {version}"
+            );
+        }
+        for (version, golden_save) in (1..).zip(GOLDEN_BINARY_SAVES) {
+            assert_eq!(
+                new_testing(Some([1; 16])),
+                read_save(golden_save, None).unwrap(),
+                "binary version {version}"
+            );
+        }
+    }
+
+    // A change to what a save game looks like fails here, until the version is raised and a
+    // migration and a golden save game for the new version are added.
+    #[test]
+    fn current_version_is_written_like_its_golden_save() {
+        let game = new_testing(Some([1; 16]));
+
+        assert_eq!(
+            serde_json::from_slice::<Value>(GOLDEN_SAVES[CURRENT_SAVE_VERSION as usize]).unwrap(),
+            serde_json::from_slice::<Value>(&write_save(&game, SaveFormat::Json, None)).unwrap()
+        );
+        assert_eq!(
+            GOLDEN_BINARY_SAVES[CURRENT_SAVE_VERSION as usize - 1],
+            write_save(&game, SaveFormat::Binary, None)
+        );
+    }
+
+    #[test]
+    fn binary_save_is_smaller() {
+        let game = new_testing(Some([1; 16]));
+
+        assert!(
+            write_save(&game, SaveFormat::Binary, None).len()
+                < write_save(&game, SaveFormat::Json, None).len()
+        );
+    }
+
+    #[test]
+    fn migrate_0_to_1() {
+        let game = read_save(GOLDEN_SAVES[0], None).unwrap();
+
+        assert_eq!(10, game.stash.capacity);
+        assert_eq!(12, game.next_item_id);
+        assert!(game
+            .items()
+            .all(|item| item.id != 0 && item.rarity == Rarity::Common && !item.locked));
+    }
+
+    #[test]
+    fn newer_version_is_rejected() {
+        let save = json!({"version": CURRENT_SAVE_VERSION + 1, "game": {}, "checksum": ""});
+
+        assert_eq!(
+            Err(MyError::create_save_load_error(
+                ErrorCode::UnsupportedSaveVersion,
+                format!(
+                    "The save game has version {}, but this server only knows versions up to {}. \
+                     Upgrade the server to load it.",
+                    CURRENT_SAVE_VERSION + 1,
+                    CURRENT_SAVE_VERSION
+                )
+            )),
+            read_save(save.to_string().as_bytes(), None)
+        );
+    }
+
+    #[test]
+    fn changed_save_is_rejected() {
+        let game = new_testing(Some([1; 16]));
+        let mut save =
+            serde_json::from_slice::<Value>(&write_save(&game, SaveFormat::Json, None)).unwrap();
+
+        save["game"]["statistics"]["wins"] = json!(1000);
+        assert!(is_checksum_mismatch(read_save(
+            save.to_string().as_bytes(),
+            None
+        )));
+
+        save.as_object_mut().unwrap().remove("checksum");
+        assert!(is_checksum_mismatch(read_save(
+            save.to_string().as_bytes(),
+            None
+        )));
+
+        let mut binary_save = write_save(&game, SaveFormat::Binary, None);
+        let middle_byte = binary_save.len() / 2;
+        binary_save[middle_byte] ^= 1;
+        assert!(read_save(&binary_save, None).is_err());
+    }
+
+    #[test]
+    fn signed_save_needs_the_signing_key() {
+        let game = new_testing(Some([1; 16]));
+        let save = write_save(&game, SaveFormat::Binary, Some(b"secret"));
+
+        assert!(is_checksum_mismatch(read_save(&save, None)));
+        assert!(is_checksum_mismatch(read_save(&save, Some(b"other"))));
+
+        let loaded_save = read_save_details(&save, Some(b"secret")).unwrap();
+        assert_eq!(game, loaded_save.game);
+        assert_eq!(SaveFormat::Binary, loaded_save.format);
+        assert!(loaded_save.checksum_verified);
+    }
+
+    #[test]
+    fn signed_save_can_not_change_its_version_or_format() {
+        let mut game = new_testing(Some([1; 16]));
+        game.random_generators.crafting.gen::<u64>();
+        let save =
+            serde_json::from_slice::<Value>(&write_save(&game, SaveFormat::Json, Some(b"secret")))
+                .unwrap();
+
+        let mut older_save = save.clone();
+        older_save["version"] = json!(0);
+        assert!(is_checksum_mismatch(read_save(
+            older_save.to_string().as_bytes(),
+            Some(b"secret")
+        )));
+
+        let mut binary_save = BINARY_SAVE_MAGIC.to_vec();
+        binary_save.extend(rmp_serde::to_vec(&save).unwrap());
+        assert!(is_checksum_mismatch(read_save(
+            &binary_save,
+            Some(b"secret")
+        )));
+    }
+
+    #[test]
+    fn save_before_checksums_is_not_verified() {
+        let loaded_save = read_save_details(GOLDEN_SAVES[0], None).unwrap();
+
+        assert_eq!(SaveFormat::Json, loaded_save.format);
+        assert!(!loaded_save.checksum_verified);
+    }
+
+    #[test]
+    fn save_before_checksums_is_rejected_with_a_signing_key() {
+        let game = new_testing(Some([1; 16]));
+        let mut save =
+            serde_json::from_slice::<Value>(&write_save(&game, SaveFormat::Json, Some(b"secret")))
+                .unwrap();
+        save["game"]["statistics"]["wins"] = json!(1000);
+        save.as_object_mut().unwrap().remove("checksum");
+        save["version"] = json!(1);
+
+        for old_save in [&save, &save["game"]] {
+            assert!(is_checksum_mismatch(read_save(
+                old_save.to_string().as_bytes(),
+                Some(b"secret")
+            )));
+        }
+        assert!(is_checksum_mismatch(read_save(
+            GOLDEN_SAVES[0],
+            Some(b"secret")
+        )));
+    }
+
+    fn is_checksum_mismatch<T>(result: Result<T, MyError>) -> bool {
+        matches!(
+            result,
+            Err(MyError::SaveLoad {
+                code: ErrorCode::SaveChecksumMismatch,
+                ..
+            })
+        )
+    }
+}
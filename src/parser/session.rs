@@ -0,0 +1,338 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use serde_json::Value;
+
+use crate::{
+    command::{
+        craft_expand_modifier::execute_craft_expand_modifiers_json,
+        craft_reroll_modifier::execute_craft_reroll_modifier_json,
+        equip_swap::{execute_equip_item_json, execute_swap_equipped_item_json},
+        expand_elements::execute_expand_elements_json,
+        expand_equipment_slots::execute_expand_equipment_slots_json,
+        expand_max_element::execute_expand_max_element_json,
+        expand_max_simultaneous_element::execute_expand_max_simultaneous_element_json,
+        expand_min_element::execute_expand_min_element_json,
+        expand_min_simultanius_element::execute_expand_min_simultaneous_element_json,
+        expand_places::execute_expand_places_json,
+        expand_stash::execute_expand_stash_json,
+        help::execute_help_json,
+        lock_item::execute_lock_item_json,
+        manage_games::{execute_list_games_json, execute_new_game_json, execute_select_game_json},
+        manage_saves::{
+            execute_delete_save_json, execute_list_saves_json, execute_rename_save_json,
+            execute_save_info_json,
+        },
+        name_item::{execute_name_item_json, execute_note_item_json},
+        optimize_loadout::execute_optimize_loadout_json,
+        presentation_game_state::execute_presentation_game_state_json,
+        query_state::{execute_equipment_json, execute_inventory_json, execute_places_json},
+        r#move::execute_move_command_json,
+        reduce_difficulty::execute_reduce_difficulty_json,
+        reorder_inventory::execute_reorder_inventory_json,
+        salvage::execute_salvage_json,
+        save_load::{execute_load_command_json, execute_save_command_json, replace_game_json},
+        simulate::execute_simulate_json,
+        sort_inventory::execute_sort_inventory_json,
+        stash::{execute_stash_item_json, execute_unstash_item_json},
+        undo_redo::{execute_redo_json, execute_undo_json},
+        upgrade_rarity::execute_upgrade_rarity_json,
+    },
+    my_little_rpg_errors::{ErrorCode, MyError},
+    parser::commands::Command,
+    the_world::{
+        game_history::GameHistory,
+        game_registry::{lock_game, GameRegistry, SelectedGame},
+    },
+    Game,
+};
+
+mod tests;
+
+// Everything one client has going on: the game it plays and the commands it can undo. Sessions
+// start out playing a new game of their own, the default game is only played after selecting it.
+pub struct Session {
+    id: u64,
+    selected_game: SelectedGame,
+    game_history: GameHistory,
+}
+
+impl Session {
+    pub fn new(
+        id: u64,
+        game_registry: &GameRegistry,
+        history_depth: usize,
+    ) -> Self {
+        Self {
+            id,
+            selected_game: game_registry.insert_session_game(id),
+            game_history: GameHistory::new(history_depth),
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn execute(
+        &mut self,
+        game_registry: &GameRegistry,
+        command: Command,
+    ) -> Result<Value, MyError> {
+        self.execute_and_inspect(game_registry, command, |_, _| {})
+    }
+
+    // The result is inspected while the game is still locked, so no other command changes the game
+    // in between. Commands managing the games have no game to inspect.
+    pub(crate) fn execute_and_inspect(
+        &mut self,
+        game_registry: &GameRegistry,
+        command: Command,
+        inspect: impl FnOnce(&Result<Value, MyError>, Option<&Game>),
+    ) -> Result<Value, MyError> {
+        let selected_game = &mut self.selected_game;
+        let game_history = &mut self.game_history;
+        let result = match command {
+            Command::NewGame(name, seed) => {
+                execute_new_game_json(game_registry, selected_game, self.id, &name, seed)
+                    .inspect(|_| game_history.clear())
+            }
+            Command::SelectGame(name) => {
+                execute_select_game_json(game_registry, selected_game, self.id, &name)
+                    .inspect(|_| game_history.clear())
+            }
+            Command::ListGames => Ok(execute_list_games_json(game_registry, selected_game)),
+            command => {
+                let mut game = lock_game(&selected_game.game);
+                let result = execute_with_history(&mut game, game_history, command);
+                inspect(&result, Some(&game));
+                return result;
+            }
+        };
+        inspect(&result, None);
+        result
+    }
+
+    // Replaces the game like LoadTheWorld does, with a game that was loaded before instead of a
+    // save game.
+    pub(crate) fn load_game(
+        &mut self,
+        loaded_game: Game,
+    ) -> Result<Value, MyError> {
+        let mut game = lock_game(&self.selected_game.game);
+        let game_before = game.clone();
+        let result = replace_game_json(&mut game, loaded_game);
+        self.game_history.record(game_before, &mut game);
+        Ok(result)
+    }
+}
+
+fn execute_with_history(
+    game: &mut Game,
+    game_history: &mut GameHistory,
+    command: Command,
+) -> Result<Value, MyError> {
+    match command {
+        Command::Undo => execute_undo_json(game, game_history),
+        Command::Redo => execute_redo_json(game, game_history),
+        // Nothing to snapshot, so the game is not cloned.
+        command if command.is_read_only() => {
+            panic::catch_unwind(AssertUnwindSafe(|| game.execute(command)))
+                .unwrap_or_else(|_| Err(create_command_panicked_error()))
+        }
+        command => {
+            let game_before = game.clone();
+            // A panic can leave the game half changed, so the game goes back to how it was.
+            match panic::catch_unwind(AssertUnwindSafe(|| game.execute(command))) {
+                Ok(result) => {
+                    if is_game_changed(&result) {
+                        game_history.record(game_before, game);
+                    }
+                    result
+                }
+                Err(_) => {
+                    *game = game_before;
+                    Err(create_command_panicked_error())
+                }
+            }
+        }
+    }
+}
+
+// Commands check everything before they change the game, so a failed command left it as it was.
+// The exception is a lost fight, which still counts as a move.
+fn is_game_changed(result: &Result<Value, MyError>) -> bool {
+    matches!(
+        result,
+        Ok(_)
+            | Err(MyError::MoveCommand {
+                code: ErrorCode::NotEnoughDamage,
+                ..
+            })
+    )
+}
+
+pub(crate) fn create_command_panicked_error() -> MyError {
+    MyError::create_execute_command_error(
+        ErrorCode::CommandPanicked,
+        "The command failed unexpectedly, nothing was changed. The error was logged by the server."
+            .to_string(),
+    )
+}
+
+// Executes the commands that only need the game itself. The rest needs a session.
+pub(crate) fn execute_game_command(
+    game: &mut Game,
+    command: Command,
+) -> Result<Value, MyError> {
+    match command {
+        Command::State => Ok(execute_presentation_game_state_json(game)),
+        Command::ReduceDifficulty => execute_reduce_difficulty_json(game),
+        Command::Move(place_index) => execute_move_command_json(game, place_index),
+        Command::Equip(inventory_item, equipped_item) => {
+            let inventory_position = inventory_item.resolve_inventory(game)?;
+            let equipped_item_position = equipped_item.resolve_equipment(game)?;
+            execute_equip_item_json(game, inventory_position, equipped_item_position)
+        }
+        Command::SwapEquipment(equipped_item_1, equipped_item_2) => {
+            let equipped_item_position_1 = equipped_item_1.resolve_equipment(game)?;
+            let equipped_item_position_2 = equipped_item_2.resolve_equipment(game)?;
+            execute_swap_equipped_item_json(
+                game,
+                equipped_item_position_1,
+                equipped_item_position_2,
+            )
+        }
+        Command::RerollModifier(inventory_item, modifier_index, sacrifice_item_indexes) => {
+            let inventory_index = inventory_item.resolve_inventory(game)?;
+            execute_craft_reroll_modifier_json(
+                game,
+                inventory_index,
+                modifier_index,
+                sacrifice_item_indexes,
+            )
+        }
+        Command::ExpandPlaces => execute_expand_places_json(game),
+        Command::ExpandElements => execute_expand_elements_json(game),
+        Command::ExpandMaxElement => execute_expand_max_element_json(game),
+        Command::ExpandMinElement => execute_expand_min_element_json(game),
+        Command::ExpandMaxSimultaneousElement => execute_expand_max_simultaneous_element_json(game),
+        Command::ExpandMinSimultaneousElement => execute_expand_min_simultaneous_element_json(game),
+        Command::ExpandEquipmentSlots => execute_expand_equipment_slots_json(game),
+        Command::ExpandStash => execute_expand_stash_json(game),
+        Command::StashItem(inventory_item, stash_tab) => {
+            let inventory_index = inventory_item.resolve_inventory(game)?;
+            execute_stash_item_json(game, inventory_index, &stash_tab)
+        }
+        Command::UnstashItem(stash_tab, stash_item) => {
+            let stash_index = stash_item.resolve(
+                game.stash
+                    .tabs
+                    .get(&stash_tab)
+                    .into_iter()
+                    .flatten()
+                    .map(Some),
+                &format!("stash tab {stash_tab}"),
+            )?;
+            execute_unstash_item_json(game, &stash_tab, stash_index)
+        }
+        Command::AddModifier(inventory_item, sacrifice_item_indexes) => {
+            let inventory_index = inventory_item.resolve_inventory(game)?;
+            execute_craft_expand_modifiers_json(game, inventory_index, sacrifice_item_indexes)
+        }
+        Command::UpgradeRarity(inventory_item, sacrifice_item_indexes) => {
+            let inventory_index = inventory_item.resolve_inventory(game)?;
+            execute_upgrade_rarity_json(game, inventory_index, sacrifice_item_indexes)
+        }
+        Command::Simulate(place_index) => execute_simulate_json(game, place_index),
+        Command::Places(query) => Ok(execute_places_json(game, &query)),
+        Command::Equipment(query) => Ok(execute_equipment_json(game, &query)),
+        Command::Inventory(query) => Ok(execute_inventory_json(game, &query)),
+        Command::Lock(inventory_item) => {
+            execute_lock_item_json(game, inventory_item.resolve_inventory(game)?, true)
+        }
+        Command::Unlock(inventory_item) => {
+            execute_lock_item_json(game, inventory_item.resolve_inventory(game)?, false)
+        }
+        Command::NameItem(inventory_item, name) => {
+            execute_name_item_json(game, inventory_item.resolve_inventory(game)?, name)
+        }
+        Command::NoteItem(inventory_item, notes) => {
+            execute_note_item_json(game, inventory_item.resolve_inventory(game)?, notes)
+        }
+        Command::Salvage(inventory_items, query) => {
+            let inventory_indexes = inventory_items
+                .iter()
+                .map(|inventory_item| inventory_item.resolve_inventory(game))
+                .collect::<Result<_, _>>()?;
+            execute_salvage_json(game, inventory_indexes, query)
+        }
+        Command::OptimizeLoadout(place_index, apply) => {
+            execute_optimize_loadout_json(game, place_index, apply)
+        }
+        Command::Preview(command) => execute_preview(game, *command),
+        Command::Help => Ok(execute_help_json()),
+        Command::ReorderInventory => Ok(execute_reorder_inventory_json(game)),
+        Command::SortInventory(sort_key, sort_order, keep_empty_slots) => {
+            Ok(execute_sort_inventory_json(
+                game,
+                &sort_key,
+                sort_order,
+                keep_empty_slots,
+            ))
+        }
+        Command::SaveTheWorld(save_game_name, save_game_path, format) => {
+            execute_save_command_json(game, &save_game_name, save_game_path, format)
+        }
+        Command::LoadTheWorld(save_game_name, save_game_path) => {
+            execute_load_command_json(game, &save_game_name, save_game_path)
+        }
+        Command::ListSaves(save_game_path) => execute_list_saves_json(game, save_game_path),
+        Command::DeleteSave(save_game_name, save_game_path) => {
+            execute_delete_save_json(game, &save_game_name, save_game_path)
+        }
+        Command::RenameSave(save_game_name, new_save_game_name, save_game_path) => {
+            execute_rename_save_json(game, &save_game_name, &new_save_game_name, save_game_path)
+        }
+        Command::SaveInfo(save_game_name, save_game_path) => {
+            execute_save_info_json(game, &save_game_name, save_game_path)
+        }
+        Command::NewGame(_, _)
+        | Command::SelectGame(_)
+        | Command::ListGames
+        | Command::Undo
+        | Command::Redo => {
+            Err(MyError::create_execute_command_error(
+                ErrorCode::CommandNeedsSession,
+                format!(
+                    "The command {} manages games or the undo history, execute it in a session.",
+                    command.name()
+                ),
+            ))
+        }
+    }
+}
+
+// The command is executed on a copy of the game, random generator included, so the report shows
+// exactly what the command would have done.
+fn execute_preview(
+    game: &Game,
+    command: Command,
+) -> Result<Value, MyError> {
+    match command {
+        Command::SaveTheWorld(_, _, _)
+        | Command::DeleteSave(_, _)
+        | Command::RenameSave(_, _, _)
+        | Command::NewGame(_, _)
+        | Command::SelectGame(_)
+        | Command::ListGames
+        | Command::Undo
+        | Command::Redo
+        | Command::Preview(_) => {
+            Err(MyError::create_execute_command_error(
+                ErrorCode::CommandNotPreviewable,
+                format!("The command {} can not be previewed.", command.name()),
+            ))
+        }
+        command => execute_game_command(&mut game.clone(), command),
+    }
+}
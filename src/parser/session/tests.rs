@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests_int {
+    use crate::{
+        generator::game::new_testing,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        parser::{commands::Command, save_format::SaveFormat, session::Session},
+        the_world::{
+            game_registry::{lock_game, GameRegistry},
+            item_reference::ItemReference,
+        },
+    };
+
+    #[test]
+    fn test_preview_does_not_change_the_game() {
+        let game_registry = GameRegistry::new(new_testing(Some([1; 16])));
+        let mut session = Session::new(0, &game_registry, 10);
+        let original_game = lock_game(&session.selected_game.game).clone();
+
+        for command in [
+            Command::Move(0),
+            Command::ExpandPlaces,
+            Command::ReduceDifficulty,
+            Command::AddModifier(ItemReference::Index(0), Vec::new()),
+        ] {
+            let preview_result =
+                session.execute(&game_registry, Command::Preview(Box::new(command.clone())));
+            assert_eq!(original_game, *lock_game(&session.selected_game.game));
+            assert_eq!(0, session.game_history.undo_count());
+
+            let result = session.execute(&game_registry, command);
+            assert_eq!(result, preview_result);
+            *lock_game(&session.selected_game.game) = original_game.clone();
+            session.game_history.clear();
+        }
+    }
+
+    #[test]
+    fn test_preview_rejects_commands_with_side_effects() {
+        let game_registry = GameRegistry::new(new_testing(Some([1; 16])));
+        let mut session = Session::new(0, &game_registry, 10);
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::CommandNotPreviewable,
+                "The command SaveTheWorld can not be previewed.".to_string()
+            )),
+            session.execute(
+                &game_registry,
+                Command::Preview(Box::new(Command::SaveTheWorld(
+                    "save".into(),
+                    None,
+                    SaveFormat::Json
+                )))
+            )
+        );
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::CommandNotPreviewable,
+                "The command NewGame can not be previewed.".to_string()
+            )),
+            session.execute(
+                &game_registry,
+                Command::Preview(Box::new(Command::NewGame("other".into(), None)))
+            )
+        );
+    }
+
+    #[test]
+    fn test_only_commands_that_changed_the_game_can_be_undone() {
+        let game_registry = GameRegistry::new(new_testing(Some([1; 16])));
+        let mut session = Session::new(0, &game_registry, 10);
+        assert!(session
+            .execute(&game_registry, Command::SelectGame("default".into()))
+            .is_ok());
+
+        assert!(matches!(
+            session.execute(&game_registry, Command::Move(0)),
+            Err(MyError::MoveCommand {
+                code: ErrorCode::NotEnoughDamage,
+                ..
+            })
+        ));
+        assert!(session.execute(&game_registry, Command::Move(99)).is_err());
+        assert_eq!(1, session.game_history.undo_count());
+        assert!(session.execute(&game_registry, Command::Undo).is_ok());
+        assert_eq!(
+            0,
+            lock_game(&session.selected_game.game)
+                .statistics
+                .moves_count
+        );
+    }
+
+    #[test]
+    fn test_undo_after_another_session_moved() {
+        let game_registry = GameRegistry::new(new_testing(Some([1; 16])));
+        let mut session = Session::new(0, &game_registry, 10);
+        let mut other_session = Session::new(1, &game_registry, 0);
+        for session in [&mut session, &mut other_session] {
+            assert!(session
+                .execute(&game_registry, Command::SelectGame("default".into()))
+                .is_ok());
+        }
+
+        let _move_result = session.execute(&game_registry, Command::Move(0));
+        let _move_result = other_session.execute(&game_registry, Command::Move(0));
+        assert!(matches!(
+            session.execute(&game_registry, Command::Undo),
+            Err(MyError::ExecuteCommand {
+                code: ErrorCode::GameChangedByOtherSession,
+                ..
+            })
+        ));
+        assert_eq!(
+            2,
+            lock_game(&session.selected_game.game)
+                .statistics
+                .moves_count
+        );
+    }
+}
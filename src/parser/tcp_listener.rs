@@ -1,60 +1,129 @@
 use std::{
-    io::{Read, Write},
+    io::{ErrorKind, Read, Write},
     net::{TcpListener, TcpStream},
-    time::Duration,
+    panic::{self, AssertUnwindSafe},
+    process,
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
 };
 
-use serde_json::json;
+use serde_json::{json, Value};
 
 use crate::{
-    command::{
-        craft_expand_modifier::execute_craft_expand_modifiers_json,
-        craft_reroll_modifier::execute_craft_reroll_modifier_json,
-        equip_swap::{execute_equip_item_json, execute_swap_equipped_item_json},
-        expand_elements::execute_expand_elements_json,
-        expand_equipment_slots::execute_expand_equipment_slots_json,
-        expand_max_element::execute_expand_max_element_json,
-        expand_max_simultaneous_element::execute_expand_max_simultaneous_element_json,
-        expand_min_element::execute_expand_min_element_json,
-        expand_min_simultanius_element::execute_expand_min_simultaneous_element_json,
-        expand_places::execute_expand_places_json,
-        help::execute_help_json,
-        presentation_game_state::execute_presentation_game_state_json,
-        r#move::execute_move_command_json,
-        reduce_difficulty::execute_reduce_difficulty_json,
-        reorder_inventory::execute_reorder_inventory_json,
-        save_load::{execute_load_command_json, execute_save_command_json},
+    generator::game::new_with_rules,
+    my_little_rpg_errors::{ErrorCode, MyError},
+    parser::{
+        autosave::{autosave_games, load_autosaved_games, Autosave},
+        journal::Journal,
+        json_request::parse_request,
+        response::Response,
+        session::{create_command_panicked_error, Session},
+    },
+    the_world::{
+        game_registry::{lock_game, GameRegistry, DEFAULT_GAME_NAME},
+        rules::Rules,
+        save_root::SaveRoot,
     },
     Game,
-    generator::game::new,
-    my_little_rpg_errors::MyError,
-    parser::commands::Command,
 };
 
+mod tests;
+
+const READ_BUFFER_SIZE: usize = 1024;
+const READ_TIMEOUT: Duration = Duration::from_secs(1);
+const MAX_COMMAND_LENGTH: usize = 1024 * 1024;
+// Commands can be long, only their start is logged.
+const MAX_LOGGED_COMMAND_LENGTH: usize = 200;
+
 pub struct Listener {
     tcp_listener: TcpListener,
+    session_timeout: Duration,
+    history_depth: usize,
+    journal: Option<Arc<Journal>>,
+    rules: Arc<Rules>,
+    save_root: Arc<SaveRoot>,
+    autosave: Option<Arc<Autosave>>,
 }
 
 impl Listener {
-    pub fn new(port: u16) -> Self {
+    pub fn new(
+        port: u16,
+        session_timeout: Duration,
+        history_depth: usize,
+        journal: Option<Journal>,
+        rules: Rules,
+        save_root: SaveRoot,
+        autosave: Option<Autosave>,
+    ) -> Self {
         Self {
             tcp_listener: TcpListener::bind(format!("0.0.0.0:{port}")).unwrap(),
+            session_timeout,
+            history_depth,
+            journal: journal.map(Arc::new),
+            rules: Arc::new(rules),
+            save_root: Arc::new(save_root),
+            autosave: autosave.map(Arc::new),
         }
     }
 
+    pub fn local_port(&self) -> u16 {
+        self.tcp_listener.local_addr().unwrap().port()
+    }
+
+    // Resuming continues the autosaved games, a new game is only created when nothing was
+    // autosaved yet.
     pub fn listen(
         &self,
         seed: Option<[u8; 16]>,
+        resume: bool,
     ) {
-        let mut game = new(seed);
+        let game_registry = if resume {
+            self.resume_games(seed)
+        } else {
+            GameRegistry::new(self.new_game(seed))
+        };
+        if let Some(journal) = &self.journal {
+            let game = game_registry.select_default().game;
+            journal.record_start(lock_game(&game).seed, &self.rules, self.history_depth);
+        }
+        if let Some(autosave) = &self.autosave {
+            autosave.start(game_registry.clone());
+            Self::autosave_on_shutdown(game_registry.clone());
+        }
         println!(
             "Game is ready and listening on: 0.0.0.0:{}",
-            self.tcp_listener.local_addr().unwrap().port()
+            self.local_port()
         );
 
-        for stream in self.tcp_listener.incoming() {
+        for (session_id, stream) in (0..).zip(self.tcp_listener.incoming()) {
             match stream {
-                Ok(mut stream) => Listener::handle_request(&mut stream, &mut game),
+                Ok(mut stream) => {
+                    let game_registry = game_registry.clone();
+                    let session_timeout = self.session_timeout;
+                    let journal = self.journal.clone();
+                    let mut session = match &journal {
+                        Some(journal) => {
+                            journal.record_connected(session_id, &game_registry, self.history_depth)
+                        }
+                        None => Session::new(session_id, &game_registry, self.history_depth),
+                    };
+                    let autosave = self.autosave.clone();
+                    thread::spawn(move || {
+                        Self::handle_session(
+                            session_timeout,
+                            &mut session,
+                            &mut stream,
+                            &game_registry,
+                            journal.as_deref(),
+                            autosave.as_deref(),
+                        );
+                        match journal {
+                            Some(journal) => journal.record_closed(&session, &game_registry),
+                            None => game_registry.release(session.id()),
+                        }
+                    });
+                }
                 Err(e) => {
                     println!("Failed handling the request, got the following error: {e}");
                 }
@@ -62,100 +131,247 @@ impl Listener {
         }
     }
 
-    fn handle_request(
+    fn new_game(
+        &self,
+        seed: Option<[u8; 16]>,
+    ) -> Game {
+        let mut game = new_with_rules(seed, self.rules.clone());
+        game.save_root = self.save_root.clone();
+        game
+    }
+
+    fn resume_games(
+        &self,
+        seed: Option<[u8; 16]>,
+    ) -> GameRegistry {
+        let mut games =
+            load_autosaved_games(&self.save_root, &self.rules).unwrap_or_else(|error| {
+                println!("Failed resuming the autosaved games, starting a new game: {error:?}");
+                Vec::new()
+            });
+        let default_game = match games
+            .iter()
+            .position(|(name, _)| &**name == DEFAULT_GAME_NAME)
+        {
+            Some(index) => games.remove(index).1,
+            None => self.new_game(seed),
+        };
+        let game_registry = GameRegistry::new(default_game);
+        for (name, game) in games {
+            if let Err(error) = game_registry.insert(&name, game, None) {
+                println!("Failed resuming the autosaved game {name}: {error:?}");
+            }
+        }
+        println!("Resumed {} autosaved games.", game_registry.list().len());
+        game_registry
+    }
+
+    // Stopping the server with SIGINT or SIGTERM autosaves every game before exiting.
+    fn autosave_on_shutdown(game_registry: GameRegistry) {
+        let result = ctrlc::set_handler(move || {
+            println!("Autosaving every game before shutting down.");
+            let exit_code = i32::from(!autosave_games(&game_registry).is_empty());
+            process::exit(exit_code);
+        });
+        if let Err(error) = result {
+            println!("Failed setting up the autosave on shutdown: {error}");
+        }
+    }
+
+    // A session reads newline terminated commands until the client closes the connection or has
+    // been idle for longer than the session timeout. A command without a trailing newline is
+    // answered once the client closes its side of the connection, or when no newline was ever sent
+    // and the client stopped sending for the read timeout, after which the connection is closed;
+    // that keeps the one command per connection clients working. Every session runs on its
+    // own thread and starts out playing a new game of its own, with its own undo history. The games the
+    // session created or selected are released once it is closed.
+    fn handle_session(
+        session_timeout: Duration,
+        session: &mut Session,
         stream: &mut TcpStream,
-        game: &mut Game,
+        game_registry: &GameRegistry,
+        journal: Option<&Journal>,
+        autosave: Option<&Autosave>,
     ) {
-        let result = Self::read_command(stream).map(Command::try_from).and_then(|r| r).map(|command| {
-            match command {
-                Command::State => execute_presentation_game_state_json(game),
-                Command::ReduceDifficulty => execute_reduce_difficulty_json(game),
-                Command::Move(place_index) => execute_move_command_json(game, place_index),
-                Command::Equip(inventory_position, equipped_item_position) => {
-                    execute_equip_item_json(game, inventory_position, equipped_item_position)
-                }
-                Command::SwapEquipment(equipped_item_position_1, equipped_item_position_2) => {
-                    execute_swap_equipped_item_json(
-                        game,
-                        equipped_item_position_1,
-                        equipped_item_position_2,
-                    )
-                }
-                Command::RerollModifier(
-                    inventory_index,
-                    modifier_index,
-                    sacrifice_item_indexes,
-                ) => {
-                    execute_craft_reroll_modifier_json(
-                        game,
-                        inventory_index,
-                        modifier_index,
-                        sacrifice_item_indexes,
-                    )
-                }
-                Command::ExpandPlaces => execute_expand_places_json(game),
-                Command::ExpandElements => execute_expand_elements_json(game),
-                Command::ExpandMaxElement => execute_expand_max_element_json(game),
-                Command::ExpandMinElement => execute_expand_min_element_json(game),
-                Command::ExpandMaxSimultaneousElement => {
-                    execute_expand_max_simultaneous_element_json(game)
-                }
-                Command::ExpandMinSimultaneousElement => {
-                    execute_expand_min_simultaneous_element_json(game)
+        if let Err(error) = stream.set_read_timeout(Some(READ_TIMEOUT)) {
+            println!("Got error from setting timeout on reading tcp input, aborting: {error}");
+            return;
+        }
+
+        let mut pending_bytes = Vec::new();
+        let mut last_activity = Instant::now();
+        let mut newline_received = false;
+        loop {
+            let mut buffer = [0; READ_BUFFER_SIZE];
+            match stream.read(&mut buffer) {
+                Ok(0) => {
+                    if !pending_bytes.is_empty() {
+                        Self::handle_request(
+                            stream,
+                            game_registry,
+                            session,
+                            journal,
+                            autosave,
+                            &pending_bytes,
+                        );
+                    }
+                    return;
                 }
-                Command::ExpandEquipmentSlots => execute_expand_equipment_slots_json(game),
-                Command::AddModifier(place_index, sacrifice_item_indexes) => {
-                    execute_craft_expand_modifiers_json(
-                        game,
-                        place_index,
-                        sacrifice_item_indexes,
-                    )
+                Ok(buffer_size) => {
+                    last_activity = Instant::now();
+                    newline_received |= buffer[..buffer_size].contains(&b'\n');
+                    pending_bytes.extend_from_slice(&buffer[..buffer_size]);
+                    for command in split_commands(&mut pending_bytes) {
+                        if !Self::handle_request(
+                            stream,
+                            game_registry,
+                            session,
+                            journal,
+                            autosave,
+                            &command,
+                        ) {
+                            return;
+                        }
+                    }
+                    if pending_bytes.len() > MAX_COMMAND_LENGTH {
+                        let error = MyError::create_parse_command_error(
+                            ErrorCode::CommandTooLong,
+                            format!(
+                                "The command is longer than the maximum of {MAX_COMMAND_LENGTH} \
+                                 bytes, aborting."
+                            ),
+                        );
+                        Self::write_response(stream, &json!(Response::new(None, None, Err(error))));
+                        return;
+                    }
                 }
-                Command::Help => execute_help_json(),
-                Command::ReorderInventory => execute_reorder_inventory_json(game),
-                Command::SaveTheWorld(save_game_name, save_game_path) => {
-                    execute_save_command_json(game, &save_game_name, save_game_path)
+                Err(error)
+                    if matches!(error.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) =>
+                {
+                    // A client sending newlines may still be sending the rest of the command, a
+                    // client that never did sends one command per connection.
+                    if !newline_received
+                        && !pending_bytes.is_empty()
+                        && last_activity.elapsed() >= READ_TIMEOUT
+                    {
+                        Self::handle_request(
+                            stream,
+                            game_registry,
+                            session,
+                            journal,
+                            autosave,
+                            &pending_bytes,
+                        );
+                        return;
+                    }
+                    if last_activity.elapsed() >= session_timeout {
+                        println!("Closing idle session.");
+                        return;
+                    }
                 }
-                Command::LoadTheWorld(save_game_name, save_game_path) => {
-                    execute_load_command_json(game, &save_game_name, save_game_path)
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => {
+                    println!("Got error from reading command, aborting: {error}");
+                    return;
                 }
             }
-        });
+        }
+    }
 
-        let result_message = match result {
-            Ok(result) => format!("{} \n", json!(result)),
-            Err(result) => format!("{} \n", json!(result)),
+    fn handle_request(
+        stream: &mut TcpStream,
+        game_registry: &GameRegistry,
+        session: &mut Session,
+        journal: Option<&Journal>,
+        autosave: Option<&Autosave>,
+        command: &[u8],
+    ) -> bool {
+        let (id, command) = match Self::read_command(command) {
+            Ok(request) => parse_request(request),
+            Err(error) => (None, Err(error)),
+        };
+        let response = match command {
+            Ok(command) => {
+                let command_name = command.name();
+                let is_read_only = command.is_read_only();
+                // A panic only fails the command, the session and the games keep going.
+                let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                    match journal {
+                        Some(journal) => {
+                            journal.execute_and_record(session, game_registry, command)
+                        }
+                        None => session.execute(game_registry, command),
+                    }
+                }))
+                .unwrap_or_else(|_| Err(create_command_panicked_error()));
+                if let Some(autosave) = autosave.filter(|_| !is_read_only) {
+                    autosave.record_command();
+                }
+                Response::new(id, Some(command_name), result)
+            }
+            Err(error) => Response::new(id, None, Err(error)),
         };
 
-        match stream.write(result_message.as_bytes()) {
-            Ok(_) => {
+        Self::write_response(stream, &json!(response))
+    }
+
+    fn write_response(
+        stream: &mut TcpStream,
+        result: &Value,
+    ) -> bool {
+        let result_message = format!("{result} \n");
+        match stream.write_all(result_message.as_bytes()) {
+            Ok(()) => {
                 println!("Responded to request.");
+                true
             }
             Err(error) => {
-                panic!("Got the following error when writing the response to the user: {error}")
+                println!(
+                    "Got the following error when writing the response to the user, closing the \
+                     session: {error}"
+                );
+                false
             }
         }
     }
 
-    fn read_command(stream: &mut TcpStream) -> Result<Box<str>, MyError> {
-        let mut buffer = [0; 1024];
-        stream.set_read_timeout(Some(Duration::from_secs(1))).map_err(|e| {
-            MyError::create_network_error(format!(
-                "Got error from setting timeout on reading tcp input, aborting: {e}"
-            ))
-        })?;
-        let buffer_size = stream.read(&mut buffer).map_err(|e| {
-            MyError::create_network_error(format!(
-                "Got error from reading command, aborting: {e}"
-            ))
-        })?;
-        let command = &buffer[..buffer_size];
+    fn read_command(command: &[u8]) -> Result<Box<str>, MyError> {
         let command_as_string = String::from_utf8(command.to_vec()).map_err(|e| {
-            MyError::create_parse_command_error(format!(
-                "Failed parsing the command, got error: {e}"
-            ))
+            MyError::create_parse_command_error(
+                ErrorCode::InvalidEncoding,
+                format!("Failed parsing the command, got error: {e}"),
+            )
         })?;
-        println!("Received request with following command: {command_as_string}");
+        match command_as_string
+            .char_indices()
+            .nth(MAX_LOGGED_COMMAND_LENGTH)
+        {
+            Some((end, _)) => {
+                println!(
+                    "Received request with following command: {}... ({} bytes)",
+                    &command_as_string[..end],
+                    command_as_string.len()
+                );
+            }
+            None => println!("Received request with following command: {command_as_string}"),
+        }
         Ok(command_as_string.into())
     }
 }
+
+// Takes every complete, newline terminated, command out of the pending bytes. Whatever follows
+// the last newline is left behind until more bytes arrive. Blank lines are skipped.
+fn split_commands(pending_bytes: &mut Vec<u8>) -> Vec<Vec<u8>> {
+    let mut commands = Vec::new();
+    while let Some(newline_index) = pending_bytes.iter().position(|byte| *byte == b'\n') {
+        let mut command: Vec<u8> = pending_bytes.drain(..=newline_index).collect();
+        command.pop();
+        if command.last() == Some(&b'\r') {
+            command.pop();
+        }
+        if !command.iter().all(u8::is_ascii_whitespace) {
+            commands.push(command);
+        }
+    }
+    commands
+}
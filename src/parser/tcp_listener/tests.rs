@@ -0,0 +1,211 @@
+#[cfg(test)]
+mod tests_int {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::{Shutdown, TcpStream},
+        thread,
+        time::Duration,
+    };
+
+    use serde_json::Value;
+
+    use crate::{
+        parser::tcp_listener::{split_commands, Listener},
+        the_world::{rules::Rules, save_root::SaveRoot},
+    };
+
+    fn start_listener() -> u16 {
+        let listener = Listener::new(
+            0,
+            Duration::from_secs(5),
+            10,
+            None,
+            Rules::default(),
+            SaveRoot::default(),
+            None,
+        );
+        let port = listener.local_port();
+        thread::spawn(move || listener.listen(Some([1; 16]), false));
+        port
+    }
+
+    #[test]
+    fn test_split_commands() {
+        let mut pending_bytes = b"State\r\n\nMove 0\nHel".to_vec();
+
+        assert_eq!(
+            vec![b"State".to_vec(), b"Move 0".to_vec()],
+            split_commands(&mut pending_bytes)
+        );
+        assert_eq!(b"Hel".to_vec(), pending_bytes);
+
+        pending_bytes.extend_from_slice(b"p\n");
+        assert_eq!(vec![b"Help".to_vec()], split_commands(&mut pending_bytes));
+        assert!(pending_bytes.is_empty());
+    }
+
+    #[test]
+    fn test_session_many_commands_one_connection() {
+        let port = start_listener();
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        stream.write_all(b"State\nMove 0\nMove 0\nState\n").unwrap();
+
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        for _i in 0..4 {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            assert!(serde_json::from_str::<Value>(&line).is_ok());
+        }
+
+        stream.shutdown(Shutdown::Write).unwrap();
+        let mut rest = String::new();
+        reader.read_to_string(&mut rest).unwrap();
+        assert_eq!("", rest);
+    }
+
+    #[test]
+    fn test_session_command_longer_than_read_buffer() {
+        let port = start_listener();
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        let sacrifices = vec!["1"; 2000].join(",");
+        stream
+            .write_all(format!("AddModifier 0 {sacrifices}\n").as_bytes())
+            .unwrap();
+
+        let mut line = String::new();
+        BufReader::new(stream).read_line(&mut line).unwrap();
+        let response = serde_json::from_str::<Value>(&line).unwrap();
+        assert_eq!("AddModifier", response["command"]);
+    }
+
+    #[test]
+    fn test_session_unterminated_command() {
+        let port = start_listener();
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        stream.write_all(b"Help").unwrap();
+        stream.shutdown(Shutdown::Write).unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let response = serde_json::from_str::<Value>(&response).unwrap();
+        assert_eq!("Help", response["command"]);
+        assert!(response["result"].get("tutorial").is_some());
+    }
+
+    #[test]
+    fn test_session_waits_for_the_rest_of_a_slow_command() {
+        let port = start_listener();
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        stream.write_all(b"State\nMo").unwrap();
+        thread::sleep(Duration::from_millis(1500));
+        stream.write_all(b"ve 0\n").unwrap();
+
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        line.clear();
+        reader.read_line(&mut line).unwrap();
+        let response = serde_json::from_str::<Value>(&line).unwrap();
+        assert_eq!("Move", response["command"]);
+        assert_eq!("ok", response["status"]);
+    }
+
+    // Like netcat without -N, the client neither sends a newline nor closes its side.
+    #[test]
+    fn test_session_answers_a_single_unterminated_command() {
+        let port = start_listener();
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        stream.write_all(b"Help").unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let response = serde_json::from_str::<Value>(&response).unwrap();
+        assert_eq!("ok", response["status"]);
+        assert!(response["result"].get("tutorial").is_some());
+    }
+
+    #[test]
+    fn test_sessions_run_concurrently_on_their_own_games() {
+        let port = start_listener();
+        let mut idle_stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        idle_stream.write_all(b"NewGame idle\n").unwrap();
+        let mut idle_reader = BufReader::new(idle_stream.try_clone().unwrap());
+        let mut line = String::new();
+        idle_reader.read_line(&mut line).unwrap();
+
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        stream.write_all(b"Move 0\nListGames\n").unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+
+        let games = serde_json::from_str::<Value>(&line).unwrap();
+        assert_eq!(
+            serde_json::json!([
+                {"name": "default", "seed": "01010101010101010101010101010101", "moves_count": 0, "selected": false},
+                {"name": "idle", "seed": games["result"]["games"][1]["seed"], "moves_count": 0, "selected": false},
+                {"name": "session-0", "seed": "01010101010101010101010101010101", "moves_count": 0, "selected": false},
+                {"name": "session-1", "seed": "01010101010101010101010101010101", "moves_count": 1, "selected": true}
+            ]),
+            games["result"]["games"]
+        );
+    }
+
+    // The stream is closed by the server after the session released its games.
+    fn execute_in_own_connection(
+        port: u16,
+        commands: &[u8],
+    ) -> String {
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        stream.write_all(commands).unwrap();
+        stream.shutdown(Shutdown::Write).unwrap();
+        let mut responses = String::new();
+        stream.read_to_string(&mut responses).unwrap();
+        responses
+    }
+
+    #[test]
+    fn test_connections_keep_only_the_games_they_changed() {
+        let port = start_listener();
+        execute_in_own_connection(port, b"Move 0\n");
+        execute_in_own_connection(port, b"State\n");
+        execute_in_own_connection(port, b"SelectGame default\nMove 0\n");
+
+        let games = execute_in_own_connection(port, b"ListGames\n");
+        let games = serde_json::from_str::<Value>(&games).unwrap();
+        assert_eq!(
+            serde_json::json!([
+                {"name": "default", "seed": "01010101010101010101010101010101", "moves_count": 1, "selected": false},
+                {"name": "session-0", "seed": "01010101010101010101010101010101", "moves_count": 1, "selected": false},
+                {"name": "session-3", "seed": "01010101010101010101010101010101", "moves_count": 0, "selected": true}
+            ]),
+            games["result"]["games"]
+        );
+    }
+
+    #[test]
+    fn test_session_undo_redo() {
+        let port = start_listener();
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        stream
+            .write_all(
+                b"SelectGame default\nMove 0\nMove 0\nUndo\nListGames\nRedo\nUndo\nUndo\nUndo\n\
+                  ListGames\n",
+            )
+            .unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut responses = Vec::new();
+        for _i in 0..10 {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            responses.push(serde_json::from_str::<Value>(&line).unwrap());
+        }
+
+        assert_eq!(1, responses[4]["result"]["games"][0]["moves_count"]);
+        assert_eq!("ok", responses[5]["status"]);
+        assert_eq!("NOTHING_TO_UNDO", responses[8]["error"]["code"]);
+        assert_eq!(0, responses[9]["result"]["games"][0]["moves_count"]);
+    }
+}
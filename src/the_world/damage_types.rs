@@ -10,7 +10,7 @@ use rand_pcg::Lcg64Xsh32;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    my_little_rpg_errors::MyError,
+    my_little_rpg_errors::{ErrorCode, MyError},
     the_world::damage_types::DamageType::{
         Corruption, Darkness, Fire, Frost, Holy, Light, Lightning, Nature, Physical,
     },
@@ -66,6 +66,7 @@ fn get_random_attack_type(
     attack_values
         .choose(random_generator_state)
         .ok_or(MyError::create_execute_command_error(
+            ErrorCode::NoElementAvailable,
             "The given Hashmap is empty!".to_string(),
         ))
         .map(|&damage_type| damage_type.clone())
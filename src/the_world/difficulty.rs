@@ -11,3 +11,21 @@ pub struct Difficulty {
     pub(crate) max_simultaneous_resistances: u8,
     pub(crate) min_simultaneous_resistances: u8,
 }
+
+impl Difficulty {
+    pub fn max_resistance(&self) -> &HashMap<damage_types::DamageType, u64> {
+        &self.max_resistance
+    }
+
+    pub fn min_resistance(&self) -> &HashMap<damage_types::DamageType, u64> {
+        &self.min_resistance
+    }
+
+    pub fn max_simultaneous_resistances(&self) -> u8 {
+        self.max_simultaneous_resistances
+    }
+
+    pub fn min_simultaneous_resistances(&self) -> u8 {
+        self.min_simultaneous_resistances
+    }
+}
@@ -1,11 +1,23 @@
-use std::collections::HashMap;
+use std::{cmp::max, collections::HashMap, sync::Arc};
 
-use rand_pcg::Lcg64Xsh32;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::the_world::{
-    difficulty::Difficulty, game_statistics::GameStatistics, item::Item, item_resource::Type,
-    place::Place, treasure_types::TreasureType,
+use crate::{
+    command::{
+        commands::Command,
+        presentation_game_state::{
+            execute as execute_presentation_game_state, PresentationGameState,
+        },
+    },
+    generator::game::new_with_rules,
+    my_little_rpg_errors::MyError,
+    parser::session::execute_game_command,
+    the_world::{
+        difficulty::Difficulty, game_history::Generation, game_statistics::GameStatistics,
+        item::Item, item_resource::Type, place::Place, random_generators::RandomGenerators,
+        rules::Rules, save_root::SaveRoot, stash::Stash, treasure_types::TreasureType,
+    },
 };
 
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
@@ -13,10 +25,147 @@ pub struct Game {
     pub(crate) places: Vec<Place>,
     pub(crate) equipped_items: Vec<Item>,
     pub(crate) inventory: Vec<Option<Item>>,
+    #[serde(default)]
+    pub(crate) stash: Stash,
     pub(crate) difficulty: Difficulty,
     pub(crate) treasure: HashMap<TreasureType, u64>,
     pub(crate) item_resources: HashMap<Type, u64>,
     pub(crate) seed: [u8; 16],
-    pub(crate) random_generator_state: Lcg64Xsh32,
+    pub(crate) random_generators: RandomGenerators,
     pub(crate) statistics: GameStatistics,
+    #[serde(default)]
+    pub(crate) next_item_id: u64,
+    // The rules belong to the server and not to the save game, a loaded game keeps playing by the
+    // rules of the game it replaces.
+    #[serde(skip)]
+    pub(crate) rules: Arc<Rules>,
+    // Like the rules the save games folder belongs to the server.
+    #[serde(skip)]
+    pub(crate) save_root: Arc<SaveRoot>,
+    // How often the game was changed since it was created or loaded by the server.
+    #[serde(skip)]
+    pub(crate) generation: Generation,
+}
+
+mod tests;
+
+// The facade for using the game as a library. The fields stay private to the crate, so the rules
+// can only be changed through commands.
+impl Game {
+    /// Creates a new game. The same seed always creates the same game and the same rolls.
+    pub fn new(seed: Option<[u8; 16]>) -> Self {
+        new_with_rules(seed, Arc::default())
+    }
+
+    /// Creates a new game played by the given rules instead of the default ones.
+    pub fn new_with_rules(
+        seed: Option<[u8; 16]>,
+        rules: Rules,
+    ) -> Self {
+        new_with_rules(seed, Arc::new(rules))
+    }
+
+    /// Executes a command on the game and returns the same result the server sends.
+    ///
+    /// Commands that need a session, like `NewGame`, `SelectGame`, `ListGames`, `Undo` and `Redo`,
+    /// fail with `ErrorCode::CommandNeedsSession`.
+    pub fn execute(
+        &mut self,
+        command: Command,
+    ) -> Result<Value, MyError> {
+        execute_game_command(self, command)
+    }
+
+    /// The state of the game as shown by the `State` command, including the costs of crafting.
+    pub fn state(&self) -> PresentationGameState {
+        execute_presentation_game_state(self)
+    }
+
+    pub fn seed(&self) -> [u8; 16] {
+        self.seed
+    }
+
+    pub fn places(&self) -> &[Place] {
+        &self.places
+    }
+
+    pub fn equipped_items(&self) -> &[Item] {
+        &self.equipped_items
+    }
+
+    /// The inventory keeps empty slots, so the indexes stay the same as in the commands.
+    pub fn inventory(&self) -> &[Option<Item>] {
+        &self.inventory
+    }
+
+    /// The items kept out of the inventory, by the name of their stash tab.
+    pub fn stash(&self) -> &Stash {
+        &self.stash
+    }
+
+    pub fn difficulty(&self) -> &Difficulty {
+        &self.difficulty
+    }
+
+    pub fn treasure(&self) -> &HashMap<TreasureType, u64> {
+        &self.treasure
+    }
+
+    pub fn item_resources(&self) -> &HashMap<Type, u64> {
+        &self.item_resources
+    }
+
+    pub fn statistics(&self) -> &GameStatistics {
+        &self.statistics
+    }
+
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    /// The folder the save game commands read and write in.
+    pub fn save_root(&self) -> &SaveRoot {
+        &self.save_root
+    }
+
+    pub fn set_save_root(
+        &mut self,
+        save_root: SaveRoot,
+    ) {
+        self.save_root = Arc::new(save_root);
+    }
+
+    pub(crate) fn create_item_id(&mut self) -> u64 {
+        self.next_item_id = max(self.next_item_id, 1);
+        let id = self.next_item_id;
+        self.next_item_id += 1;
+        id
+    }
+
+    // Gives every item without an id one, for new games and for save games from before item ids.
+    pub(crate) fn assign_missing_item_ids(&mut self) {
+        let highest_id = self.items().map(|item| item.id).max().unwrap_or(0);
+        self.next_item_id = max(self.next_item_id, highest_id + 1);
+
+        let mut next_item_id = self.next_item_id;
+        for item in self
+            .equipped_items
+            .iter_mut()
+            .chain(self.inventory.iter_mut().flatten())
+            .chain(self.stash.tabs.values_mut().flatten())
+            .filter(|item| item.id == 0)
+        {
+            item.id = next_item_id;
+            next_item_id += 1;
+        }
+        self.next_item_id = next_item_id;
+    }
+
+    // Every item of the game, wherever it is kept.
+    pub(crate) fn items(&self) -> impl Iterator<Item = &Item> {
+        self.equipped_items
+            .iter()
+            .chain(self.inventory.iter().flatten())
+            .chain(self.stash.tabs.values().flatten())
+    }
 }
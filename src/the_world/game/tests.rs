@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests_int {
+    use serde_json::json;
+
+    use crate::{
+        command::{commands::Command, r#move::execute_move_command_json},
+        generator::game::new,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        the_world::item::test_util::create_item,
+        Game,
+    };
+
+    #[test]
+    fn test_new_is_deterministic() {
+        assert_eq!(Game::new(Some([1; 16])), Game::new(Some([1; 16])));
+        assert_eq!(new(Some([1; 16])), Game::new(Some([1; 16])));
+        assert_eq!([1; 16], Game::new(Some([1; 16])).seed());
+    }
+
+    #[test]
+    fn test_execute_is_the_same_as_the_command() {
+        let mut game = Game::new(Some([1; 16]));
+        let mut expected_game = game.clone();
+
+        let result = game.execute(Command::Move(0));
+
+        assert_eq!(execute_move_command_json(&mut expected_game, 0), result);
+        assert_eq!(expected_game, game);
+        assert_eq!(1, game.statistics().moves_count());
+        assert_eq!(json!(game.state()), game.execute(Command::State).unwrap());
+    }
+
+    #[test]
+    fn test_execute_needs_session() {
+        let mut game = Game::new(Some([1; 16]));
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::CommandNeedsSession,
+                "The command Undo manages games or the undo history, execute it in a session."
+                    .to_string()
+            )),
+            game.execute(Command::Undo)
+        );
+        assert_eq!(Game::new(Some([1; 16])), game);
+    }
+
+    #[test]
+    fn test_item_ids() {
+        let mut game = new(Some([1; 16]));
+        assert_eq!(1, game.equipped_items()[0].id());
+
+        game.equipped_items[0].id = 0;
+        game.inventory.push(Some(create_item(&game)));
+        game.assign_missing_item_ids();
+
+        assert_eq!(2, game.equipped_items()[0].id());
+        assert_eq!(Some(3), game.inventory()[0].as_ref().map(|item| item.id()));
+        assert_eq!(4, game.create_item_id());
+    }
+}
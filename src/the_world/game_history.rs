@@ -0,0 +1,138 @@
+use std::{collections::VecDeque, mem};
+
+use crate::Game;
+
+// Snapshots of a game from before each command that changed it, so a session can undo and redo
+// those commands. The oldest snapshots are dropped once there are more than max_depth of them.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GameHistory {
+    max_depth: usize,
+    undo_stack: VecDeque<Game>,
+    redo_stack: Vec<Game>,
+    // The generation of the game as this session left it. Games are shared between sessions, when
+    // the generation is different now another session changed it, and going back to a snapshot
+    // would throw those changes away.
+    last_generation: Option<u64>,
+}
+
+// Counts the changes of a game, so a session can tell whether another session changed the game
+// since its last command without keeping a copy of it. The generation is not part of the game
+// itself, games that only differ in their generation are equal.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct Generation(u64);
+
+impl PartialEq for Generation {
+    fn eq(
+        &self,
+        _other: &Self,
+    ) -> bool {
+        true
+    }
+}
+
+impl Eq for Generation {}
+
+impl Generation {
+    pub fn is_changed(self) -> bool {
+        self.0 != 0
+    }
+
+    fn next(self) -> Self {
+        Self(self.0 + 1)
+    }
+}
+
+impl GameHistory {
+    pub fn new(max_depth: usize) -> Self {
+        Self {
+            max_depth,
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            last_generation: None,
+        }
+    }
+
+    // Records that a command changed the game. Without history only the generation moves on.
+    pub fn record(
+        &mut self,
+        game_before: Game,
+        game_after: &mut Game,
+    ) {
+        game_after.generation = game_before.generation.next();
+        if self.max_depth == 0 {
+            return;
+        }
+        // The older snapshots are from before the changes of another session.
+        if self.is_changed_by_other_session(&game_before) {
+            self.clear();
+        }
+        self.undo_stack.push_back(game_before);
+        if self.undo_stack.len() > self.max_depth {
+            self.undo_stack.pop_front();
+        }
+        self.redo_stack.clear();
+        self.last_generation = Some(game_after.generation.0);
+    }
+
+    pub fn is_changed_by_other_session(
+        &self,
+        game: &Game,
+    ) -> bool {
+        self.last_generation
+            .is_some_and(|last_generation| last_generation != game.generation.0)
+    }
+
+    pub fn undo(
+        &mut self,
+        game: &mut Game,
+    ) -> bool {
+        match self.undo_stack.pop_back() {
+            None => false,
+            Some(previous_game) => {
+                let generation = game.generation.next();
+                self.redo_stack.push(mem::replace(game, previous_game));
+                self.restored(game, generation);
+                true
+            }
+        }
+    }
+
+    pub fn redo(
+        &mut self,
+        game: &mut Game,
+    ) -> bool {
+        match self.redo_stack.pop() {
+            None => false,
+            Some(next_game) => {
+                let generation = game.generation.next();
+                self.undo_stack.push_back(mem::replace(game, next_game));
+                self.restored(game, generation);
+                true
+            }
+        }
+    }
+
+    // A restored snapshot is a change of the game like any other, so it gets the next generation.
+    fn restored(
+        &mut self,
+        game: &mut Game,
+        generation: Generation,
+    ) {
+        game.generation = generation;
+        self.last_generation = Some(generation.0);
+    }
+
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.last_generation = None;
+    }
+
+    pub fn undo_count(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    pub fn redo_count(&self) -> usize {
+        self.redo_stack.len()
+    }
+}
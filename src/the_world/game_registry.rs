@@ -0,0 +1,233 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use crate::{
+    generator::game::new_with_rules,
+    my_little_rpg_errors::{ErrorCode, MyError},
+    the_world::{
+        rules::Rules,
+        save_root::{is_save_name, SaveRoot},
+    },
+    Game,
+};
+
+pub type SharedGame = Arc<Mutex<Game>>;
+
+pub const DEFAULT_GAME_NAME: &str = "default";
+
+// The games sessions start out playing are named after the session, clients can not create games
+// with these names.
+pub const SESSION_GAME_PREFIX: &str = "session-";
+
+// All games the server knows about. The default game is shared between every connection that
+// selects it, any other game belongs to the session that created or selected it until that session
+// is closed. New games are played by the same rules, and save in the same folder, as the default
+// game.
+#[derive(Clone)]
+pub struct GameRegistry {
+    games: Arc<Mutex<HashMap<Box<str>, RegisteredGame>>>,
+    rules: Arc<Rules>,
+    save_root: Arc<SaveRoot>,
+    // The seed of the default game, every session starts out playing a new game from it.
+    seed: [u8; 16],
+}
+
+struct RegisteredGame {
+    game: SharedGame,
+    owner: Option<u64>,
+    // Created for a session when it connected, removed again when the session never changed it.
+    session_game: bool,
+}
+
+// The game a single connection is currently playing.
+pub struct SelectedGame {
+    pub(crate) name: Box<str>,
+    pub(crate) game: SharedGame,
+}
+
+impl GameRegistry {
+    pub fn new(default_game: Game) -> Self {
+        let rules = default_game.rules.clone();
+        let save_root = default_game.save_root.clone();
+        let seed = default_game.seed;
+        let games = HashMap::from([(
+            Box::from(DEFAULT_GAME_NAME),
+            RegisteredGame {
+                game: Arc::new(Mutex::new(default_game)),
+                owner: None,
+                session_game: false,
+            },
+        )]);
+        Self {
+            games: Arc::new(Mutex::new(games)),
+            rules,
+            save_root,
+            seed,
+        }
+    }
+
+    pub fn rules(&self) -> Arc<Rules> {
+        self.rules.clone()
+    }
+
+    pub fn save_root(&self) -> Arc<SaveRoot> {
+        self.save_root.clone()
+    }
+
+    pub fn select_default(&self) -> SelectedGame {
+        SelectedGame {
+            name: DEFAULT_GAME_NAME.into(),
+            game: self
+                .get(DEFAULT_GAME_NAME)
+                .expect("The default game is created together with the registry."),
+        }
+    }
+
+    // The session owns its game from the start. A game resumed from the autosaves can have the name
+    // already, the name then gets a number.
+    pub fn insert_session_game(
+        &self,
+        session: u64,
+    ) -> SelectedGame {
+        let mut game = new_with_rules(Some(self.seed), self.rules.clone());
+        game.save_root = self.save_root.clone();
+        let mut games = self.lock_games();
+        let name: Box<str> = (1..)
+            .map(|number| {
+                match number {
+                    1 => format!("{SESSION_GAME_PREFIX}{session}"),
+                    number => format!("{SESSION_GAME_PREFIX}{session}-{number}"),
+                }
+            })
+            .find(|name| !games.contains_key(name.as_str()))
+            .expect("There are more numbers than games.")
+            .into();
+        let game = Arc::new(Mutex::new(game));
+        games.insert(
+            name.clone(),
+            RegisteredGame {
+                game: game.clone(),
+                owner: Some(session),
+                session_game: true,
+            },
+        );
+        SelectedGame { name, game }
+    }
+
+    // A game without an owner belongs to the first session selecting it. Games are autosaved under
+    // their name, so only names that are valid save game names are allowed.
+    pub fn insert(
+        &self,
+        name: &str,
+        game: Game,
+        owner: Option<u64>,
+    ) -> Result<SharedGame, MyError> {
+        if !is_save_name(name) {
+            return Err(MyError::create_execute_command_error(
+                ErrorCode::InvalidGameName,
+                format!(
+                    "The game name {name:?} can only contain letters, digits, -, _ and . and can \
+                     not start with a ."
+                ),
+            ));
+        }
+        let mut games = self.lock_games();
+        if games.contains_key(name) {
+            return Err(MyError::create_execute_command_error(
+                ErrorCode::GameAlreadyExists,
+                format!("A game with the name {name} already exists."),
+            ));
+        }
+        let game = Arc::new(Mutex::new(game));
+        games.insert(
+            name.into(),
+            RegisteredGame {
+                game: game.clone(),
+                owner,
+                session_game: false,
+            },
+        );
+        Ok(game)
+    }
+
+    // Selecting a game makes the session its owner, unless it is the default game.
+    pub fn select(
+        &self,
+        name: &str,
+        session: u64,
+    ) -> Result<SharedGame, MyError> {
+        let mut games = self.lock_games();
+        let registered_game = games
+            .get_mut(name)
+            .ok_or_else(|| create_game_not_found_error(name))?;
+        match registered_game.owner {
+            Some(owner) if owner != session => {
+                Err(MyError::create_execute_command_error(
+                    ErrorCode::GameOwnedByOtherSession,
+                    format!("The game {name} is played by another session."),
+                ))
+            }
+            _ => {
+                if name != DEFAULT_GAME_NAME {
+                    registered_game.owner = Some(session);
+                }
+                Ok(registered_game.game.clone())
+            }
+        }
+    }
+
+    // The games of a closed session can be selected by everybody again. The game the session started
+    // out with is removed when it was never changed, so connections that only look around leave
+    // nothing behind.
+    pub fn release(
+        &self,
+        session: u64,
+    ) {
+        self.lock_games().retain(|_, registered_game| {
+            if registered_game.owner != Some(session) {
+                return true;
+            }
+            registered_game.owner = None;
+            !registered_game.session_game
+                || lock_game(&registered_game.game).generation.is_changed()
+        });
+    }
+
+    pub fn get(
+        &self,
+        name: &str,
+    ) -> Result<SharedGame, MyError> {
+        self.lock_games()
+            .get(name)
+            .map(|registered_game| registered_game.game.clone())
+            .ok_or_else(|| create_game_not_found_error(name))
+    }
+
+    pub fn list(&self) -> Vec<(Box<str>, SharedGame)> {
+        let mut games: Vec<(Box<str>, SharedGame)> = self
+            .lock_games()
+            .iter()
+            .map(|(name, registered_game)| (name.clone(), registered_game.game.clone()))
+            .collect();
+        games.sort_by(|(a_name, _), (b_name, _)| a_name.cmp(b_name));
+        games
+    }
+
+    fn lock_games(&self) -> MutexGuard<'_, HashMap<Box<str>, RegisteredGame>> {
+        self.games.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+fn create_game_not_found_error(name: &str) -> MyError {
+    MyError::create_execute_command_error(
+        ErrorCode::GameNotFound,
+        format!("There is no game with the name {name}."),
+    )
+}
+
+// A panic while a game was locked should not make the game unreachable for everybody else.
+pub fn lock_game(game: &SharedGame) -> MutexGuard<'_, Game> {
+    game.lock().unwrap_or_else(PoisonError::into_inner)
+}
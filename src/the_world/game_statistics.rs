@@ -8,3 +8,25 @@ pub struct GameStatistics {
     pub(crate) wins_in_a_row: u64,
     pub(crate) loses_in_a_row: u64,
 }
+
+impl GameStatistics {
+    pub fn moves_count(&self) -> u64 {
+        self.moves_count
+    }
+
+    pub fn wins(&self) -> u64 {
+        self.wins
+    }
+
+    pub fn loses(&self) -> u64 {
+        self.loses
+    }
+
+    pub fn wins_in_a_row(&self) -> u64 {
+        self.wins_in_a_row
+    }
+
+    pub fn loses_in_a_row(&self) -> u64 {
+        self.loses_in_a_row
+    }
+}
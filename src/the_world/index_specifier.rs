@@ -2,18 +2,94 @@ use std::collections::HashSet;
 
 use serde::{Deserialize, Serialize};
 
-use crate::{my_little_rpg_errors::MyError, the_world::item::Item, Game};
+use crate::{
+    my_little_rpg_errors::{ErrorCode, MyError},
+    the_world::{item::Item, item_query::ItemQuery, item_reference::ItemReference},
+    Game,
+};
 
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Eq, Hash)]
 pub enum IndexSpecifier {
     Absolute(usize),
     RelativePositive(usize),
     RelativeNegative(usize),
+    Query(ItemQuery),
+    Id(u64),
+    Name(Box<str>),
+}
+
+impl IndexSpecifier {
+    fn item_reference(&self) -> Option<ItemReference> {
+        match self {
+            IndexSpecifier::Id(id) => Some(ItemReference::Id(*id)),
+            IndexSpecifier::Name(name) => Some(ItemReference::Name(name.clone())),
+            _ => None,
+        }
+    }
 }
 
 type ErrorCondition = dyn Fn(usize, &Item) -> Option<MyError>;
 pub(crate) type ErrorConditions = Vec<Box<ErrorCondition>>;
 
+// Replaces every query with the absolute indexes of all the inventory items it matches, so a query
+// offers every matching item as a sacrifice and the crafting cost decides how many are used. The
+// crafted item, locked items, items that are given by any other index specifier and items that fail
+// the error conditions are left out. Relative indexes are resolved in order, like they are when the
+// sacrifices are calculated.
+pub fn expand_item_queries(
+    game: &Game,
+    inventory_index: usize,
+    index_specifiers: Vec<IndexSpecifier>,
+    error_conditions: &ErrorConditions,
+) -> Vec<IndexSpecifier> {
+    let mut selected_indexes = HashSet::new();
+    for index_specifier in &index_specifiers {
+        let index = match index_specifier {
+            IndexSpecifier::Absolute(index) => Some(*index),
+            IndexSpecifier::Id(_) | IndexSpecifier::Name(_) => {
+                referenced_index(game, index_specifier).ok()
+            }
+            IndexSpecifier::RelativePositive(_) | IndexSpecifier::RelativeNegative(_) => {
+                get_index(
+                    game,
+                    inventory_index,
+                    &selected_indexes,
+                    index_specifier,
+                    error_conditions,
+                )
+                .ok()
+            }
+            IndexSpecifier::Query(_) => None,
+        };
+        selected_indexes.extend(index);
+    }
+    let mut excluded_indexes = selected_indexes;
+    excluded_indexes.insert(inventory_index);
+
+    let mut expanded_index_specifiers = Vec::new();
+    for index_specifier in index_specifiers {
+        let IndexSpecifier::Query(query) = index_specifier else {
+            expanded_index_specifiers.push(index_specifier);
+            continue;
+        };
+        for (index, item) in game
+            .inventory
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| unlocked_item(item).map(|item| (index, item)))
+        {
+            if !excluded_indexes.contains(&index)
+                && query.matches(item)
+                && handle_conditions(index, error_conditions, item).is_ok()
+            {
+                excluded_indexes.insert(index);
+                expanded_index_specifiers.push(IndexSpecifier::Absolute(index));
+            }
+        }
+    }
+    expanded_index_specifiers
+}
+
 //TODO moving this
 pub fn calculate_absolute_item_indexes(
     game: &Game,
@@ -73,9 +149,39 @@ fn get_index(
                 error_conditions,
             )
         }
+        IndexSpecifier::Query(query) => {
+            get_query_index(
+                game,
+                inventory_index,
+                calculated_selected_item_indexes,
+                index_specifier,
+                query,
+                error_conditions,
+            )
+        }
+        IndexSpecifier::Id(_) | IndexSpecifier::Name(_) => {
+            get_absolute_index(
+                game,
+                inventory_index,
+                calculated_selected_item_indexes,
+                index_specifier,
+                referenced_index(game, index_specifier)?,
+                error_conditions,
+            )
+        }
     }
 }
 
+fn referenced_index(
+    game: &Game,
+    index_specifier: &IndexSpecifier,
+) -> Result<usize, MyError> {
+    index_specifier
+        .item_reference()
+        .expect("Only ids and names are referenced.")
+        .resolve_inventory(game)
+}
+
 fn get_absolute_index(
     game: &Game,
     inventory_index: usize,
@@ -85,33 +191,58 @@ fn get_absolute_index(
     error_conditions: &ErrorConditions,
 ) -> Result<usize, MyError> {
     if inventory_index == candidate_index {
-        return Err(MyError::create_execute_command_error(format!(
-            "inventory_index {inventory_index} and index_specifier {index_specifier:?} cannot be \
-             the same"
-        )));
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::SacrificeIsCraftedItem,
+            format!(
+                "inventory_index {inventory_index} and index_specifier {index_specifier:?} cannot \
+                 be the same"
+            ),
+        ));
     }
     if game.inventory.len() <= candidate_index {
-        return Err(MyError::create_execute_command_error(format!(
-            "index_specifier {:?} is not within the range of the inventory {}",
-            index_specifier,
-            game.inventory.len()
-        )));
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::InventoryIndexOutOfRange,
+            format!(
+                "index_specifier {:?} is not within the range of the inventory {}",
+                index_specifier,
+                game.inventory.len()
+            ),
+        ));
     }
     let candidate_item = game.inventory[candidate_index].as_ref().ok_or_else(|| {
-        MyError::create_execute_command_error(format!(
-            "index_specifier {index_specifier:?} is pointing at empty inventory slot."
-        ))
+        MyError::create_execute_command_error(
+            ErrorCode::InventorySlotEmpty,
+            format!("index_specifier {index_specifier:?} is pointing at empty inventory slot."),
+        )
     })?;
+    if candidate_item.locked {
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::ItemLocked,
+            format!(
+                "index_specifier {index_specifier:?} is pointing at a locked item, unlock it to \
+                 sacrifice it."
+            ),
+        ));
+    }
     if calculated_selected_item_indexes.contains(&candidate_index) {
-        return Err(MyError::create_execute_command_error(format!(
-            "index_specifier {index_specifier:?} is already present in calculated sacrifice \
-             indexes {calculated_selected_item_indexes:?}"
-        )));
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::SacrificeAlreadySelected,
+            format!(
+                "index_specifier {index_specifier:?} is already present in calculated sacrifice \
+                 indexes {calculated_selected_item_indexes:?}"
+            ),
+        ));
     };
     handle_conditions(candidate_index, error_conditions, candidate_item)?;
     Ok(candidate_index)
 }
 
+// Locked items are never found by relative indexes or queries, they can only be given by index to
+// get an error.
+fn unlocked_item(item: &Option<Item>) -> Option<&Item> {
+    item.as_ref().filter(|item| !item.locked)
+}
+
 fn handle_conditions(
     candidate_index: usize,
     error_conditions: &ErrorConditions,
@@ -139,14 +270,19 @@ fn get_relative_positive_index(
         .iter()
         .enumerate()
         .skip(start_index)
-        .filter_map(|(index, item)| item.as_ref().map(|unwrapped_item| (index, unwrapped_item)))
+        .filter_map(|(index, item)| {
+            unlocked_item(item).map(|unwrapped_item| (index, unwrapped_item))
+        })
         .find(|(i, _)| !calculated_selected_item_indexes.contains(i))
         .map(|(index, item)| handle_conditions(index, error_conditions, item))
         .ok_or_else(|| {
-            MyError::create_execute_command_error(format!(
-                "index_specifier: {index_specifier:?} did not find any items in inventory from relative point {start_index} \
-                 until end of inventory."
-            ))
+            MyError::create_execute_command_error(
+                ErrorCode::NoSacrificeFound,
+                format!(
+                    "index_specifier: {index_specifier:?} did not find any items in inventory \
+                     from relative point {start_index} until end of inventory."
+                ),
+            )
         })?
 }
 
@@ -158,18 +294,62 @@ fn get_relative_negative_index(
     relative_index: usize,
     error_conditions: &ErrorConditions,
 ) -> Result<usize, MyError> {
-    let start_index = inventory_index - relative_index;
+    let start_index = inventory_index.checked_sub(relative_index).ok_or_else(|| {
+        MyError::create_execute_command_error(
+            ErrorCode::InvalidIndexSpecifier,
+            format!(
+                "index_specifier: {index_specifier:?} is before the start of the inventory from \
+                 inventory_index {inventory_index}."
+            ),
+        )
+    })?;
     game.inventory[..=start_index]
         .iter()
         .enumerate()
         .rev()
-        .filter_map(|(index, item)| item.as_ref().map(|unwrapped_item| (index, unwrapped_item)))
+        .filter_map(|(index, item)| {
+            unlocked_item(item).map(|unwrapped_item| (index, unwrapped_item))
+        })
         .find(|(i, _)| !calculated_selected_item_indexes.contains(i))
         .map(|(index, item)| handle_conditions(index, error_conditions, item))
         .ok_or_else(|| {
-            MyError::create_execute_command_error(format!(
-                "index_specifier: {index_specifier:?} did not find any items in inventory from relative point {start_index} \
-                 until start of inventory."
-            ))
+            MyError::create_execute_command_error(
+                ErrorCode::NoSacrificeFound,
+                format!(
+                    "index_specifier: {index_specifier:?} did not find any items in inventory \
+                     from relative point {start_index} until start of inventory."
+                ),
+            )
+        })?
+}
+
+// Without expanding the queries first, a query selects the first matching item.
+fn get_query_index(
+    game: &Game,
+    inventory_index: usize,
+    calculated_selected_item_indexes: &HashSet<usize>,
+    index_specifier: &IndexSpecifier,
+    query: &ItemQuery,
+    error_conditions: &ErrorConditions,
+) -> Result<usize, MyError> {
+    game.inventory
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| {
+            unlocked_item(item).map(|unwrapped_item| (index, unwrapped_item))
+        })
+        .find(|(index, item)| {
+            *index != inventory_index
+                && !calculated_selected_item_indexes.contains(index)
+                && query.matches(item)
+        })
+        .map(|(index, item)| handle_conditions(index, error_conditions, item))
+        .ok_or_else(|| {
+            MyError::create_execute_command_error(
+                ErrorCode::NoSacrificeFound,
+                format!(
+                    "index_specifier: {index_specifier:?} did not match any items in inventory."
+                ),
+            )
         })?
 }
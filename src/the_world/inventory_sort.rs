@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use crate::the_world::{damage_types::DamageType, item::Item, modifier_gain::Gain};
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Eq, Hash)]
+pub enum InventorySortKey {
+    Modifiers,
+    // The flat damage of the given damage type, or of all damage types.
+    FlatDamage(Option<DamageType>),
+    // The number of costs of all modifiers.
+    CostBurden,
+    PlacesCount,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize, Eq, Hash)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl InventorySortKey {
+    pub fn sort_value(
+        &self,
+        item: &Item,
+    ) -> u64 {
+        match self {
+            InventorySortKey::Modifiers => item.modifiers.len() as u64,
+            InventorySortKey::FlatDamage(damage_type) => {
+                item.modifiers
+                    .iter()
+                    .flat_map(|modifier| modifier.gains.iter())
+                    .filter_map(|gain| {
+                        match gain {
+                            Gain::FlatDamage(gain_damage_type, value)
+                                if damage_type
+                                    .as_ref()
+                                    .is_none_or(|damage_type| damage_type == gain_damage_type) =>
+                            {
+                                Some(*value)
+                            }
+                            _ => None,
+                        }
+                    })
+                    .fold(0, u64::saturating_add)
+            }
+            InventorySortKey::CostBurden => {
+                item.modifiers
+                    .iter()
+                    .map(|modifier| modifier.costs.len() as u64)
+                    .sum()
+            }
+            InventorySortKey::PlacesCount => item.crafting_info.places_count as u64,
+        }
+    }
+}
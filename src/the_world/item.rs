@@ -1,11 +1,22 @@
 use serde::{Deserialize, Serialize};
 
-use crate::the_world::{difficulty::Difficulty, item_modifier::Modifier};
+use crate::the_world::{difficulty::Difficulty, item_modifier::Modifier, rarity::Rarity};
 
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct Item {
+    // 0 until the game assigns the item an id, after that it never changes.
+    #[serde(default)]
+    pub(crate) id: u64,
+    #[serde(default)]
+    pub(crate) name: Option<Box<str>>,
+    #[serde(default)]
+    pub(crate) notes: Option<Box<str>>,
+    #[serde(default)]
+    pub(crate) rarity: Rarity,
     pub(crate) modifiers: Vec<Modifier>,
     pub(crate) crafting_info: CraftingInfo,
+    #[serde(default)]
+    pub(crate) locked: bool,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
@@ -14,12 +25,48 @@ pub struct CraftingInfo {
     pub(crate) places_count: usize,
 }
 
+impl Item {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn notes(&self) -> Option<&str> {
+        self.notes.as_deref()
+    }
+
+    pub fn rarity(&self) -> Rarity {
+        self.rarity
+    }
+
+    // The most modifiers the item can be given with AddModifier.
+    pub fn modifier_slots(&self) -> usize {
+        usize::from(
+            self.crafting_info
+                .possible_rolls
+                .min_simultaneous_resistances,
+        ) + self.rarity.extra_modifier_slots()
+    }
+
+    pub fn modifiers(&self) -> &[Modifier] {
+        &self.modifiers
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+}
+
 #[cfg(test)]
 pub mod test_util {
     use crate::{
         the_world::{
             item::{CraftingInfo, Item},
             item_modifier::Modifier,
+            rarity::Rarity,
         },
         Game,
     };
@@ -41,6 +88,11 @@ pub mod test_util {
                 possible_rolls: game.difficulty.clone(),
                 places_count: game.places.len(),
             },
+            locked: false,
+            id: 0,
+            name: None,
+            notes: None,
+            rarity: Rarity::Common,
         }
     }
 }
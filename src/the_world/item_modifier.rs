@@ -7,3 +7,13 @@ pub struct Modifier {
     pub(crate) costs: Vec<Cost>,
     pub(crate) gains: Vec<Gain>,
 }
+
+impl Modifier {
+    pub fn costs(&self) -> &[Cost] {
+        &self.costs
+    }
+
+    pub fn gains(&self) -> &[Gain] {
+        &self.gains
+    }
+}
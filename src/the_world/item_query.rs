@@ -0,0 +1,305 @@
+use serde::{Deserialize, Serialize};
+
+use crate::the_world::{item::Item, modifier_cost::Cost, modifier_gain::Gain, rarity::Rarity};
+
+mod tests;
+
+// A condition on the modifiers or the rarity of an item, like: gain:FlatDamage(Fire)>50 and cost:none
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Eq, Hash)]
+pub enum ItemQuery {
+    Or(Vec<ItemQuery>),
+    And(Vec<ItemQuery>),
+    Not(Box<ItemQuery>),
+    Modifiers(Comparison, usize),
+    Rarity(Comparison, Rarity),
+    Gain(ModifierPartQuery),
+    Cost(ModifierPartQuery),
+    NoGains,
+    NoCosts,
+}
+
+// Matches the gains or costs with the given name, and argument like the damage type when given.
+// With a comparison the values of all matching gains or costs of the item are summed up and
+// compared, so an item without any matching gain or cost has the value 0.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Eq, Hash)]
+pub struct ModifierPartQuery {
+    pub(crate) name: Box<str>,
+    pub(crate) argument: Option<Box<str>>,
+    pub(crate) comparison: Option<ValueComparison>,
+}
+
+pub type ValueComparison = (Comparison, u64);
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize, Eq, Hash)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Equal,
+    NotEqual,
+    GreaterOrEqual,
+    Greater,
+}
+
+pub const GAIN_NAMES: [&str; 11] = [
+    "FlatDamage",
+    "PercentageIncreaseDamage",
+    "FlatItemResource",
+    "FlatResistanceReduction",
+    "PercentageIncreaseResistanceReduction",
+    "FlatDamageAgainstHighestResistance",
+    "PercentageIncreaseDamageAgainstHighestResistance",
+    "FlatDamageAgainstLowestResistance",
+    "PercentageIncreaseDamageAgainstLowestResistance",
+    "PercentageIncreaseTreasure",
+    "FlatIncreaseRewardedItems",
+];
+
+pub const COST_NAMES: [&str; 14] = [
+    "FlatItemResource",
+    "FlatMinItemResourceRequirement",
+    "FlatMaxItemResourceRequirement",
+    "FlatMinAttackRequirement",
+    "FlatMaxAttackRequirement",
+    "FlatSumMinAttackRequirement",
+    "FlatSumMaxAttackRequirement",
+    "FlatMinResistanceRequirement",
+    "FlatMaxResistanceRequirement",
+    "FlatMinSumResistanceRequirement",
+    "FlatMaxSumResistanceRequirement",
+    "PlaceLimitedByIndexModulus",
+    "MinWinsInARow",
+    "MaxWinsInARow",
+];
+
+// The name, argument and value of a gain or cost, so they can be queried the same way.
+pub(crate) struct ModifierPart {
+    pub(crate) name: &'static str,
+    pub(crate) argument: Option<String>,
+    pub(crate) value: u64,
+}
+
+impl ItemQuery {
+    pub fn matches(
+        &self,
+        item: &Item,
+    ) -> bool {
+        match self {
+            ItemQuery::Or(queries) => queries.iter().any(|query| query.matches(item)),
+            ItemQuery::And(queries) => queries.iter().all(|query| query.matches(item)),
+            ItemQuery::Not(query) => !query.matches(item),
+            ItemQuery::Modifiers(comparison, count) => {
+                comparison.compare(item.modifiers.len() as u64, *count as u64)
+            }
+            ItemQuery::Rarity(comparison, rarity) => {
+                comparison.compare(item.rarity.tier(), rarity.tier())
+            }
+            ItemQuery::Gain(query) => {
+                query.matches(
+                    item.modifiers
+                        .iter()
+                        .flat_map(|modifier| modifier.gains.iter().map(gain_part)),
+                )
+            }
+            ItemQuery::Cost(query) => {
+                query.matches(
+                    item.modifiers
+                        .iter()
+                        .flat_map(|modifier| modifier.costs.iter().map(cost_part)),
+                )
+            }
+            ItemQuery::NoGains => {
+                item.modifiers
+                    .iter()
+                    .all(|modifier| modifier.gains.is_empty())
+            }
+            ItemQuery::NoCosts => {
+                item.modifiers
+                    .iter()
+                    .all(|modifier| modifier.costs.is_empty())
+            }
+        }
+    }
+}
+
+impl ModifierPartQuery {
+    fn matches(
+        &self,
+        parts: impl Iterator<Item = ModifierPart>,
+    ) -> bool {
+        let mut matching_parts = parts.filter(|part| {
+            *self.name == *part.name
+                && self
+                    .argument
+                    .as_ref()
+                    .is_none_or(|argument| part.argument.as_deref() == Some(&**argument))
+        });
+        match self.comparison {
+            None => matching_parts.next().is_some(),
+            Some((comparison, value)) => {
+                let sum = matching_parts.fold(0u64, |sum, part| sum.saturating_add(part.value));
+                comparison.compare(sum, value)
+            }
+        }
+    }
+}
+
+impl Comparison {
+    pub fn compare(
+        &self,
+        left: u64,
+        right: u64,
+    ) -> bool {
+        match self {
+            Comparison::Less => left < right,
+            Comparison::LessOrEqual => left <= right,
+            Comparison::Equal => left == right,
+            Comparison::NotEqual => left != right,
+            Comparison::GreaterOrEqual => left >= right,
+            Comparison::Greater => left > right,
+        }
+    }
+}
+
+pub(crate) fn gain_part(gain: &Gain) -> ModifierPart {
+    let (name, argument, value) = match gain {
+        Gain::FlatDamage(damage_type, value) => {
+            ("FlatDamage", Some(format!("{damage_type:?}")), *value)
+        }
+        Gain::PercentageIncreaseDamage(damage_type, value) => {
+            (
+                "PercentageIncreaseDamage",
+                Some(format!("{damage_type:?}")),
+                u64::from(*value),
+            )
+        }
+        Gain::FlatItemResource(resource_type, value) => {
+            (
+                "FlatItemResource",
+                Some(format!("{resource_type:?}")),
+                *value,
+            )
+        }
+        Gain::FlatResistanceReduction(damage_type, value) => {
+            (
+                "FlatResistanceReduction",
+                Some(format!("{damage_type:?}")),
+                *value,
+            )
+        }
+        Gain::PercentageIncreaseResistanceReduction(damage_type, value) => {
+            (
+                "PercentageIncreaseResistanceReduction",
+                Some(format!("{damage_type:?}")),
+                u64::from(*value),
+            )
+        }
+        Gain::FlatDamageAgainstHighestResistance(value) => {
+            ("FlatDamageAgainstHighestResistance", None, *value)
+        }
+        Gain::PercentageIncreaseDamageAgainstHighestResistance(value) => {
+            (
+                "PercentageIncreaseDamageAgainstHighestResistance",
+                None,
+                u64::from(*value),
+            )
+        }
+        Gain::FlatDamageAgainstLowestResistance(value) => {
+            ("FlatDamageAgainstLowestResistance", None, *value)
+        }
+        Gain::PercentageIncreaseDamageAgainstLowestResistance(value) => {
+            (
+                "PercentageIncreaseDamageAgainstLowestResistance",
+                None,
+                u64::from(*value),
+            )
+        }
+        Gain::PercentageIncreaseTreasure(treasure_type, value) => {
+            (
+                "PercentageIncreaseTreasure",
+                Some(format!("{treasure_type:?}")),
+                u64::from(*value),
+            )
+        }
+        Gain::FlatIncreaseRewardedItems(value) => {
+            ("FlatIncreaseRewardedItems", None, u64::from(*value))
+        }
+    };
+    ModifierPart {
+        name,
+        argument,
+        value,
+    }
+}
+
+// PlaceLimitedByIndexModulus is queried by its modulus.
+fn cost_part(cost: &Cost) -> ModifierPart {
+    let (name, argument, value) = match cost {
+        Cost::FlatItemResource(resource_type, value) => {
+            (
+                "FlatItemResource",
+                Some(format!("{resource_type:?}")),
+                *value,
+            )
+        }
+        Cost::FlatMinItemResourceRequirement(resource_type, value) => {
+            (
+                "FlatMinItemResourceRequirement",
+                Some(format!("{resource_type:?}")),
+                *value,
+            )
+        }
+        Cost::FlatMaxItemResourceRequirement(resource_type, value) => {
+            (
+                "FlatMaxItemResourceRequirement",
+                Some(format!("{resource_type:?}")),
+                *value,
+            )
+        }
+        Cost::FlatMinAttackRequirement(damage_type, value) => {
+            (
+                "FlatMinAttackRequirement",
+                Some(format!("{damage_type:?}")),
+                *value,
+            )
+        }
+        Cost::FlatMaxAttackRequirement(damage_type, value) => {
+            (
+                "FlatMaxAttackRequirement",
+                Some(format!("{damage_type:?}")),
+                *value,
+            )
+        }
+        Cost::FlatSumMinAttackRequirement(value) => ("FlatSumMinAttackRequirement", None, *value),
+        Cost::FlatSumMaxAttackRequirement(value) => ("FlatSumMaxAttackRequirement", None, *value),
+        Cost::FlatMinResistanceRequirement(damage_type, value) => {
+            (
+                "FlatMinResistanceRequirement",
+                Some(format!("{damage_type:?}")),
+                *value,
+            )
+        }
+        Cost::FlatMaxResistanceRequirement(damage_type, value) => {
+            (
+                "FlatMaxResistanceRequirement",
+                Some(format!("{damage_type:?}")),
+                *value,
+            )
+        }
+        Cost::FlatMinSumResistanceRequirement(value) => {
+            ("FlatMinSumResistanceRequirement", None, *value)
+        }
+        Cost::FlatMaxSumResistanceRequirement(value) => {
+            ("FlatMaxSumResistanceRequirement", None, *value)
+        }
+        Cost::PlaceLimitedByIndexModulus(modulus, _) => {
+            ("PlaceLimitedByIndexModulus", None, u64::from(*modulus))
+        }
+        Cost::MinWinsInARow(value) => ("MinWinsInARow", None, u64::from(*value)),
+        Cost::MaxWinsInARow(value) => ("MaxWinsInARow", None, u64::from(*value)),
+    };
+    ModifierPart {
+        name,
+        argument,
+        value,
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests_int {
+    use crate::{
+        generator::game::new_testing,
+        the_world::{
+            item_query::{Comparison, ItemQuery, ModifierPartQuery},
+            rarity::Rarity,
+        },
+    };
+
+    fn gain(
+        name: &str,
+        argument: Option<&str>,
+        comparison: Option<(Comparison, u64)>,
+    ) -> ItemQuery {
+        ItemQuery::Gain(ModifierPartQuery {
+            name: name.into(),
+            argument: argument.map(Box::from),
+            comparison,
+        })
+    }
+
+    #[test]
+    fn test_matches_gains() {
+        let game = new_testing(Some([1; 16]));
+        let fire_item = game.inventory[1].as_ref().unwrap();
+        let powerful_item = &game.equipped_items[1];
+
+        assert!(gain("FlatDamage", None, None).matches(fire_item));
+        assert!(gain("FlatDamage", Some("Fire"), None).matches(fire_item));
+        assert!(!gain("FlatDamage", Some("Frost"), None).matches(fire_item));
+        assert!(!gain("FlatItemResource", None, None).matches(fire_item));
+
+        let query = gain("FlatDamage", Some("Fire"), Some((Comparison::Greater, 50)));
+        assert!(!query.matches(fire_item));
+        assert!(query.matches(powerful_item));
+
+        let query = gain("FlatDamage", None, Some((Comparison::Equal, 900)));
+        assert!(query.matches(powerful_item));
+
+        let query = gain("FlatDamage", Some("Frost"), Some((Comparison::Less, 1)));
+        assert!(query.matches(fire_item));
+    }
+
+    #[test]
+    fn test_matches_costs_and_modifiers() {
+        let game = new_testing(Some([1; 16]));
+        let fire_item = game.inventory[1].as_ref().unwrap();
+        let powerful_item = &game.equipped_items[1];
+
+        assert!(ItemQuery::NoCosts.matches(fire_item));
+        assert!(!ItemQuery::NoCosts.matches(powerful_item));
+        assert!(!ItemQuery::NoGains.matches(fire_item));
+
+        let query = ItemQuery::Cost(ModifierPartQuery {
+            name: "FlatItemResource".into(),
+            argument: Some("Mana".into()),
+            comparison: Some((Comparison::GreaterOrEqual, 9)),
+        });
+        assert!(query.matches(powerful_item));
+
+        let query = ItemQuery::And(vec![
+            ItemQuery::Modifiers(Comparison::GreaterOrEqual, 3),
+            ItemQuery::Not(Box::new(ItemQuery::NoCosts)),
+        ]);
+        assert!(query.matches(powerful_item));
+        assert!(!query.matches(fire_item));
+
+        let query = ItemQuery::Or(vec![
+            ItemQuery::Modifiers(Comparison::NotEqual, 9),
+            ItemQuery::NoCosts,
+        ]);
+        assert!(query.matches(fire_item));
+        assert!(!query.matches(powerful_item));
+    }
+
+    #[test]
+    fn test_matches_rarity() {
+        let mut game = new_testing(Some([1; 16]));
+        game.inventory[1].as_mut().unwrap().rarity = Rarity::Rare;
+        let rare_item = game.inventory[1].as_ref().unwrap();
+        let common_item = game.inventory[2].as_ref().unwrap();
+
+        let query = ItemQuery::Rarity(Comparison::GreaterOrEqual, Rarity::Magic);
+        assert!(query.matches(rare_item));
+        assert!(!query.matches(common_item));
+
+        let query = ItemQuery::Rarity(Comparison::Equal, Rarity::Common);
+        assert!(!query.matches(rare_item));
+        assert!(query.matches(common_item));
+    }
+}
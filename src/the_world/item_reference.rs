@@ -0,0 +1,101 @@
+use std::num::ParseIntError;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    my_little_rpg_errors::{ErrorCode, MyError},
+    the_world::item::Item,
+    Game,
+};
+
+mod tests;
+
+// Points at an item by its position, or by its id or name, which do not change when the items
+// move around. In json it is written like in the text commands: 3, "#12" or "keeper".
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Eq, Hash)]
+#[serde(try_from = "JsonItemReference", into = "JsonItemReference")]
+pub enum ItemReference {
+    Index(usize),
+    Id(u64),
+    Name(Box<str>),
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum JsonItemReference {
+    Index(usize),
+    IdOrName(Box<str>),
+}
+
+impl TryFrom<JsonItemReference> for ItemReference {
+    type Error = ParseIntError;
+
+    fn try_from(value: JsonItemReference) -> Result<Self, Self::Error> {
+        match value {
+            JsonItemReference::Index(index) => Ok(ItemReference::Index(index)),
+            JsonItemReference::IdOrName(id_or_name) => {
+                match id_or_name.strip_prefix('#') {
+                    Some(id) => id.parse().map(ItemReference::Id),
+                    None => Ok(ItemReference::Name(id_or_name)),
+                }
+            }
+        }
+    }
+}
+
+impl From<ItemReference> for JsonItemReference {
+    fn from(value: ItemReference) -> Self {
+        match value {
+            ItemReference::Index(index) => JsonItemReference::Index(index),
+            ItemReference::Id(id) => JsonItemReference::IdOrName(format!("#{id}").into()),
+            ItemReference::Name(name) => JsonItemReference::IdOrName(name),
+        }
+    }
+}
+
+impl ItemReference {
+    pub fn matches(
+        &self,
+        item: &Item,
+    ) -> bool {
+        match self {
+            ItemReference::Index(_) => false,
+            ItemReference::Id(id) => item.id == *id,
+            ItemReference::Name(name) => item.name.as_ref() == Some(name),
+        }
+    }
+
+    // An index is returned as is, so the commands still report when it is out of range or empty.
+    pub fn resolve<'a>(
+        &self,
+        items: impl IntoIterator<Item = Option<&'a Item>>,
+        items_description: &str,
+    ) -> Result<usize, MyError> {
+        if let ItemReference::Index(index) = self {
+            return Ok(*index);
+        }
+        items
+            .into_iter()
+            .position(|item| item.is_some_and(|item| self.matches(item)))
+            .ok_or_else(|| {
+                MyError::create_execute_command_error(
+                    ErrorCode::ItemNotFound,
+                    format!("There is no item {self:?} in the {items_description}."),
+                )
+            })
+    }
+
+    pub fn resolve_inventory(
+        &self,
+        game: &Game,
+    ) -> Result<usize, MyError> {
+        self.resolve(game.inventory.iter().map(Option::as_ref), "inventory")
+    }
+
+    pub fn resolve_equipment(
+        &self,
+        game: &Game,
+    ) -> Result<usize, MyError> {
+        self.resolve(game.equipped_items.iter().map(Some), "equipment")
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests_int {
+    use crate::{
+        generator::game::new_testing,
+        my_little_rpg_errors::{ErrorCode, MyError},
+        the_world::item_reference::ItemReference,
+    };
+
+    #[test]
+    fn test_resolve_inventory() {
+        let mut game = new_testing(Some([1; 16]));
+        game.inventory[4].as_mut().unwrap().name = Some("keeper".into());
+
+        assert_eq!(Ok(20), ItemReference::Index(20).resolve_inventory(&game));
+        assert_eq!(Ok(2), ItemReference::Id(5).resolve_inventory(&game));
+        assert_eq!(
+            Ok(4),
+            ItemReference::Name("keeper".into()).resolve_inventory(&game)
+        );
+
+        game.inventory.insert(0, None);
+        assert_eq!(Ok(3), ItemReference::Id(5).resolve_inventory(&game));
+        assert_eq!(
+            Ok(5),
+            ItemReference::Name("keeper".into()).resolve_inventory(&game)
+        );
+
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::ItemNotFound,
+                "There is no item Id(1) in the inventory.".to_string()
+            )),
+            ItemReference::Id(1).resolve_inventory(&game)
+        );
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::ItemNotFound,
+                "There is no item Name(\"fodder\") in the inventory.".to_string()
+            )),
+            ItemReference::Name("fodder".into()).resolve_inventory(&game)
+        );
+    }
+
+    #[test]
+    fn test_resolve_equipment() {
+        let game = new_testing(Some([1; 16]));
+
+        assert_eq!(Ok(1), ItemReference::Id(2).resolve_equipment(&game));
+        assert_eq!(
+            Err(MyError::create_execute_command_error(
+                ErrorCode::ItemNotFound,
+                "There is no item Id(3) in the equipment.".to_string()
+            )),
+            ItemReference::Id(3).resolve_equipment(&game)
+        );
+    }
+}
@@ -1,12 +1,24 @@
-pub(crate) mod damage_types;
-pub(crate) mod difficulty;
+pub mod damage_types;
+pub mod difficulty;
 pub mod game;
-pub(crate) mod game_statistics;
-pub(crate) mod index_specifier;
-pub(crate) mod item;
-pub(crate) mod item_modifier;
-pub(crate) mod item_resource;
-pub(crate) mod modifier_cost;
-pub(crate) mod modifier_gain;
-pub(crate) mod place;
-pub(crate) mod treasure_types;
+pub mod game_history;
+pub mod game_registry;
+pub mod game_statistics;
+pub mod index_specifier;
+pub mod inventory_sort;
+pub mod item;
+pub mod item_modifier;
+pub mod item_query;
+pub mod item_reference;
+pub mod item_resource;
+pub mod modifier_cost;
+pub mod modifier_gain;
+pub mod place;
+pub mod random_generators;
+pub mod rarity;
+pub mod roll_table;
+pub mod rules;
+pub mod save_root;
+pub mod stash;
+pub mod state_query;
+pub mod treasure_types;
@@ -12,6 +12,14 @@ pub struct Place {
 }
 
 impl Place {
+    pub fn resistance(&self) -> &HashMap<damage_types::DamageType, u64> {
+        &self.resistance
+    }
+
+    pub fn reward(&self) -> &HashMap<TreasureType, u64> {
+        &self.reward
+    }
+
     //TODO consider moving function and tests: Because should the place know about attacks etc.
     pub fn claim_rewards(
         &self,
@@ -0,0 +1,43 @@
+use rand::SeedableRng;
+use rand_pcg::{Lcg64Xsh32, Pcg32};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+mod tests;
+
+// Every part of the game rolls from its own stream, all derived from the seed, so rolling in one
+// part never changes the rolls of another. The same seed always generates the same places, no
+// matter how much is crafted in between. Places roll from the seed itself, so seeds keep the
+// places they had before the streams were split.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct RandomGenerators {
+    // New places, and the changes to the difficulty they are generated from.
+    pub(crate) places: Lcg64Xsh32,
+    // The items won from places.
+    pub(crate) loot: Lcg64Xsh32,
+    // The modifiers rolled by crafting.
+    pub(crate) crafting: Lcg64Xsh32,
+}
+
+impl RandomGenerators {
+    pub fn new(seed: [u8; 16]) -> Self {
+        Self {
+            places: Pcg32::from_seed(seed),
+            loot: Pcg32::from_seed(derive_seed(seed, "loot")),
+            crafting: Pcg32::from_seed(derive_seed(seed, "crafting")),
+        }
+    }
+}
+
+fn derive_seed(
+    seed: [u8; 16],
+    stream: &str,
+) -> [u8; 16] {
+    let hash = Sha256::new()
+        .chain_update(seed)
+        .chain_update(stream)
+        .finalize();
+    let mut derived_seed = [0; 16];
+    derived_seed.copy_from_slice(&hash[..16]);
+    derived_seed
+}
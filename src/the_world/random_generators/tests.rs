@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod tests_int {
+    use rand::Rng;
+
+    use crate::{
+        generator::{game::new_testing, place::new as new_place},
+        the_world::random_generators::RandomGenerators,
+    };
+
+    #[test]
+    fn crafting_and_loot_do_not_change_the_places() {
+        let mut game = new_testing(Some([1; 16]));
+        let mut crafted_game = game.clone();
+        for _i in 0..100 {
+            crafted_game.random_generators.crafting.gen::<u64>();
+            crafted_game.random_generators.loot.gen::<u64>();
+        }
+
+        assert_ne!(game.random_generators, crafted_game.random_generators);
+        for _i in 0..10 {
+            assert_eq!(new_place(&mut game), new_place(&mut crafted_game));
+        }
+    }
+
+    #[test]
+    fn streams_are_derived_from_the_seed() {
+        let random_generators = RandomGenerators::new([1; 16]);
+
+        assert_eq!(random_generators, RandomGenerators::new([1; 16]));
+        assert_ne!(random_generators, RandomGenerators::new([2; 16]));
+        assert_ne!(random_generators.places, random_generators.loot);
+        assert_ne!(random_generators.loot, random_generators.crafting);
+    }
+}
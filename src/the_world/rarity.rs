@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use crate::the_world::rarity::Rarity::{Common, Legendary, Magic, Rare};
+
+// How good an item is. Rarer items have more modifier slots and roll their gains from a bigger
+// budget.
+#[derive(
+    Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize, Eq, Hash, PartialOrd, Ord,
+)]
+pub enum Rarity {
+    #[default]
+    Common,
+    Magic,
+    Rare,
+    Legendary,
+}
+
+impl Rarity {
+    pub fn get_all() -> Vec<Rarity> {
+        vec![Common, Magic, Rare, Legendary]
+    }
+
+    // Common is tier 0, every rarer rarity is one tier higher.
+    pub fn tier(&self) -> u64 {
+        match self {
+            Common => 0,
+            Magic => 1,
+            Rare => 2,
+            Legendary => 3,
+        }
+    }
+
+    pub fn next(&self) -> Option<Rarity> {
+        match self {
+            Common => Some(Magic),
+            Magic => Some(Rare),
+            Rare => Some(Legendary),
+            Legendary => None,
+        }
+    }
+
+    // The modifiers an item can have on top of the ones its possible rolls allow.
+    pub fn extra_modifier_slots(&self) -> usize {
+        match self {
+            Common => 0,
+            Magic => 1,
+            Rare => 2,
+            Legendary => 3,
+        }
+    }
+
+    // The budget the gains of a new modifier are rolled from, in percent of the rolled costs.
+    pub fn gain_budget_percentage(&self) -> u64 {
+        match self {
+            Common => 100,
+            Magic => 125,
+            Rare => 150,
+            Legendary => 200,
+        }
+    }
+}
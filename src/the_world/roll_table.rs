@@ -0,0 +1,149 @@
+use rand::Rng;
+use rand_pcg::Lcg64Xsh32;
+use serde::{Deserialize, Serialize};
+
+use crate::the_world::difficulty::Difficulty;
+
+mod tests;
+
+// Entries rolled with a chance proportional to their weight. Entries with weight 0 are never rolled.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RollTable<T> {
+    entries: Vec<(T, u32)>,
+    total_weight: u32,
+}
+
+// Which entry of a roll table was rolled, and the chance it had of weight out of total_weight.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct RolledEntry {
+    entry: Box<str>,
+    weight: u32,
+    total_weight: u32,
+}
+
+// The weight of an entry can grow with the number of damage types the item can be rolled against,
+// and the entry can be held back until the item can be rolled against enough of them. In the
+// rules a plain number is just the weight.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(from = "JsonRollWeight")]
+pub struct RollWeight {
+    pub(crate) weight: u32,
+    pub(crate) weight_per_element: u32,
+    pub(crate) minimum_elements: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonRollWeight {
+    Weight(u32),
+    Detailed {
+        #[serde(default)]
+        weight: u32,
+        #[serde(default)]
+        weight_per_element: u32,
+        #[serde(default)]
+        minimum_elements: u32,
+    },
+}
+
+impl<T> RollTable<T> {
+    pub fn new(entries: impl IntoIterator<Item = (T, u32)>) -> Self {
+        let entries: Vec<(T, u32)> = entries
+            .into_iter()
+            .filter(|(_, weight)| *weight > 0)
+            .collect();
+        let total_weight = entries
+            .iter()
+            .fold(0u32, |total, (_, weight)| total.saturating_add(*weight));
+        Self {
+            entries,
+            total_weight,
+        }
+    }
+
+    pub fn total_weight(&self) -> u32 {
+        self.total_weight
+    }
+
+    // The rolled entry and its weight, None when every entry has weight 0.
+    pub fn roll(
+        &self,
+        random_generator_state: &mut Lcg64Xsh32,
+    ) -> Option<(&T, u32)> {
+        if self.total_weight == 0 {
+            return None;
+        }
+        let mut rolled = random_generator_state.gen_range(0..self.total_weight);
+        for (entry, weight) in &self.entries {
+            if rolled < *weight {
+                return Some((entry, *weight));
+            }
+            rolled -= weight;
+        }
+        unreachable!("The roll is less than the sum of the weights.")
+    }
+}
+
+impl RolledEntry {
+    pub fn new(
+        entry: &str,
+        weight: u32,
+        total_weight: u32,
+    ) -> Self {
+        Self {
+            entry: entry.into(),
+            weight,
+            total_weight,
+        }
+    }
+
+    pub fn entry(&self) -> &str {
+        &self.entry
+    }
+}
+
+impl RollWeight {
+    pub fn new(weight: u32) -> Self {
+        Self {
+            weight,
+            ..Self::default()
+        }
+    }
+
+    pub fn weight_for(
+        &self,
+        possible_rolls: &Difficulty,
+    ) -> u32 {
+        let elements = u32::try_from(possible_rolls.max_resistance.len()).unwrap_or(u32::MAX);
+        if elements < self.minimum_elements {
+            return 0;
+        }
+        self.weight_per_element
+            .saturating_mul(elements)
+            .saturating_add(self.weight)
+    }
+
+    // Whether the entry can be rolled for every item, even one rolled against a single damage type.
+    pub(crate) fn is_always_rollable(&self) -> bool {
+        self.minimum_elements <= 1 && (self.weight > 0 || self.weight_per_element > 0)
+    }
+}
+
+impl From<JsonRollWeight> for RollWeight {
+    fn from(json_roll_weight: JsonRollWeight) -> Self {
+        match json_roll_weight {
+            JsonRollWeight::Weight(weight) => RollWeight::new(weight),
+            JsonRollWeight::Detailed {
+                weight,
+                weight_per_element,
+                minimum_elements,
+            } => {
+                RollWeight {
+                    weight,
+                    weight_per_element,
+                    minimum_elements,
+                }
+            }
+        }
+    }
+}
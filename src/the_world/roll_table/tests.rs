@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests_int {
+    use std::collections::HashMap;
+
+    use crate::{
+        generator::game::new_testing,
+        the_world::roll_table::{RollTable, RollWeight},
+    };
+
+    #[test]
+    fn test_roll() {
+        let mut game = new_testing(Some([1; 16]));
+        let roll_table = RollTable::new([("never", 0), ("often", 3), ("rarely", 1)]);
+        assert_eq!(4, roll_table.total_weight());
+
+        let mut rolled = HashMap::new();
+        for _i in 0..1000 {
+            let (entry, weight) = roll_table
+                .roll(&mut game.random_generators.crafting)
+                .unwrap();
+            assert_eq!(if *entry == "often" { 3 } else { 1 }, weight);
+            *rolled.entry(*entry).or_insert(0) += 1;
+        }
+        assert_eq!(None, rolled.get("never"));
+        assert!(rolled["often"] > 2 * rolled["rarely"]);
+
+        let empty_roll_table = RollTable::new([("never", 0)]);
+        assert_eq!(
+            None,
+            empty_roll_table.roll(&mut game.random_generators.crafting)
+        );
+    }
+
+    #[test]
+    fn test_roll_weight() {
+        let game = new_testing(Some([1; 16]));
+        assert_eq!(
+            RollWeight::new(3),
+            serde_json::from_str::<RollWeight>("3").unwrap()
+        );
+        let roll_weight = serde_json::from_str::<RollWeight>(
+            r#"{"weight_per_element": 2, "minimum_elements": 8}"#,
+        )
+        .unwrap();
+        assert_eq!(16, roll_weight.weight_for(&game.difficulty));
+        assert!(!roll_weight.is_always_rollable());
+
+        let roll_weight = RollWeight {
+            minimum_elements: 9,
+            ..roll_weight
+        };
+        assert_eq!(0, roll_weight.weight_for(&game.difficulty));
+    }
+}
@@ -0,0 +1,305 @@
+use std::{collections::BTreeMap, fs};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    my_little_rpg_errors::{ErrorCode, MyError},
+    the_world::{
+        difficulty::Difficulty,
+        item_query::{COST_NAMES, GAIN_NAMES},
+        rarity::Rarity,
+        roll_table::{RollTable, RollWeight},
+    },
+};
+
+mod tests;
+
+// The numbers the game is balanced with. The defaults are the rules the game always had, a rules
+// file only needs to contain the parts it changes.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Rules {
+    pub(crate) costs: CostRules,
+    // How often each kind of cost and gain is rolled for a new modifier, by the name of the cost or
+    // gain. Gains with an argument, like FlatDamage, have the weight once for every argument.
+    pub(crate) modifier_cost_weights: BTreeMap<Box<str>, RollWeight>,
+    pub(crate) modifier_gain_weights: BTreeMap<Box<str>, RollWeight>,
+    // How often each rarity is rolled for the items rewarded by a place.
+    pub(crate) item_rarity_weights: BTreeMap<Rarity, RollWeight>,
+    pub(crate) place_reward: PlaceRewardRules,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CostRules {
+    pub(crate) expand_places: CostCurve,
+    pub(crate) expand_elements: CostCurve,
+    pub(crate) expand_max_element: CostCurve,
+    pub(crate) expand_min_element: CostCurve,
+    pub(crate) expand_max_simultaneous_element: CostCurve,
+    pub(crate) expand_min_simultaneous_element: CostCurve,
+    pub(crate) expand_equipment_slots: CostCurve,
+    pub(crate) expand_stash: CostCurve,
+    pub(crate) reduce_difficulty: CostCurve,
+    // The number of items to sacrifice, by the number of modifiers of the crafted item.
+    pub(crate) reroll_modifier: CostCurve,
+    pub(crate) add_modifier: CostCurve,
+    // By the tier of the rarity the item is upgraded from.
+    pub(crate) upgrade_rarity: CostCurve,
+}
+
+// base + factor * (x + offset)^exponent, where x is what the cost grows with, like the number of
+// places for ExpandPlaces.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CostCurve {
+    pub(crate) base: u64,
+    pub(crate) factor: u64,
+    pub(crate) offset: u64,
+    pub(crate) exponent: u32,
+}
+
+// The gold of a place is the part from its own resistances plus the part from the difficulty, each
+// scaled by a percentage.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlaceRewardRules {
+    pub(crate) resistance_percentage: u64,
+    pub(crate) difficulty_percentage: u64,
+    pub(crate) minimum_difficulty_reward: u64,
+}
+
+// The order the costs are rolled in, so equal weights roll exactly like the game always did.
+pub const COST_ROLL_ORDER: [&str; 14] = [
+    "FlatMinAttackRequirement",
+    "FlatMaxAttackRequirement",
+    "PlaceLimitedByIndexModulus",
+    "FlatSumMinAttackRequirement",
+    "FlatSumMaxAttackRequirement",
+    "FlatMinItemResourceRequirement",
+    "FlatMaxItemResourceRequirement",
+    "FlatMinResistanceRequirement",
+    "FlatMaxResistanceRequirement",
+    "FlatMinSumResistanceRequirement",
+    "FlatMaxSumResistanceRequirement",
+    "MinWinsInARow",
+    "MaxWinsInARow",
+    "FlatItemResource",
+];
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            costs: CostRules::default(),
+            modifier_cost_weights: COST_ROLL_ORDER
+                .iter()
+                .map(|name| (Box::from(*name), RollWeight::new(1)))
+                .collect(),
+            modifier_gain_weights: GAIN_NAMES
+                .iter()
+                .map(|name| (Box::from(*name), RollWeight::new(1)))
+                .collect(),
+            item_rarity_weights: BTreeMap::from([
+                (Rarity::Common, RollWeight::new(100)),
+                (Rarity::Magic, RollWeight::new(20)),
+                (Rarity::Rare, RollWeight::new(5)),
+                (Rarity::Legendary, RollWeight::new(1)),
+            ]),
+            place_reward: PlaceRewardRules::default(),
+        }
+    }
+}
+
+impl Default for CostRules {
+    fn default() -> Self {
+        Self {
+            expand_places: CostCurve::linear(10),
+            expand_elements: CostCurve::linear(10),
+            expand_max_element: CostCurve::linear(1),
+            expand_min_element: CostCurve::linear(1),
+            expand_max_simultaneous_element: CostCurve::linear(10),
+            expand_min_simultaneous_element: CostCurve::linear(10),
+            expand_equipment_slots: CostCurve {
+                offset: 1,
+                exponent: 5,
+                ..CostCurve::linear(1)
+            },
+            expand_stash: CostCurve {
+                offset: 2,
+                exponent: 4,
+                ..CostCurve::linear(1)
+            },
+            reduce_difficulty: CostCurve::linear(0),
+            reroll_modifier: CostCurve::linear(1),
+            add_modifier: CostCurve::linear(2),
+            upgrade_rarity: CostCurve {
+                offset: 1,
+                exponent: 2,
+                ..CostCurve::linear(2)
+            },
+        }
+    }
+}
+
+impl Default for CostCurve {
+    fn default() -> Self {
+        CostCurve::linear(1)
+    }
+}
+
+impl Default for PlaceRewardRules {
+    fn default() -> Self {
+        Self {
+            resistance_percentage: 100,
+            difficulty_percentage: 100,
+            minimum_difficulty_reward: 1,
+        }
+    }
+}
+
+impl CostCurve {
+    fn linear(factor: u64) -> Self {
+        Self {
+            base: 0,
+            factor,
+            offset: 0,
+            exponent: 1,
+        }
+    }
+
+    pub fn cost(
+        &self,
+        x: u64,
+    ) -> u64 {
+        x.saturating_add(self.offset)
+            .saturating_pow(self.exponent)
+            .saturating_mul(self.factor)
+            .saturating_add(self.base)
+    }
+}
+
+impl PlaceRewardRules {
+    pub fn reward(
+        &self,
+        reward_from_resistance: u64,
+        reward_from_difficulty: u64,
+    ) -> u64 {
+        let reward_from_difficulty = reward_from_difficulty.max(self.minimum_difficulty_reward);
+        scale(reward_from_resistance, self.resistance_percentage)
+            .saturating_add(scale(reward_from_difficulty, self.difficulty_percentage))
+    }
+}
+
+fn scale(
+    value: u64,
+    percentage: u64,
+) -> u64 {
+    value.saturating_mul(percentage) / 100
+}
+
+impl Rules {
+    pub fn load(path: &str) -> Result<Self, MyError> {
+        let data = fs::read(path).map_err(|error| {
+            create_invalid_rules_error(format!("Failed reading the rules {path}! Reason: {error}"))
+        })?;
+        let rules = serde_json::from_slice::<Rules>(&data).map_err(|error| {
+            create_invalid_rules_error(format!("Failed parsing the rules {path}! Reason: {error}"))
+        })?;
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    fn validate(&self) -> Result<(), MyError> {
+        validate_weights(
+            "modifier_cost_weights",
+            &self.modifier_cost_weights,
+            &COST_NAMES,
+        )?;
+        validate_weights(
+            "modifier_gain_weights",
+            &self.modifier_gain_weights,
+            &GAIN_NAMES,
+        )?;
+        if !self
+            .item_rarity_weights
+            .values()
+            .any(RollWeight::is_always_rollable)
+        {
+            return Err(create_invalid_rules_error(
+                "At least one of the item_rarity_weights needs to be more than 0 for items with a \
+                 single element."
+                    .to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    // The costs that can be rolled for an item with the given possible rolls, costs missing from
+    // the weights are never rolled.
+    pub fn modifier_cost_table(
+        &self,
+        possible_rolls: &Difficulty,
+    ) -> RollTable<&'static str> {
+        RollTable::new(COST_ROLL_ORDER.map(|name| {
+            (
+                name,
+                weight_for(&self.modifier_cost_weights, name, possible_rolls),
+            )
+        }))
+    }
+
+    pub fn item_rarity_table(
+        &self,
+        possible_rolls: &Difficulty,
+    ) -> RollTable<Rarity> {
+        RollTable::new(Rarity::get_all().into_iter().map(|rarity| {
+            let weight = self
+                .item_rarity_weights
+                .get(&rarity)
+                .map_or(0, |weight| weight.weight_for(possible_rolls));
+            (rarity, weight)
+        }))
+    }
+
+    pub fn modifier_gain_weight(
+        &self,
+        name: &str,
+        possible_rolls: &Difficulty,
+    ) -> u32 {
+        weight_for(&self.modifier_gain_weights, name, possible_rolls)
+    }
+}
+
+fn weight_for(
+    weights: &BTreeMap<Box<str>, RollWeight>,
+    name: &str,
+    possible_rolls: &Difficulty,
+) -> u32 {
+    weights
+        .get(name)
+        .map_or(0, |weight| weight.weight_for(possible_rolls))
+}
+
+// Every name needs to be known, and something needs to be rollable for every item.
+fn validate_weights(
+    description: &str,
+    weights: &BTreeMap<Box<str>, RollWeight>,
+    names: &[&str],
+) -> Result<(), MyError> {
+    if let Some(name) = weights.keys().find(|name| !names.contains(&&***name)) {
+        return Err(create_invalid_rules_error(format!(
+            "{name} in {description} is not known, it needs to be one of {names:?}"
+        )));
+    }
+    if !weights.values().any(RollWeight::is_always_rollable) {
+        return Err(create_invalid_rules_error(format!(
+            "At least one of the {description} needs to be more than 0 for items with a single \
+             element."
+        )));
+    }
+    Ok(())
+}
+
+fn create_invalid_rules_error(error_message: String) -> MyError {
+    MyError::create_save_load_error(ErrorCode::InvalidRules, error_message)
+}
@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod tests_int {
+    use std::{collections::HashMap, fs, sync::Arc};
+
+    use crate::{
+        command::expand_places::execute_expand_places_calculate_cost,
+        generator::game::{new_testing, new_with_rules},
+        my_little_rpg_errors::{ErrorCode, MyError},
+        the_world::{
+            roll_table::RollWeight,
+            rules::{CostCurve, PlaceRewardRules, Rules},
+            treasure_types::TreasureType::Gold,
+        },
+    };
+
+    #[test]
+    fn test_cost_curve() {
+        let curve = CostCurve {
+            base: 3,
+            factor: 2,
+            offset: 1,
+            exponent: 3,
+        };
+        assert_eq!(3 + 2 * 8, curve.cost(1));
+        assert_eq!(u64::MAX, curve.cost(u64::MAX));
+
+        let rules = Rules::default();
+        assert_eq!(30, rules.costs.expand_places.cost(3));
+        assert_eq!(3u64.pow(5), rules.costs.expand_equipment_slots.cost(2));
+        assert_eq!(2u64.pow(4), rules.costs.expand_stash.cost(0));
+        assert_eq!(0, rules.costs.reduce_difficulty.cost(10));
+    }
+
+    #[test]
+    fn test_place_reward() {
+        let rewards = PlaceRewardRules::default();
+        assert_eq!(11, rewards.reward(10, 0));
+        assert_eq!(15, rewards.reward(10, 5));
+
+        let rewards = PlaceRewardRules {
+            resistance_percentage: 50,
+            difficulty_percentage: 200,
+            minimum_difficulty_reward: 3,
+        };
+        assert_eq!(5 + 6, rewards.reward(10, 0));
+        assert_eq!(5 + 10, rewards.reward(10, 5));
+    }
+
+    #[test]
+    fn test_modifier_cost_table() {
+        let game = new_testing(Some([1; 16]));
+        let possible_rolls = &game.difficulty;
+        let rules = Rules::default();
+        assert_eq!(14, rules.modifier_cost_table(possible_rolls).total_weight());
+
+        let rules = Rules {
+            modifier_cost_weights: [
+                ("MinWinsInARow".into(), RollWeight::new(2)),
+                (
+                    "FlatItemResource".into(),
+                    RollWeight {
+                        weight: 1,
+                        weight_per_element: 3,
+                        minimum_elements: 0,
+                    },
+                ),
+                (
+                    "MaxWinsInARow".into(),
+                    RollWeight {
+                        weight: 5,
+                        weight_per_element: 0,
+                        minimum_elements: 9,
+                    },
+                ),
+            ]
+            .into(),
+            ..Rules::default()
+        };
+        assert_eq!(
+            2 + 1 + 3 * 8,
+            rules.modifier_cost_table(possible_rolls).total_weight()
+        );
+        assert_eq!(
+            5,
+            rules.modifier_gain_weight("FlatIncreaseRewardedItems", possible_rolls) * 5
+        );
+        assert_eq!(0, rules.modifier_gain_weight("Nothing", possible_rolls));
+    }
+
+    #[test]
+    fn test_load() {
+        fs::create_dir_all("./testing_rules/").unwrap();
+        fs::write(
+            "./testing_rules/partial.json",
+            r#"{"costs": {"expand_places": {"factor": 3}}, "place_reward": {"difficulty_percentage": 0}}"#,
+        )
+        .unwrap();
+        fs::write(
+            "./testing_rules/unknown_cost.json",
+            r#"{"modifier_cost_weights": {"Nothing": 1}}"#,
+        )
+        .unwrap();
+        fs::write(
+            "./testing_rules/no_weight.json",
+            r#"{"modifier_cost_weights": {"FlatItemResource": 0, "MinWinsInARow": {"weight": 1, "minimum_elements": 2}}}"#,
+        )
+        .unwrap();
+        let partial = Rules::load("./testing_rules/partial.json");
+        let unknown_cost = Rules::load("./testing_rules/unknown_cost.json");
+        let no_weight = Rules::load("./testing_rules/no_weight.json");
+        let missing = Rules::load("./testing_rules/missing.json");
+        fs::remove_dir_all("./testing_rules/").unwrap();
+
+        let partial = partial.unwrap();
+        assert_eq!(3, partial.costs.expand_places.factor);
+        assert_eq!(1, partial.costs.expand_places.exponent);
+        assert_eq!(
+            Rules::default().costs.expand_stash,
+            partial.costs.expand_stash
+        );
+        assert_eq!(0, partial.place_reward.difficulty_percentage);
+        assert_eq!(100, partial.place_reward.resistance_percentage);
+        assert_eq!(
+            Rules::default().modifier_cost_weights,
+            partial.modifier_cost_weights
+        );
+
+        assert_eq!(
+            Err(MyError::create_save_load_error(
+                ErrorCode::InvalidRules,
+                format!(
+                    "Nothing in modifier_cost_weights is not known, it needs to be one of {:?}",
+                    crate::the_world::item_query::COST_NAMES
+                )
+            )),
+            unknown_cost
+        );
+        assert_eq!(
+            Err(MyError::create_save_load_error(
+                ErrorCode::InvalidRules,
+                "At least one of the modifier_cost_weights needs to be more than 0 for items with \
+                 a single element."
+                    .to_string()
+            )),
+            no_weight
+        );
+        assert!(matches!(
+            missing,
+            Err(MyError::SaveLoad {
+                code: ErrorCode::InvalidRules,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_game_uses_rules() {
+        let mut rules = Rules::default();
+        rules.costs.expand_places = CostCurve {
+            base: 7,
+            factor: 0,
+            offset: 0,
+            exponent: 1,
+        };
+        let game = new_with_rules(Some([1; 16]), Arc::new(rules));
+        assert_eq!(
+            HashMap::from([(Gold, 7)]),
+            execute_expand_places_calculate_cost(&game)
+        );
+
+        let default_game = new_testing(Some([1; 16]));
+        assert_eq!(
+            HashMap::from([(Gold, 100)]),
+            execute_expand_places_calculate_cost(&default_game)
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use std::{
+    fmt::{Debug, Formatter},
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::{
+    my_little_rpg_errors::{ErrorCode, MyError},
+    parser::save_format::SaveFormat,
+};
+
+mod tests;
+
+pub const DEFAULT_SAVE_ROOT: &str = "./save_games/";
+
+// The folder inside of the save root every game of the server is autosaved in, named after the
+// game. It starts with a ., so clients can never name it.
+pub const AUTOSAVE_FOLDER: &str = ".autosave";
+
+// The folder every save game is kept in. Clients only name save games and the folders inside of
+// it, so the server never reads or writes files anywhere else. Save games are signed with the
+// signing key when there is one.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SaveRoot {
+    path: PathBuf,
+    signing_key: Option<Box<[u8]>>,
+}
+
+// The signing key is a secret, so it is never printed.
+impl Debug for SaveRoot {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.debug_struct("SaveRoot")
+            .field("path", &self.path)
+            .field("signed", &self.signing_key.is_some())
+            .finish()
+    }
+}
+
+impl Default for SaveRoot {
+    fn default() -> Self {
+        Self::new(DEFAULT_SAVE_ROOT)
+    }
+}
+
+impl SaveRoot {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            signing_key: None,
+        }
+    }
+
+    pub fn with_signing_key(
+        mut self,
+        signing_key: impl Into<Box<[u8]>>,
+    ) -> Self {
+        self.signing_key = Some(signing_key.into());
+        self
+    }
+
+    // The key is the content of the file, without whitespace around it.
+    pub fn with_signing_key_file(
+        self,
+        key_path: &str,
+    ) -> Result<Self, MyError> {
+        let data = fs::read(key_path).map_err(|error| {
+            create_invalid_signing_key_error(format!(
+                "Failed reading the signing key {key_path}! Reason: {error}"
+            ))
+        })?;
+        let signing_key = data.trim_ascii();
+        if signing_key.is_empty() {
+            return Err(create_invalid_signing_key_error(format!(
+                "The signing key {key_path} is empty."
+            )));
+        }
+        Ok(self.with_signing_key(signing_key))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub(crate) fn signing_key(&self) -> Option<&[u8]> {
+        self.signing_key.as_deref()
+    }
+
+    // The folder is relative to the save root, . is allowed but .., / at the start and drive
+    // letters are not.
+    pub(crate) fn folder(
+        &self,
+        save_path: Option<&str>,
+    ) -> Result<PathBuf, MyError> {
+        let mut folder = self.path.clone();
+        for component in Path::new(save_path.unwrap_or("")).components() {
+            match component {
+                Component::CurDir => {}
+                Component::Normal(part) => {
+                    let part = part
+                        .to_str()
+                        .filter(|part| is_save_name(part))
+                        .ok_or_else(|| create_invalid_save_path_error(save_path.unwrap_or("")))?;
+                    folder.push(part);
+                }
+                Component::ParentDir | Component::RootDir | Component::Prefix(_) => {
+                    return Err(create_invalid_save_path_error(save_path.unwrap_or("")));
+                }
+            }
+        }
+        Ok(folder)
+    }
+
+    // The save game in whichever format it was saved, or where it would be saved as JSON when there
+    // is none.
+    pub(crate) fn find_file(
+        &self,
+        save_name: &str,
+        save_path: Option<&str>,
+    ) -> Result<(PathBuf, SaveFormat), MyError> {
+        for format in SaveFormat::get_all() {
+            let file_path = self.file(save_name, save_path, format)?;
+            if file_path.is_file() {
+                return Ok((file_path, format));
+            }
+        }
+        Ok((
+            self.file(save_name, save_path, SaveFormat::Json)?,
+            SaveFormat::Json,
+        ))
+    }
+
+    pub(crate) fn file(
+        &self,
+        save_name: &str,
+        save_path: Option<&str>,
+        format: SaveFormat,
+    ) -> Result<PathBuf, MyError> {
+        let file_name = file_name(save_name, format)?;
+        Ok(self.folder(save_path)?.join(file_name))
+    }
+
+    pub(crate) fn autosave_folder(&self) -> PathBuf {
+        self.path.join(AUTOSAVE_FOLDER)
+    }
+
+    pub(crate) fn autosave_file(
+        &self,
+        game_name: &str,
+        format: SaveFormat,
+    ) -> Result<PathBuf, MyError> {
+        Ok(self.autosave_folder().join(file_name(game_name, format)?))
+    }
+}
+
+fn file_name(
+    save_name: &str,
+    format: SaveFormat,
+) -> Result<String, MyError> {
+    if !is_save_name(save_name) {
+        return Err(MyError::create_save_load_error(
+            ErrorCode::InvalidSaveName,
+            format!(
+                "The save game name {save_name:?} can only contain letters, digits, -, _ and . \
+                 and can not start with a ."
+            ),
+        ));
+    }
+    Ok(format!("{save_name}.{}", format.extension()))
+}
+
+// Only letters, digits, -, _ and . so a name can never be a path of its own.
+pub fn is_save_name(save_name: &str) -> bool {
+    !save_name.is_empty()
+        && !save_name.starts_with('.')
+        && save_name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_' || c == '.')
+}
+
+fn create_invalid_signing_key_error(error_message: String) -> MyError {
+    MyError::create_save_load_error(ErrorCode::LoadFailed, error_message)
+}
+
+fn create_invalid_save_path_error(save_path: &str) -> MyError {
+    MyError::create_save_load_error(
+        ErrorCode::InvalidSavePath,
+        format!(
+            "The save game path {save_path:?} needs to be a folder inside of the save games \
+             folder, without .. and not starting with /."
+        ),
+    )
+}
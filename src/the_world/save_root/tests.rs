@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests_int {
+    use std::path::PathBuf;
+
+    use crate::{
+        my_little_rpg_errors::{ErrorCode, MyError},
+        parser::save_format::SaveFormat,
+        the_world::save_root::{is_save_name, SaveRoot},
+    };
+
+    #[test]
+    fn file_is_inside_of_the_root() {
+        let save_root = SaveRoot::new("root");
+
+        assert_eq!(
+            PathBuf::from("root/save.json"),
+            save_root.file("save", None, SaveFormat::Json).unwrap()
+        );
+        assert_eq!(
+            PathBuf::from("root/a/b/save.sav"),
+            save_root
+                .file("save", Some("./a/b/"), SaveFormat::Binary)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn file_rejects_escaping_paths() {
+        let save_root = SaveRoot::new("root");
+
+        for save_path in ["..", "a/../..", "/etc", "a/.hidden", "a\\..\\b"] {
+            assert!(
+                matches!(
+                    save_root.file("save", Some(save_path), SaveFormat::Json),
+                    Err(MyError::SaveLoad {
+                        code: ErrorCode::InvalidSavePath,
+                        ..
+                    })
+                ),
+                "{save_path}"
+            );
+        }
+    }
+
+    #[test]
+    fn file_rejects_invalid_names() {
+        let save_root = SaveRoot::new("root");
+
+        for save_name in ["", "..", ".save", "a/b", "a\\b", "../save", "a b"] {
+            assert!(
+                matches!(
+                    save_root.find_file(save_name, None),
+                    Err(MyError::SaveLoad {
+                        code: ErrorCode::InvalidSaveName,
+                        ..
+                    })
+                ),
+                "{save_name}"
+            );
+        }
+    }
+
+    #[test]
+    fn save_names() {
+        assert!(is_save_name("save_1.backup-2"));
+        assert!(!is_save_name("."));
+        assert!(!is_save_name("save:1"));
+    }
+}
@@ -0,0 +1,39 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::the_world::item::Item;
+
+pub const STARTING_STASH_CAPACITY: usize = 10;
+pub const STASH_CAPACITY_EXPANSION: usize = 10;
+
+// Items kept out of the working inventory, so crafting can not sacrifice them. The capacity is
+// shared by all tabs, and a tab is removed when its last item is taken out.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Stash {
+    pub(crate) tabs: BTreeMap<Box<str>, Vec<Item>>,
+    pub(crate) capacity: usize,
+}
+
+impl Default for Stash {
+    fn default() -> Self {
+        Self {
+            tabs: BTreeMap::new(),
+            capacity: STARTING_STASH_CAPACITY,
+        }
+    }
+}
+
+impl Stash {
+    pub fn tabs(&self) -> &BTreeMap<Box<str>, Vec<Item>> {
+        &self.tabs
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn items_count(&self) -> usize {
+        self.tabs.values().map(Vec::len).sum()
+    }
+}
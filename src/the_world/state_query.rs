@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+use crate::the_world::{damage_types::DamageType, item::Item, item_query::ItemQuery, place::Place};
+
+// Selects a page of the places or items matching all the filters. The offset and limit count the
+// matching entries, so the pages do not change with the filters.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Eq, Hash)]
+pub struct StateQuery<F> {
+    #[serde(default)]
+    pub(crate) offset: usize,
+    #[serde(default)]
+    pub(crate) limit: Option<usize>,
+    #[serde(default = "Vec::new")]
+    pub(crate) filters: Vec<F>,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Eq, Hash)]
+pub enum ItemFilter {
+    MinModifiers(usize),
+    MaxModifiers(usize),
+    Query(ItemQuery),
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Eq, Hash)]
+pub enum PlaceFilter {
+    Resistance(DamageType),
+    NoResistance(DamageType),
+}
+
+impl<F> StateQuery<F> {
+    pub fn new(
+        offset: usize,
+        limit: Option<usize>,
+        filters: Vec<F>,
+    ) -> Self {
+        Self {
+            offset,
+            limit,
+            filters,
+        }
+    }
+
+    pub fn all() -> Self {
+        Self::new(0, None, Vec::new())
+    }
+}
+
+impl ItemFilter {
+    pub fn matches(
+        &self,
+        item: &Item,
+    ) -> bool {
+        match self {
+            ItemFilter::MinModifiers(min_modifiers) => item.modifiers.len() >= *min_modifiers,
+            ItemFilter::MaxModifiers(max_modifiers) => item.modifiers.len() <= *max_modifiers,
+            ItemFilter::Query(query) => query.matches(item),
+        }
+    }
+}
+
+impl PlaceFilter {
+    pub fn matches(
+        &self,
+        place: &Place,
+    ) -> bool {
+        match self {
+            PlaceFilter::Resistance(damage_type) => place.resistance.contains_key(damage_type),
+            PlaceFilter::NoResistance(damage_type) => !place.resistance.contains_key(damage_type),
+        }
+    }
+}
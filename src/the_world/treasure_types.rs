@@ -2,7 +2,11 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::{my_little_rpg_errors::MyError, the_world::treasure_types::TreasureType::Gold, Game};
+use crate::{
+    my_little_rpg_errors::{ErrorCode, MyError},
+    the_world::treasure_types::TreasureType::Gold,
+    Game,
+};
 
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize, Eq, Hash)]
 pub enum TreasureType {
@@ -23,10 +27,13 @@ pub fn pay_crafting_cost(
     if calculate_are_all_treasure_payable(&game.treasure, crafting_cost) {
         update_all_treasure(&mut game.treasure, crafting_cost);
     } else {
-        return Err(MyError::create_execute_command_error(format!(
-            "Cant pay the crafting cost, the cost is {:?} and you only have {:?}",
-            crafting_cost, game.treasure
-        )));
+        return Err(MyError::create_execute_command_error(
+            ErrorCode::CantPayCraftingCost,
+            format!(
+                "Cant pay the crafting cost, the cost is {:?} and you only have {:?}",
+                crafting_cost, game.treasure
+            ),
+        ));
     }
     Ok(())
 }
@@ -37,7 +44,7 @@ fn calculate_are_all_treasure_payable(
 ) -> bool {
     treasure_cost.iter().all(|(item_resource_type, amount)| {
         match current_treasure.get(item_resource_type) {
-            None => false,
+            None => *amount == 0,
             Some(stored_amount) => stored_amount >= amount,
         }
     })